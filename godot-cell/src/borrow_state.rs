@@ -91,7 +91,7 @@ impl BorrowState {
         self.ensure_not_poisoned()?;
 
         if self.has_accessible() {
-            return Err("cannot borrow while accessible mutable borrow exists".into());
+            return Err(BorrowStateErr::AccessibleMutBorrow);
         }
 
         Ok(())
@@ -101,11 +101,11 @@ impl BorrowState {
         self.ensure_not_poisoned()?;
 
         if self.has_accessible() {
-            return Err("cannot borrow while accessible mutable borrow exists".into());
+            return Err(BorrowStateErr::AccessibleMutBorrow);
         }
 
         if self.shared_count != 0 {
-            return Err("cannot borrow mutable while shared borrow exists".into());
+            return Err(BorrowStateErr::SharedBorrow);
         }
 
         Ok(())
@@ -271,6 +271,10 @@ impl Default for BorrowState {
 pub enum BorrowStateErr {
     Poisoned(String),
     IsPoisoned,
+    /// A new borrow conflicts with an existing accessible mutable borrow.
+    AccessibleMutBorrow,
+    /// A new mutable borrow conflicts with existing shared borrows.
+    SharedBorrow,
     Custom(String),
 }
 
@@ -279,6 +283,12 @@ impl std::fmt::Display for BorrowStateErr {
         match self {
             BorrowStateErr::Poisoned(err) => write!(f, "the borrow state was poisoned: {err}"),
             BorrowStateErr::IsPoisoned => write!(f, "the borrow state is poisoned"),
+            BorrowStateErr::AccessibleMutBorrow => {
+                f.write_str("cannot borrow while accessible mutable borrow exists")
+            }
+            BorrowStateErr::SharedBorrow => {
+                f.write_str("cannot borrow mutable while shared borrow exists")
+            }
             BorrowStateErr::Custom(err) => f.write_str(err),
         }
     }
@@ -34,6 +34,7 @@ use std::ptr::NonNull;
 use std::sync::Mutex;
 
 use borrow_state::BorrowState;
+pub use borrow_state::BorrowStateErr;
 pub use guards::{InaccessibleGuard, MutGuard, RefGuard};
 
 /// A cell which can hand out new `&mut` references to its value even when one already exists. As long as
@@ -54,7 +54,7 @@ pub struct BuiltinClass {
     pub indexing_return_type: Option<String>,
     pub is_keyed: bool,
     pub members: Option<Vec<Member>>,
-    // pub constants: Option<Vec<BuiltinConstant>>,
+    pub constants: Option<Vec<BuiltinConstant>>,
     pub enums: Option<Vec<BuiltinClassEnum>>, // no bitfield
     pub operators: Vec<Operator>,
     pub methods: Option<Vec<BuiltinClassMethod>>,
@@ -72,8 +72,8 @@ pub struct Class {
     pub constants: Option<Vec<ClassConstant>>,
     pub enums: Option<Vec<Enum>>,
     pub methods: Option<Vec<ClassMethod>>,
-    // pub properties: Option<Vec<Property>>,
-    // pub signals: Option<Vec<Signal>>,
+    pub properties: Option<Vec<Property>>,
+    pub signals: Option<Vec<Signal>>,
 }
 
 #[derive(DeJson)]
@@ -157,7 +157,6 @@ impl EnumConstant {
 
 pub type ClassConstant = EnumConstant;
 
-/*
 // Constants of builtin types have a string value like "Vector2(1, 1)", hence also a type field
 #[derive(DeJson)]
 pub struct BuiltinConstant {
@@ -166,7 +165,6 @@ pub struct BuiltinConstant {
     pub type_: String,
     pub value: String,
 }
-*/
 
 #[derive(DeJson)]
 pub struct Operator {
@@ -185,17 +183,17 @@ pub struct Member {
 #[derive(DeJson)]
 pub struct Property {
     #[nserde(rename = "type")]
-    type_: String,
-    name: String,
-    setter: String,
-    getter: String,
-    index: i32, // can be -1
+    pub type_: String,
+    pub name: String,
+    pub setter: Option<String>, // absent for read-only properties
+    pub getter: Option<String>,
+    pub index: Option<i32>,
 }
 
 #[derive(DeJson)]
 pub struct Signal {
-    name: String,
-    arguments: Option<Vec<MethodArg>>,
+    pub name: String,
+    pub arguments: Option<Vec<MethodArg>>,
 }
 
 #[derive(DeJson)]
@@ -0,0 +1,205 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::path::Path;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use crate::api_parser::*;
+use crate::{ident, to_snake_case, util, SubmitFn};
+
+/// Generates tests which verify that hand-written constants of builtin types (e.g. `Vector3::FORWARD`) match Godot's.
+///
+/// The builtin types themselves are implemented in Rust, so their constants cannot be generated as part of them. Instead, each constant
+/// declared in the extension API is compared against the Rust constant of the same name. If one is missing, the tests fail to compile,
+/// so the set of constants cannot silently drift from the engine.
+pub(crate) fn generate_builtin_constants_file(
+    api: &ExtensionApi,
+    gen_path: &Path,
+    submit_fn: &mut SubmitFn,
+) {
+    let test_fns = api.builtin_classes.iter().filter_map(make_constants_test);
+
+    let tokens = quote! {
+        //! Verifies that hand-written constants of builtin types match the values in Godot's extension API.
+
+        use crate::builtin::math::ApproxEq;
+        use crate::builtin::*;
+
+        use std::fmt::Debug;
+
+        fn check_approx<T: ApproxEq + Debug>(rust: T, godot: T, name: &str) {
+            assert!(
+                rust.approx_eq(&godot),
+                "constant `{name}` differs from Godot:\n  Rust:  {rust:?}\n  Godot: {godot:?}"
+            );
+        }
+
+        fn check_exact<T: PartialEq + Debug>(rust: T, godot: T, name: &str) {
+            assert_eq!(rust, godot, "constant `{name}` differs from Godot");
+        }
+
+        #( #test_fns )*
+    };
+
+    submit_fn(gen_path.join("builtin_constants.rs"), tokens);
+}
+
+fn make_constants_test(class: &BuiltinClass) -> Option<TokenStream> {
+    let checks: Vec<TokenStream> = util::option_as_slice(&class.constants)
+        .iter()
+        // Other constant types are integers, like Vector3::AXIS_X. In Rust, those are represented as enums such as Vector3Axis.
+        .filter(|constant| constant.type_ == class.name)
+        .filter_map(|constant| make_constant_check(constant, &class.name))
+        .collect();
+
+    if checks.is_empty() {
+        return None;
+    }
+
+    let test_name = ident(&format!("{}_constants", to_snake_case(&class.name)));
+
+    Some(quote! {
+        #[test]
+        fn #test_name() {
+            #( #checks )*
+        }
+    })
+}
+
+/// Returns `None` for value formats that are not (yet) supported. Those are skipped rather than failing codegen, which would break the
+/// build for users of future Godot versions, although the check only matters for tests.
+fn make_constant_check(constant: &BuiltinConstant, class_name: &str) -> Option<TokenStream> {
+    let (godot_value, is_exact) = parse_builtin_constant_value(&constant.value, class_name)?;
+
+    let class_ty = ident(class_name);
+    let constant_ident = ident(&constant.name);
+    let full_name = format!("{class_name}::{}", constant.name);
+
+    let check = if is_exact {
+        quote! { check_exact(#class_ty::#constant_ident, #godot_value, #full_name); }
+    } else {
+        quote! { check_approx(#class_ty::#constant_ident, #godot_value, #full_name); }
+    };
+
+    Some(check)
+}
+
+/// Converts a value like `"Vector3(0, 0, -1)"` into a Rust expression constructing it.
+///
+/// Returns the expression, and whether it should be compared exactly (integer types) rather than approximately.
+/// Returns `None` if the type is not supported or the value cannot be parsed.
+pub(crate) fn parse_builtin_constant_value(
+    value: &str,
+    type_name: &str,
+) -> Option<(TokenStream, bool)> {
+    let args: Vec<&str> = value
+        .strip_prefix(type_name)?
+        .strip_prefix('(')?
+        .strip_suffix(')')?
+        .split(',')
+        .map(str::trim)
+        .collect();
+
+    let ty = ident(type_name);
+
+    let result = match type_name {
+        "Vector2" | "Vector3" | "Vector4" => {
+            let components = make_floats(&args)?;
+            (quote! { #ty::new(#( #components ),*) }, false)
+        }
+        "Vector2i" | "Vector3i" | "Vector4i" => {
+            let components = make_ints(&args)?;
+            (quote! { #ty::new(#( #components ),*) }, true)
+        }
+        "Color" => {
+            let [r, g, b, a] = to_array(make_floats(&args)?)?;
+            (quote! { Color::from_rgba(#r, #g, #b, #a) }, false)
+        }
+        "Quaternion" => {
+            let [x, y, z, w] = to_array(make_floats(&args)?)?;
+            (quote! { Quaternion::new(#x, #y, #z, #w) }, false)
+        }
+        "Plane" => {
+            let [x, y, z, d] = to_array(make_floats(&args)?)?;
+            (
+                quote! { Plane { normal: Vector3::new(#x, #y, #z), d: #d } },
+                false,
+            )
+        }
+        // Matrix types list their columns (basis vectors and origin) in order.
+        "Transform2D" => {
+            let [a, b, origin] = to_array(make_columns(&args, "Vector2", 2)?)?;
+            (quote! { Transform2D::from_cols(#a, #b, #origin) }, false)
+        }
+        "Basis" => {
+            let [a, b, c] = to_array(make_columns(&args, "Vector3", 3)?)?;
+            (quote! { Basis::from_cols(#a, #b, #c) }, false)
+        }
+        "Transform3D" => {
+            let [a, b, c, origin] = to_array(make_columns(&args, "Vector3", 3)?)?;
+            (
+                quote! { Transform3D::from_cols(#a, #b, #c, #origin) },
+                false,
+            )
+        }
+        "Projection" => {
+            let [x, y, z, w] = to_array(make_columns(&args, "Vector4", 4)?)?;
+            (quote! { Projection::from_cols(#x, #y, #z, #w) }, false)
+        }
+        _ => return None,
+    };
+
+    Some(result)
+}
+
+fn make_floats(args: &[&str]) -> Option<Vec<TokenStream>> {
+    args.iter()
+        .map(|arg| {
+            let expr = match *arg {
+                "inf" => quote! { real::INFINITY },
+                "-inf" => quote! { -real::INFINITY },
+                _ => {
+                    let lit = Literal::f64_unsuffixed(arg.parse::<f64>().ok()?);
+                    quote! { #lit }
+                }
+            };
+            Some(expr)
+        })
+        .collect()
+}
+
+fn make_ints(args: &[&str]) -> Option<Vec<TokenStream>> {
+    args.iter()
+        .map(|arg| {
+            let lit = Literal::i64_unsuffixed(arg.parse::<i64>().ok()?);
+            Some(quote! { #lit })
+        })
+        .collect()
+}
+
+/// Groups the arguments of a matrix type into column vectors of type `column_ty`.
+fn make_columns(args: &[&str], column_ty: &str, column_len: usize) -> Option<Vec<TokenStream>> {
+    if args.len() % column_len != 0 {
+        return None;
+    }
+
+    let column_ty = ident(column_ty);
+    let floats = make_floats(args)?;
+
+    let columns = floats
+        .chunks(column_len)
+        .map(|components| quote! { #column_ty::new(#( #components ),*) })
+        .collect();
+
+    Some(columns)
+}
+
+fn to_array<const N: usize>(items: Vec<TokenStream>) -> Option<[TokenStream; N]> {
+    items.try_into().ok()
+}
@@ -517,6 +517,9 @@ pub(crate) fn generate_core_mod_file(gen_path: &Path, submit_fn: &mut SubmitFn)
         pub mod builtin_classes;
         pub mod utilities;
         pub mod native;
+
+        #[cfg(test)]
+        mod builtin_constants;
     };
 
     submit_fn(gen_path.join("mod.rs"), code);
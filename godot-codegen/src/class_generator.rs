@@ -402,6 +402,8 @@ fn make_class_doc(
         Related symbols:\n\n\
         {sidecar_line}\
         * [`{trait_name}`][crate::engine::{trait_name}]: virtual methods\n\
+        * [`{rust_ty}Signals`][crate::engine::signals::{rust_ty}Signals]: signals\n\
+        * [`{rust_ty}Properties`][crate::engine::properties::{rust_ty}Properties]: properties\n\
         {notify_line}\
         \n\n\
         See also [Godot docs for `{godot_ty}`]({online_link}).\n\n",
@@ -530,6 +532,17 @@ fn make_class(class: &Class, class_name: &TyName, ctx: &mut Context) -> Generate
 
     let enums = make_enums(option_as_slice(&class.enums), class_name, ctx);
     let constants = make_constants(option_as_slice(&class.constants), class_name, ctx);
+    let (signals_accessor, signals_collection) = make_signals(
+        option_as_slice(&class.signals),
+        class_name,
+        &base_ident_opt,
+        ctx,
+    );
+    let (properties_accessor, properties_collection) = make_properties(
+        option_as_slice(&class.properties),
+        class_name,
+        &base_ident_opt,
+    );
     let inherits_macro = format_ident!("inherits_transitive_{}", class_name.rust_ty);
 
     let (exportable_impl, exportable_macro_impl) = if ctx.is_exportable(class_name) {
@@ -648,7 +661,11 @@ fn make_class(class: &Class, class_name: &TyName, ctx: &mut Context) -> Generate
                 #notify_methods
                 #internal_methods
                 #constants
+                #signals_accessor
+                #properties_accessor
             }
+            #signals_collection
+            #properties_collection
             impl crate::obj::GodotClass for #class_name {
                 type Base = #base_ty;
 
@@ -708,6 +725,167 @@ fn make_class(class: &Class, class_name: &TyName, ctx: &mut Context) -> Generate
     }
 }
 
+/// Generates `Class::signals()` and the signal collection type `ClassSignals`, which derefs to the base class' collection.
+fn make_signals(
+    signals: &[Signal],
+    class_name: &TyName,
+    base_ident_opt: &Option<Ident>,
+    ctx: &mut Context,
+) -> (TokenStream, TokenStream) {
+    let collection = format_ident!("{}Signals", class_name.rust_ty);
+    let base_collection = base_ident_opt
+        .as_ref()
+        .map(|base| format_ident!("{}Signals", base));
+
+    let accessors = signals.iter().filter_map(|signal| {
+        // Parameter tuples are only supported up to a certain length; see SignalParams in godot-core.
+        if codegen_special_cases::is_signal_excluded(signal, ctx)
+            || option_as_slice(&signal.arguments).len() > 10
+        {
+            return None;
+        }
+
+        let method_name = safe_ident(&signal.name);
+        let signal_name_str = &signal.name;
+
+        let mut param_decls = Vec::new();
+        let mut param_types = Vec::new();
+        for arg in option_as_slice(&signal.arguments) {
+            let ty = to_rust_type(&arg.type_, arg.meta.as_ref(), ctx);
+            param_decls.push(format!("{}: {}", arg.name, arg.type_));
+
+            // Objects may be null when emitted by the engine.
+            param_types.push(match ty {
+                RustTy::EngineClass { tokens, .. } => quote! { Option<#tokens> },
+                other => quote! { #other },
+            });
+        }
+
+        let doc = format!("Signal `{}({})`.", signal.name, param_decls.join(", "));
+
+        Some(quote! {
+            #[doc = #doc]
+            pub fn #method_name(&self) -> crate::obj::EngineSignal<#class_name, ( #(#param_types,)* )> {
+                crate::obj::EngineSignal::__new(#signal_name_str)
+            }
+        })
+    });
+
+    let collection_doc = format!(
+        "Signals declared by [`{rust_ty}`][crate::engine::{rust_ty}]. Signals of base classes are accessible through `Deref`.",
+        rust_ty = class_name.rust_ty
+    );
+    let deref_impl = make_collection_deref(&collection, base_collection, ident("signals"));
+
+    let accessor = quote! {
+        /// Type-safe access to the signals of this class, including inherited ones.
+        pub fn signals() -> #collection {
+            #collection
+        }
+    };
+
+    let collection_decl = quote! {
+        #[doc = #collection_doc]
+        #[derive(Copy, Clone, Debug)]
+        pub struct #collection;
+
+        impl #collection {
+            #( #accessors )*
+        }
+
+        #deref_impl
+    };
+
+    (accessor, collection_decl)
+}
+
+/// Generates `Class::properties()` and the property collection type `ClassProperties`, which derefs to the base class' collection.
+fn make_properties(
+    properties: &[Property],
+    class_name: &TyName,
+    base_ident_opt: &Option<Ident>,
+) -> (TokenStream, TokenStream) {
+    let collection = format_ident!("{}Properties", class_name.rust_ty);
+    let base_collection = base_ident_opt
+        .as_ref()
+        .map(|base| format_ident!("{}Properties", base));
+
+    let mut method_names = std::collections::HashSet::new();
+    let accessors = properties.iter().filter_map(|property| {
+        // Some property names contain '/' or other characters, which cannot be part of identifiers.
+        let sanitized: String = property
+            .name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+
+        if !method_names.insert(sanitized.clone()) {
+            return None;
+        }
+
+        let method_name = safe_ident(&sanitized);
+        let name_str = &property.name;
+        let type_str = &property.type_;
+        let getter_str = property.getter.as_deref().unwrap_or("");
+        let setter_str = property.setter.as_deref().unwrap_or("");
+        let doc = format!("Property `{}: {}`.", property.name, property.type_);
+
+        Some(quote! {
+            #[doc = #doc]
+            pub fn #method_name(&self) -> crate::obj::EngineProperty<#class_name> {
+                crate::obj::EngineProperty::__new(#name_str, #type_str, #getter_str, #setter_str)
+            }
+        })
+    });
+
+    let collection_doc = format!(
+        "Properties declared by [`{rust_ty}`][crate::engine::{rust_ty}]. Properties of base classes are accessible through `Deref`.",
+        rust_ty = class_name.rust_ty
+    );
+    let deref_impl = make_collection_deref(&collection, base_collection, ident("properties"));
+
+    let accessor = quote! {
+        /// Metadata of the properties of this class, including inherited ones.
+        pub fn properties() -> #collection {
+            #collection
+        }
+    };
+
+    let collection_decl = quote! {
+        #[doc = #collection_doc]
+        #[derive(Copy, Clone, Debug)]
+        pub struct #collection;
+
+        impl #collection {
+            #( #accessors )*
+        }
+
+        #deref_impl
+    };
+
+    (accessor, collection_decl)
+}
+
+fn make_collection_deref(
+    collection: &Ident,
+    base_collection: Option<Ident>,
+    module: Ident,
+) -> TokenStream {
+    let Some(base_collection) = base_collection else {
+        return TokenStream::new();
+    };
+
+    quote! {
+        impl std::ops::Deref for #collection {
+            type Target = crate::engine::#module::#base_collection;
+
+            fn deref(&self) -> &Self::Target {
+                &crate::engine::#module::#base_collection
+            }
+        }
+    }
+}
+
 fn make_notify_methods(class_name: &TyName, ctx: &mut Context) -> TokenStream {
     // Note: there are two more methods, but only from Node downwards, not from Object:
     // - notify_thread_safe
@@ -1008,6 +1186,8 @@ fn normalize_native_structure_field_type(field_type: &str) -> String {
 fn make_module_file(classes_and_modules: Vec<GeneratedClassModule>) -> TokenStream {
     let mut class_decls = Vec::new();
     let mut notify_decls = Vec::new();
+    let mut signals_decls = Vec::new();
+    let mut properties_decls = Vec::new();
 
     for m in classes_and_modules.iter() {
         let GeneratedClassModule {
@@ -1028,6 +1208,15 @@ fn make_module_file(classes_and_modules: Vec<GeneratedClassModule>) -> TokenStre
         };
         class_decls.push(class_decl);
 
+        let signals_name = format_ident!("{}Signals", class_name.rust_ty);
+        let properties_name = format_ident!("{}Properties", class_name.rust_ty);
+        signals_decls.push(quote! {
+            pub use super::#module_name::re_export::#signals_name;
+        });
+        properties_decls.push(quote! {
+            pub use super::#module_name::re_export::#properties_name;
+        });
+
         if let Some(enum_name) = own_notification_enum_name {
             let notify_decl = quote! {
                 pub use super::#module_name::re_export::#enum_name;
@@ -1057,6 +1246,16 @@ fn make_module_file(classes_and_modules: Vec<GeneratedClassModule>) -> TokenStre
             #( #notify_decls )*
         }
 
+        /// Signal collections of engine classes, obtained through `Class::signals()`.
+        pub mod signals {
+            #( #signals_decls )*
+        }
+
+        /// Property collections of engine classes, obtained through `Class::properties()`.
+        pub mod properties {
+            #( #properties_decls )*
+        }
+
         #[doc(hidden)]
         pub mod class_macros {
             pub use crate::*;
@@ -1868,7 +2067,10 @@ fn make_virtual_methods_trait(
 
 fn special_virtual_methods(notification_enum_name: &Ident) -> TokenStream {
     quote! {
-        #[doc(hidden)]
+        /// Registers additional methods, properties, signals and constants at runtime.
+        ///
+        /// Called once when the class is registered, after all symbols from `#[godot_api]` and `#[var]` attributes.
+        /// See [`ClassBuilder`][crate::builder::ClassBuilder] for details.
         fn register_class(builder: &mut crate::builder::ClassBuilder<Self>) {
             unimplemented!()
         }
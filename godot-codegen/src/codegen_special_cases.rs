@@ -7,7 +7,7 @@
 
 //! Codegen-dependent exclusions. Can be removed if feature `codegen-full` is removed.
 
-use crate::api_parser::{BuiltinClassMethod, ClassMethod, UtilityFunction};
+use crate::api_parser::{BuiltinClassMethod, ClassMethod, Signal, UtilityFunction};
 use crate::context::Context;
 use crate::{special_cases, TyName};
 
@@ -113,6 +113,27 @@ pub(crate) fn is_function_excluded(function: &UtilityFunction, ctx: &mut Context
         })
 }
 
+pub(crate) fn is_signal_excluded(signal: &Signal, ctx: &mut Context) -> bool {
+    let is_arg_excluded = |ty: &str, _ctx: &mut Context| {
+        let class_deleted = special_cases::is_class_deleted(&TyName::from_godot(ty));
+
+        #[cfg(not(feature = "codegen-full"))]
+        {
+            class_deleted || is_type_excluded(ty, _ctx)
+        }
+        #[cfg(feature = "codegen-full")]
+        {
+            class_deleted
+        }
+    };
+
+    // Exclude if any argument contains an excluded type.
+    signal.arguments.as_ref().map_or(false, |args| {
+        args.iter()
+            .any(|arg| is_arg_excluded(arg.type_.as_str(), ctx))
+    })
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Allowed-classes
 
@@ -147,6 +168,8 @@ const SELECTED_CLASSES: &[&str] = &[
     "MainLoop",
     "Marker2D",
     "Mesh",
+    "MultiplayerAPI",
+    "MultiplayerPeer",
     "Node",
     "Node2D",
     "Node3D",
@@ -154,6 +177,7 @@ const SELECTED_CLASSES: &[&str] = &[
     "Object",
     "OS",
     "PackedScene",
+    "PacketPeer",
     "PathFollow2D",
     "PhysicsBody2D",
     "PrimitiveMesh",
@@ -6,6 +6,7 @@
  */
 
 mod api_parser;
+mod builtin_constants_generator;
 mod central_generator;
 mod class_generator;
 mod codegen_special_cases;
@@ -19,6 +20,7 @@ mod utilities_generator;
 mod tests;
 
 use api_parser::{load_extension_api, ExtensionApi};
+use builtin_constants_generator::generate_builtin_constants_file;
 use central_generator::{
     generate_core_central_file, generate_core_mod_file, generate_sys_central_file,
     generate_sys_classes_file,
@@ -125,6 +127,9 @@ pub fn generate_core_files(core_gen_path: &Path) {
     );
     watch.record("generate_builtin_class_files");
 
+    generate_builtin_constants_file(&api, core_gen_path, &mut submit_fn);
+    watch.record("generate_builtin_constants_file");
+
     generate_native_structures_files(
         &api,
         &mut ctx,
@@ -5,6 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::builtin_constants_generator::parse_builtin_constant_value;
 use crate::util::{
     parse_native_structures_format, to_pascal_case, to_snake_case, NativeStructuresField,
 };
@@ -209,3 +210,44 @@ fn test_parse_native_structures_format() {
     ];
     assert_eq!(actual.unwrap(), expected);
 }
+
+#[test]
+fn test_builtin_constant_values() {
+    fn parse(value: &str, type_name: &str) -> Option<(String, bool)> {
+        parse_builtin_constant_value(value, type_name).map(|(tokens, is_exact)| {
+            let mut code = tokens.to_string();
+            code.retain(|c| !c.is_whitespace());
+            (code, is_exact)
+        })
+    }
+
+    let expr = |code: &str| Some((code.to_string(), false));
+    let exact = |code: &str| Some((code.to_string(), true));
+
+    assert_eq!(
+        parse("Vector3(0, 0, -1)", "Vector3"),
+        expr("Vector3::new(0.0,0.0,-1.0)")
+    );
+    assert_eq!(
+        parse("Vector2(inf, inf)", "Vector2"),
+        expr("Vector2::new(real::INFINITY,real::INFINITY)")
+    );
+    assert_eq!(
+        parse("Vector2i(-2147483648, 2147483647)", "Vector2i"),
+        exact("Vector2i::new(-2147483648,2147483647)")
+    );
+    assert_eq!(
+        parse("Color(0.941176, 0.972549, 1, 1)", "Color"),
+        expr("Color::from_rgba(0.941176,0.972549,1.0,1.0)")
+    );
+    assert_eq!(
+        parse("Transform2D(-1, 0, 0, 1, 0, 0)", "Transform2D"),
+        expr("Transform2D::from_cols(Vector2::new(-1.0,0.0),Vector2::new(0.0,1.0),Vector2::new(0.0,0.0))")
+    );
+
+    // Mismatched types, argument counts and unknown formats are not supported.
+    assert_eq!(parse("Vector3(0, 0, -1)", "Vector2"), None);
+    assert_eq!(parse("Basis(1, 0, 0, 0)", "Basis"), None);
+    assert_eq!(parse("Vector3(a, b, c)", "Vector3"), None);
+    assert_eq!(parse("Rect2(0, 0, 1, 1)", "Rect2"), None);
+}
@@ -5,136 +5,149 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-// use crate::GodotClass;
-// use godot_core::Variant;
-// use godot_ffi as sys;
-// use std::borrow::Cow;
-// use std::ffi::CStr;
-
-// pub trait Method<C> {
-//     type ReturnType;
-//     type ParamTypes;
-//
-//     fn method_name(&self) -> Cow<CStr>;
-//     fn ptrcall(&mut self, instance: &mut C, args: Self::ParamTypes) -> Self::ReturnType;
-// }
-
-// ----------------------------------------------------------------------------------------------------------------------------------------------
-/*
+use godot_ffi as sys;
+
+use crate::builtin::meta::registration::method::{ClassMethodInfo, MethodParamOrReturnInfo};
+use crate::builtin::meta::{GodotConvert, GodotType};
+use crate::builtin::{StringName, Variant};
+use crate::engine::global::MethodFlags;
+use crate::obj::{Gd, GodotClass, Inherits};
+use crate::storage::{as_storage, Storage as _};
+
+type DynMethod<C> = dyn Fn(Gd<C>, &[&Variant]) -> Result<Variant, ()> + Send + Sync;
+
+/// Declares a method added through [`ClassBuilder::method()`][super::ClassBuilder::method].
+#[must_use = "the method is only registered once `done()` is called"]
+pub struct MethodBuilder<C: GodotClass> {
+    name: String,
+    function: Box<DynMethod<C>>,
+    params: Vec<MethodParamOrReturnInfo>,
+    return_info: Option<MethodParamOrReturnInfo>,
+}
 
-/// Method known at compile time (statically), either a Rust `fn` or closure.
-pub trait CodeMethod<C, R, Ps>
+impl<C> MethodBuilder<C>
 where
-    C: GodotClass,
+    C: GodotClass + Inherits<C::Base>,
 {
-    const PARAM_COUNT: usize;
-    const NAME: &'static str;
-
-    unsafe fn varcall(
-        &mut self,
-        instance: sys::GDExtensionClassInstancePtr,
-        args: *const sys::GDExtensionTypePtr,
-        ret: sys::GDExtensionTypePtr,
-        err: *mut sys::GDExtensionCallError,
-    );
+    pub(super) fn new(name: &str, function: Box<DynMethod<C>>) -> Self {
+        Self {
+            name: name.to_string(),
+            function,
+            params: Vec::new(),
+            return_info: None,
+        }
+    }
 
-    unsafe fn ptrcall(
-        &mut self,
-        instance: sys::GDExtensionClassInstancePtr,
-        args: *const sys::GDExtensionTypePtr,
-        ret: sys::GDExtensionTypePtr,
-    );
-}
+    /// Declares the next parameter, named `name` and of type `T`.
+    ///
+    /// Callers must pass exactly one argument per declared parameter.
+    pub fn param<T: GodotConvert>(mut self, name: &str) -> Self {
+        self.params.push(<T::Via as GodotType>::argument_info(name));
+        self
+    }
 
+    /// Declares the return type `T`. Methods without declared return type are shown as returning `void`.
+    pub fn returns<T: GodotConvert>(mut self) -> Self {
+        self.return_info = <T::Via as GodotType>::return_info();
+        self
+    }
 
-// TODO code duplication ((2))
-macro_rules! count_idents {
-    () => {
-        0
-    };
-    ($name:ident, $($other:ident,)*) => {
-        1 + $crate::gdext_count_idents!($($other,)*)
+    /// Registers the method with Godot.
+    pub fn done(self) {
+        let class_name = C::class_name();
+        let method_name = StringName::from(self.name.as_str());
+
+        let userdata = Box::new(MethodUserdata {
+            name: self.name,
+            param_count: self.params.len(),
+            function: self.function,
+        });
+
+        // The method stays registered until the class is unregistered, at which point the library is being unloaded. The userdata is
+        // thus intentionally leaked.
+        let method_userdata = Box::into_raw(userdata) as *mut std::ffi::c_void;
+
+        // Arguments arrive as variants; there is no ptrcall, as the signature is not known at compile time. Declaring the method as
+        // vararg makes Godot always use varcall.
+        let method_flags = MethodFlags::METHOD_FLAGS_DEFAULT | MethodFlags::METHOD_FLAG_VARARG;
+
+        // SAFETY: `dynamic_varcall::<C>` interprets the userdata as `MethodUserdata<C>`, and checks the argument count.
+        let info = unsafe {
+            ClassMethodInfo::from_runtime_info(
+                class_name,
+                method_name,
+                method_userdata,
+                Some(dynamic_varcall::<C>),
+                None,
+                method_flags,
+                self.return_info,
+                self.params,
+            )
+        };
+
+        info.register_extension_class_method();
     }
 }
 
-macro_rules! impl_code_method {
-// 	( $( $Param:ident ),* ) => {
-    ( $( $Param:ident $arg:ident ),* ) => {
-        impl<C, F, R, $( $Param ),*> CodeMethod<C, R, ( $( $Param, )* )> for F
-        where
-            C: $crate::GodotClass + $crate::GodotDefault, // TODO only GodotClass
-            F: Fn(&C, $( $Param ),* ) -> R,
-            $(
-                $Param: sys::GodotFfi,
-            )*
-            R: sys::GodotFfi + 'static,
-        {
-            const PARAM_COUNT: usize = count_idents!($( $Param, )*);
-
-            // Varcall
-            #[inline]
-            #[allow(unused_variables, unused_assignments, unused_mut)]
-            unsafe fn varcall(
-                &mut self,
-                instance: sys::GDExtensionClassInstancePtr,
-                args: *const sys::GDExtensionTypePtr,
-                ret: sys::GDExtensionTypePtr,
-                err: *mut sys::GDExtensionCallError,
-            ) {
-                let storage = ::godot_core::private::as_storage::<C>(instance);
-                let instance = storage.get_mut_lateinit();
-
-                let mut idx = 0;
-
-                $(
-                    let $arg = <$Param as FromVariant::from_variant(&*(*args.offset(idx) as *mut Variant));
-                    idx += 1;
-                )*
-
-                let ret_val = self(&instance, $(
-                    $arg,
-                )*);
-
-                *(ret as *mut Variant) = Variant::from(ret_val);
-                (*err).error = sys::GDExtensionCallErrorType_GDEXTENSION_CALL_OK;
-            }
-
-
-            // Ptrcall
-            #[inline]
-            #[allow(unused_variables, unused_assignments, unused_mut)]
-            unsafe fn ptrcall(
-                &mut self,
-                instance: sys::GDExtensionClassInstancePtr,
-                args: *const sys::GDExtensionTypePtr,
-                ret: sys::GDExtensionTypePtr,
-            ) {
-                let storage = $crate::private::as_storage::<C>(instance);
-                let instance = storage.get_mut_lateinit();
-
-                // TODO reuse code, see ((1))
-                let mut idx = 0;
-
-                $(
-                    let $arg = <$Param as sys::GodotFfi>::from_sys(*args.offset(idx));
-                    // FIXME update refcount, e.g. Gd::ready() or T::DynMemory::maybe_inc_ref(&result);
-                    // possibly in from_sys() directly; what about from_sys_init() and from_{obj|str}_sys()?
-                    idx += 1;
-                )*
-
-                let ret_val = self(&instance, $(
-                    $arg,
-                )*);
-
-                <R as sys::GodotFfi>::write_sys(&ret_val, ret);
-            }
-        }
+// ----------------------------------------------------------------------------------------------------------------------------------------------
 
-    };
+struct MethodUserdata<C: GodotClass> {
+    name: String,
+    param_count: usize,
+    function: Box<DynMethod<C>>,
 }
 
-impl_code_method!();
-impl_code_method!(P0 arg0);
-impl_code_method!(P0 arg0, P1 arg1);
-impl_code_method!(P0 arg0, P1 arg1, P2 arg2);
-*/
+/// # Safety
+/// - `method_userdata` must point to a valid `MethodUserdata<C>`.
+/// - `instance_ptr` must point to a valid instance of `C`.
+/// - `args_ptr` must point to an array of `arg_count` valid variant pointers.
+unsafe extern "C" fn dynamic_varcall<C>(
+    method_userdata: *mut std::ffi::c_void,
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: sys::GDExtensionInt,
+    ret: sys::GDExtensionVariantPtr,
+    err: *mut sys::GDExtensionCallError,
+) where
+    C: GodotClass + Inherits<C::Base>,
+{
+    let userdata = &*(method_userdata as *const MethodUserdata<C>);
+    let arg_count = arg_count as usize;
+
+    if arg_count != userdata.param_count {
+        (*err).error = if arg_count < userdata.param_count {
+            sys::GDEXTENSION_CALL_ERROR_TOO_FEW_ARGUMENTS
+        } else {
+            sys::GDEXTENSION_CALL_ERROR_TOO_MANY_ARGUMENTS
+        };
+        (*err).expected = userdata.param_count as i32;
+        return;
+    }
+
+    let args = Variant::unbounded_refs_from_sys(args_ptr, arg_count);
+
+    let result = crate::private::handle_panic(
+        || format!("{}::{}", C::class_name(), userdata.name),
+        std::panic::AssertUnwindSafe(|| {
+            let storage = as_storage::<C>(instance_ptr);
+            (userdata.function)(storage.get_gd(), args)
+        }),
+    );
+
+    match result {
+        Some(Ok(value)) => {
+            *(ret as *mut Variant) = value;
+            (*err).error = sys::GDEXTENSION_CALL_OK;
+        }
+        Some(Err(())) => {
+            (*err).error = sys::GDEXTENSION_CALL_ERROR_INVALID_ARGUMENT;
+        }
+        None => {
+            // Signal error and set return type to Nil, like #[func] varcalls.
+            (*err).error = sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD;
+
+            // TODO(uninit)
+            sys::interface_fn!(variant_new_nil)(sys::AsUninit::as_uninit(ret));
+        }
+    }
+}
@@ -5,11 +5,60 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::obj::GodotClass;
+//! Programmatic registration of class symbols at runtime.
+//!
+//! Complements the `#[func]`, `#[var]`, `#[signal]` and `#[constant]` attributes for cases where the set of symbols is only known
+//! at runtime, e.g. when it is generated from a data file. See [`ClassBuilder`] for details.
+
+use crate::builtin::meta::registration::constant::{ConstantKind, ExportConstant, IntegerConstant};
+use crate::builtin::meta::GodotConvert;
+use crate::builtin::{StringName, Variant};
+use crate::obj::{Gd, GodotClass, Inherits};
 use std::marker::PhantomData;
 
 mod method;
+mod property;
+mod rpc_config;
+mod signal;
+
+pub use method::*;
+pub use property::*;
+pub use rpc_config::*;
+pub use signal::*;
 
+/// Registers methods, properties, signals and constants of the class `C` at runtime.
+///
+/// A `ClassBuilder` is passed to the `register_class()` function of your class' `I*` interface trait, e.g.
+/// [`INode::register_class()`][crate::engine::INode::register_class]. This function is invoked once, when the class is registered
+/// with Godot, after all symbols declared via proc-macro attributes have been registered. The builder can thus refer to those
+/// symbols, for example use a `#[func]` as a property getter.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::register::builder::ClassBuilder;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Stats {}
+///
+/// #[godot_api]
+/// impl INode for Stats {
+///     fn register_class(builder: &mut ClassBuilder<Self>) {
+///         builder
+///             .method("add", |_this: Gd<Self>, args: &[&Variant]| {
+///                 let a = args[0].try_to::<i64>().map_err(|_| ())?;
+///                 let b = args[1].try_to::<i64>().map_err(|_| ())?;
+///                 Ok((a + b).to_variant())
+///             })
+///             .param::<i64>("a")
+///             .param::<i64>("b")
+///             .returns::<i64>()
+///             .done();
+///
+///         builder.signal("level_up").param::<i64>("level").done();
+///         builder.constant("MAX_LEVEL", 99);
+///     }
+/// }
+/// ```
 pub struct ClassBuilder<C> {
     _c: PhantomData<C>,
 }
@@ -22,29 +71,42 @@ where
         Self { _c: PhantomData }
     }
 
-    pub fn virtual_method<'cb, F>(&'cb mut self, name: &'cb str, method: F) -> MethodBuilder<C, F> {
-        MethodBuilder::new(self, name, method)
+    /// Adds a method named `name`, implemented by the closure `function`.
+    ///
+    /// The closure receives the object on which the method is called, as well as all arguments passed by the caller. Returning
+    /// `Err(())` reports an invalid-argument error to the caller.
+    ///
+    /// Parameter and return types are declared on the returned [`MethodBuilder`]; they are used for documentation and editor
+    /// integration, as well as for checking the number of arguments. The method is registered once [`MethodBuilder::done()`] is
+    /// called.
+    pub fn method<F>(&mut self, name: &str, function: F) -> MethodBuilder<C>
+    where
+        C: Inherits<C::Base>,
+        F: Fn(Gd<C>, &[&Variant]) -> Result<Variant, ()> + Send + Sync + 'static,
+    {
+        MethodBuilder::new(name, Box::new(function))
     }
-}
-
-// ----------------------------------------------------------------------------------------------------------------------------------------------
 
-#[allow(dead_code)] // TODO rm
-#[must_use]
-pub struct MethodBuilder<'cb, C, F> {
-    class_builder: &'cb mut ClassBuilder<C>,
-    name: &'cb str,
-    method: F,
-}
+    /// Adds a property named `name` of type `T`.
+    ///
+    /// Getter and setter are names of methods registered with Godot, declared on the returned [`PropertyBuilder`]. The property is
+    /// registered once [`PropertyBuilder::done()`] is called.
+    pub fn property<T: GodotConvert>(&mut self, name: &str) -> PropertyBuilder<C> {
+        PropertyBuilder::new::<T>(name)
+    }
 
-impl<'cb, C, F> MethodBuilder<'cb, C, F> {
-    pub(super) fn new(class_builder: &'cb mut ClassBuilder<C>, name: &'cb str, method: F) -> Self {
-        Self {
-            class_builder,
-            name,
-            method,
-        }
+    /// Adds a signal named `name`.
+    ///
+    /// Parameters are declared on the returned [`SignalBuilder`]. The signal is registered once [`SignalBuilder::done()`] is called.
+    pub fn signal(&mut self, name: &str) -> SignalBuilder<C> {
+        SignalBuilder::new(name)
     }
 
-    pub fn done(self) {}
+    /// Adds an integer constant named `name`.
+    ///
+    /// The constant is registered immediately.
+    pub fn constant(&mut self, name: &str, value: i64) {
+        let constant = IntegerConstant::new(StringName::from(name), value);
+        ExportConstant::new(C::class_name(), ConstantKind::Integer(constant)).register();
+    }
 }
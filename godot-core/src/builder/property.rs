@@ -0,0 +1,76 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+
+use crate::builtin::meta::{GodotConvert, GodotType, PropertyInfo};
+use crate::builtin::StringName;
+use crate::engine::global::PropertyUsageFlags;
+use crate::obj::GodotClass;
+use crate::property::PropertyHintInfo;
+use std::marker::PhantomData;
+
+/// Declares a property added through [`ClassBuilder::property()`][super::ClassBuilder::property].
+#[must_use = "the property is only registered once `done()` is called"]
+pub struct PropertyBuilder<C: GodotClass> {
+    info: PropertyInfo,
+    getter: StringName,
+    setter: StringName,
+    _c: PhantomData<C>,
+}
+
+impl<C: GodotClass> PropertyBuilder<C> {
+    pub(super) fn new<T: GodotConvert>(name: &str) -> Self {
+        Self {
+            info: <T::Via as GodotType>::property_info(name),
+            getter: StringName::default(),
+            setter: StringName::default(),
+            _c: PhantomData,
+        }
+    }
+
+    /// Name of the method used to read the property. Without getter, the property is write-only.
+    pub fn getter(mut self, method_name: &str) -> Self {
+        self.getter = StringName::from(method_name);
+        self
+    }
+
+    /// Name of the method used to write the property. Without setter, the property is read-only.
+    pub fn setter(mut self, method_name: &str) -> Self {
+        self.setter = StringName::from(method_name);
+        self
+    }
+
+    /// Editor hint, e.g. a range for numbers. Defaults to no hint.
+    pub fn hint(mut self, hint_info: PropertyHintInfo) -> Self {
+        self.info.hint = hint_info.hint;
+        self.info.hint_string = hint_info.hint_string;
+        self
+    }
+
+    /// Usage flags, e.g. whether the property is stored or shown in the editor. Defaults to `PROPERTY_USAGE_DEFAULT`.
+    pub fn usage(mut self, usage: PropertyUsageFlags) -> Self {
+        self.info.usage = usage;
+        self
+    }
+
+    /// Registers the property with Godot.
+    pub fn done(self) {
+        let class_name = C::class_name();
+        let property_info_sys = self.info.property_sys();
+
+        unsafe {
+            sys::interface_fn!(classdb_register_extension_class_property)(
+                sys::get_library(),
+                class_name.string_sys(),
+                std::ptr::addr_of!(property_info_sys),
+                self.setter.string_sys(),
+                self.getter.string_sys(),
+            );
+        }
+    }
+}
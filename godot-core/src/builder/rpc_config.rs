@@ -0,0 +1,64 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::{Dictionary, StringName};
+use crate::engine::multiplayer_api::RPCMode;
+use crate::engine::multiplayer_peer::TransferMode;
+use crate::engine::Node;
+use crate::obj::Gd;
+
+/// Configuration of a remote procedure call (RPC) on a node.
+///
+/// Usually declared through the `#[rpc]` attribute on a method in a `#[godot_api]` block, which applies it to every instance of the
+/// class before `ready()`. Can also be applied manually with [`configure_node()`][Self::configure_node].
+///
+/// The default values are the same as for GDScript's `@rpc` annotation.
+///
+/// _Godot equivalent: argument of [`Node::rpc_config()`][crate::engine::Node::rpc_config]_
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RpcConfig {
+    /// Which peers may call the method. Default: `AUTHORITY`.
+    pub rpc_mode: RPCMode,
+
+    /// Delivery guarantees of the call. Default: `UNRELIABLE`.
+    pub transfer_mode: TransferMode,
+
+    /// Whether the method is also called on the peer sending the RPC. Default: `false`.
+    pub call_local: bool,
+
+    /// Channel over which the call is sent. Default: `0`.
+    pub channel: u32,
+}
+
+impl RpcConfig {
+    /// Registers this configuration for the method `method_name` of `node`.
+    pub fn configure_node(&self, node: &mut Gd<Node>, method_name: StringName) {
+        node.rpc_config(method_name, self.to_dictionary().to_variant());
+    }
+
+    /// Returns the dictionary representation expected by Godot.
+    pub fn to_dictionary(&self) -> Dictionary {
+        let mut dict = Dictionary::new();
+        dict.set("rpc_mode", self.rpc_mode);
+        dict.set("transfer_mode", self.transfer_mode);
+        dict.set("call_local", self.call_local);
+        dict.set("channel", self.channel);
+        dict
+    }
+}
+
+impl Default for RpcConfig {
+    fn default() -> Self {
+        Self {
+            rpc_mode: RPCMode::RPC_MODE_AUTHORITY,
+            transfer_mode: TransferMode::TRANSFER_MODE_UNRELIABLE,
+            call_local: false,
+            channel: 0,
+        }
+    }
+}
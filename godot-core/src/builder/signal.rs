@@ -0,0 +1,57 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+
+use crate::builtin::meta::{GodotConvert, GodotType, PropertyInfo};
+use crate::builtin::StringName;
+use crate::obj::GodotClass;
+use std::marker::PhantomData;
+
+/// Declares a signal added through [`ClassBuilder::signal()`][super::ClassBuilder::signal].
+#[must_use = "the signal is only registered once `done()` is called"]
+pub struct SignalBuilder<C: GodotClass> {
+    name: StringName,
+    params: Vec<PropertyInfo>,
+    _c: PhantomData<C>,
+}
+
+impl<C: GodotClass> SignalBuilder<C> {
+    pub(super) fn new(name: &str) -> Self {
+        Self {
+            name: StringName::from(name),
+            params: Vec::new(),
+            _c: PhantomData,
+        }
+    }
+
+    /// Declares the next parameter, named `name` and of type `T`.
+    pub fn param<T: GodotConvert>(mut self, name: &str) -> Self {
+        self.params.push(<T::Via as GodotType>::property_info(name));
+        self
+    }
+
+    /// Registers the signal with Godot.
+    pub fn done(self) {
+        let class_name = C::class_name();
+        let params_sys: Vec<sys::GDExtensionPropertyInfo> = self
+            .params
+            .iter()
+            .map(|param| param.property_sys())
+            .collect();
+
+        unsafe {
+            sys::interface_fn!(classdb_register_extension_class_signal)(
+                sys::get_library(),
+                class_name.string_sys(),
+                self.name.string_sys(),
+                params_sys.as_ptr(),
+                params_sys.len() as sys::GDExtensionInt,
+            );
+        }
+    }
+}
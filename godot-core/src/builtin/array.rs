@@ -390,6 +390,19 @@ impl<T: GodotType + FromGodot> Array<T> {
         }
     }
 
+    /// Converts all elements to a `Vec`, failing on the first element that cannot be represented as `T`.
+    ///
+    /// Unlike `Vec::from(&array)`, this does not panic if e.g. an `Array<u8>` contains values above 255.
+    pub(crate) fn try_to_vec(&self) -> Result<Vec<T>, ConvertError> {
+        (0..self.len())
+            .map(|index| {
+                // SAFETY: `ptr()` verifies that the index is not out of bounds.
+                let variant = unsafe { &*self.ptr(index) };
+                T::try_from_variant(variant)
+            })
+            .collect()
+    }
+
     /// Returns the value at the specified index.
     ///
     /// # Panics
@@ -5,6 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::builtin::color_constants::NAMED_COLORS;
 use crate::builtin::inner::InnerColor;
 use crate::builtin::math::ApproxEq;
 use crate::builtin::GString;
@@ -39,7 +40,7 @@ pub struct Color {
 }
 
 impl Color {
-    // TODO implement all the other color constants using code generation
+    // Named colors such as `RED` or `CORNFLOWER_BLUE` are defined in color_constants.rs.
 
     /// Transparent black.
     pub const TRANSPARENT_BLACK: Color = Self::from_rgba(0.0, 0.0, 0.0, 0.0);
@@ -49,12 +50,6 @@ impl Color {
     /// _Godot equivalent: `Color.TRANSPARENT`_
     pub const TRANSPARENT_WHITE: Color = Self::from_rgba(1.0, 1.0, 1.0, 0.0);
 
-    /// Opaque black.
-    pub const BLACK: Color = Self::from_rgba(0.0, 0.0, 0.0, 1.0);
-
-    /// Opaque white.
-    pub const WHITE: Color = Self::from_rgba(1.0, 1.0, 1.0, 1.0);
-
     /// Constructs a new `Color` with the given components.
     pub const fn from_rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
         Self { r, g, b, a }
@@ -138,6 +133,22 @@ impl Color {
         }
     }
 
+    /// Looks up a named color constant, such as `"CORNFLOWER_BLUE"`, by its name.
+    ///
+    /// Matching follows Godot's rules: it is case-insensitive, and spaces, hyphens, underscores, apostrophes and dots are ignored.
+    /// So `"cornflower-blue"` and `"Cornflower Blue"` both find [`Color::CORNFLOWER_BLUE`]. Unlike [`Color::from_string`],
+    /// HTML color codes are not accepted, and the lookup does not call into Godot.
+    ///
+    /// Returns `None` if there is no color with that name.
+    ///
+    /// _Godot equivalent: `Color.from_string` restricted to names_
+    pub fn from_name(name: &str) -> Option<Self> {
+        NAMED_COLORS
+            .iter()
+            .find(|(color_name, _)| color_name_matches(color_name, name))
+            .map(|(_, color)| *color)
+    }
+
     /// Constructs a `Color` from an [HSV profile](https://en.wikipedia.org/wiki/HSL_and_HSV). The
     /// hue (`h`), saturation (`s`), and value (`v`) are typically between 0.0 and 1.0. Alpha is
     /// set to 1; use [`Color::with_alpha`] to change it.
@@ -212,7 +223,62 @@ impl Color {
         self.a = from_u8(a);
     }
 
-    // TODO add getters and setters for h, s, v (needs generated property wrappers)
+    /// Returns the hue of this color in the [HSV profile](https://en.wikipedia.org/wiki/HSL_and_HSV), between 0.0 and 1.0.
+    ///
+    /// Achromatic colors (shades of gray) have a hue of 0.
+    ///
+    /// _Godot equivalent: `Color.h`_
+    pub fn h(self) -> f64 {
+        let (min, max) = self.rgb_min_max();
+        let delta = max - min;
+        if delta == 0.0 {
+            return 0.0;
+        }
+
+        let (r, g, b) = (self.r as f64, self.g as f64, self.b as f64);
+        let sector = if r == max {
+            (g - b) / delta // between yellow and magenta
+        } else if g == max {
+            2.0 + (b - r) / delta // between cyan and yellow
+        } else {
+            4.0 + (r - g) / delta // between magenta and cyan
+        };
+
+        let h = sector / 6.0;
+        if h < 0.0 {
+            h + 1.0
+        } else {
+            h
+        }
+    }
+
+    /// Returns the saturation of this color in the [HSV profile](https://en.wikipedia.org/wiki/HSL_and_HSV), between 0.0 and 1.0.
+    ///
+    /// _Godot equivalent: `Color.s`_
+    pub fn s(self) -> f64 {
+        let (min, max) = self.rgb_min_max();
+        if max == 0.0 {
+            0.0
+        } else {
+            (max - min) / max
+        }
+    }
+
+    /// Returns the value (brightness) of this color in the [HSV profile](https://en.wikipedia.org/wiki/HSL_and_HSV), between 0.0
+    /// and 1.0 for non-HDR colors.
+    ///
+    /// _Godot equivalent: `Color.v`_
+    pub fn v(self) -> f64 {
+        let (_, max) = self.rgb_min_max();
+        max
+    }
+
+    /// Returns the hue, saturation and value of this color as a tuple `(h, s, v)`. The alpha channel is ignored.
+    ///
+    /// This is the inverse of [`Color::from_hsv`].
+    pub fn to_hsv(self) -> (f64, f64, f64) {
+        (self.h(), self.s(), self.v())
+    }
 
     /// Returns the light intensity of the color, as a value between 0.0 and 1.0 (inclusive). This
     /// is useful when determining whether a color is light or dark. Colors with a luminance
@@ -313,6 +379,12 @@ impl Color {
     fn as_inner(&self) -> InnerColor {
         InnerColor::from_outer(self)
     }
+
+    fn rgb_min_max(self) -> (f64, f64) {
+        let min = self.r.min(self.g).min(self.b);
+        let max = self.r.max(self.g).max(self.b);
+        (min as f64, max as f64)
+    }
 }
 
 // SAFETY:
@@ -329,8 +401,10 @@ impl_godot_as_self!(Color);
 
 impl ApproxEq for Color {
     fn approx_eq(&self, other: &Self) -> bool {
-        // TODO(bromeon): re-implement in Rust
-        self.as_inner().is_equal_approx(*other)
+        self.r.approx_eq(&other.r)
+            && self.g.approx_eq(&other.g)
+            && self.b.approx_eq(&other.b)
+            && self.a.approx_eq(&other.a)
     }
 }
 
@@ -517,6 +591,17 @@ fn to_be_words(mut u: u64) -> [u16; 4] {
     [x, y, z, w]
 }
 
+/// Compares a constant name like `LIGHT_SEA_GREEN` with user input, ignoring case and separator characters.
+fn color_name_matches(constant_name: &str, input: &str) -> bool {
+    fn normalized(name: &str) -> impl Iterator<Item = char> + '_ {
+        name.chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_' | '\'' | '.'))
+            .map(|c| c.to_ascii_uppercase())
+    }
+
+    normalized(constant_name).eq(normalized(input))
+}
+
 impl std::fmt::Display for Color {
     /// Formats `Color` to match Godot's string representation.
     ///
@@ -533,10 +618,53 @@ impl std::fmt::Display for Color {
 
 #[cfg(test)]
 mod test {
+    use super::Color;
+
+    #[test]
+    fn named_constants() {
+        assert_eq!(Color::RED, Color::from_rgb(1.0, 0.0, 0.0));
+        assert_eq!(Color::WHITE, Color::from_rgb(1.0, 1.0, 1.0));
+        assert_eq!(Color::TRANSPARENT, Color::TRANSPARENT_WHITE);
+        assert_eq!(
+            Color::CORNFLOWER_BLUE,
+            Color::from_rgb(100.0 / 255.0, 149.0 / 255.0, 237.0 / 255.0)
+        );
+    }
+
+    #[test]
+    fn from_name() {
+        assert_eq!(Color::from_name("RED"), Some(Color::RED));
+        assert_eq!(
+            Color::from_name("cornflower-blue"),
+            Some(Color::CORNFLOWER_BLUE)
+        );
+        assert_eq!(Color::from_name("Navy Blue"), Some(Color::NAVY_BLUE));
+        assert_eq!(Color::from_name("transparent"), Some(Color::TRANSPARENT));
+        assert_eq!(Color::from_name("not_a_color"), None);
+        assert_eq!(Color::from_name("#ff0000"), None);
+        assert_eq!(Color::from_name(""), None);
+    }
+
+    #[test]
+    fn hsv_getters() {
+        assert_eq!(Color::RED.to_hsv(), (0.0, 1.0, 1.0));
+        assert_eq!(Color::BLACK.to_hsv(), (0.0, 0.0, 0.0));
+        assert_eq!(Color::WEB_GRAY.s(), 0.0);
+
+        let (h, s, v) = Color::from_rgb(0.0, 0.5, 1.0).to_hsv();
+        assert!((h - 7.0 / 12.0).abs() < 1e-6);
+        assert_eq!(s, 1.0);
+        assert_eq!(v, 1.0);
+
+        // Magenta-ish hues wrap around instead of becoming negative.
+        let h = Color::from_rgb(1.0, 0.0, 0.5).h();
+        assert!((h - 11.0 / 12.0).abs() < 1e-6);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
-        let color = super::Color::WHITE;
+        let color = Color::WHITE;
         let expected_json = "{\"r\":1.0,\"g\":1.0,\"b\":1.0,\"a\":1.0}";
 
         crate::builtin::test_utils::roundtrip(&color, expected_json);
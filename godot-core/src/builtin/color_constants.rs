@@ -0,0 +1,486 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Named color constants, mirroring Godot's `Color` constants (`core/math/color_names.inc`).
+//!
+//! Values are X11 color names, with the web (CSS) variants of conflicting names prefixed with `WEB_`.
+
+use crate::builtin::Color;
+
+/// Converts a `0xRRGGBBAA` literal into the corresponding color channel, in a `const` context.
+macro_rules! hex_channel {
+    ($hex:literal, $shift:literal) => {{
+        let hex: u32 = $hex;
+        ((hex >> $shift) & 0xff) as f32 / 255.0
+    }};
+}
+
+macro_rules! impl_named_colors {
+    ($(
+        $( #[$attr:meta] )*
+        $NAME:ident = $hex:literal;
+    )*) => {
+        impl Color {
+            $(
+                $( #[$attr] )*
+                pub const $NAME: Color = Color::from_rgba(
+                    hex_channel!($hex, 24),
+                    hex_channel!($hex, 16),
+                    hex_channel!($hex, 8),
+                    hex_channel!($hex, 0),
+                );
+            )*
+        }
+
+        /// All named colors with their Godot names, sorted alphabetically.
+        pub(super) const NAMED_COLORS: &[(&str, Color)] = &[
+            $(
+                (stringify!($NAME), Color::$NAME),
+            )*
+        ];
+    };
+}
+
+impl_named_colors! {
+    /// Alice blue, `#F0F8FF`.
+    ALICE_BLUE = 0xF0F8FFFF;
+
+    /// Antique white, `#FAEBD7`.
+    ANTIQUE_WHITE = 0xFAEBD7FF;
+
+    /// Aqua, `#00FFFF`.
+    AQUA = 0x00FFFFFF;
+
+    /// Aquamarine, `#7FFFD4`.
+    AQUAMARINE = 0x7FFFD4FF;
+
+    /// Azure, `#F0FFFF`.
+    AZURE = 0xF0FFFFFF;
+
+    /// Beige, `#F5F5DC`.
+    BEIGE = 0xF5F5DCFF;
+
+    /// Bisque, `#FFE4C4`.
+    BISQUE = 0xFFE4C4FF;
+
+    /// Black, `#000000`.
+    BLACK = 0x000000FF;
+
+    /// Blanched almond, `#FFEBCD`.
+    BLANCHED_ALMOND = 0xFFEBCDFF;
+
+    /// Blue, `#0000FF`.
+    BLUE = 0x0000FFFF;
+
+    /// Blue violet, `#8A2BE2`.
+    BLUE_VIOLET = 0x8A2BE2FF;
+
+    /// Brown, `#A52A2A`.
+    BROWN = 0xA52A2AFF;
+
+    /// Burlywood, `#DEB887`.
+    BURLYWOOD = 0xDEB887FF;
+
+    /// Cadet blue, `#5F9EA0`.
+    CADET_BLUE = 0x5F9EA0FF;
+
+    /// Chartreuse, `#7FFF00`.
+    CHARTREUSE = 0x7FFF00FF;
+
+    /// Chocolate, `#D2691E`.
+    CHOCOLATE = 0xD2691EFF;
+
+    /// Coral, `#FF7F50`.
+    CORAL = 0xFF7F50FF;
+
+    /// Cornflower blue, `#6495ED`.
+    CORNFLOWER_BLUE = 0x6495EDFF;
+
+    /// Cornsilk, `#FFF8DC`.
+    CORNSILK = 0xFFF8DCFF;
+
+    /// Crimson, `#DC143C`.
+    CRIMSON = 0xDC143CFF;
+
+    /// Cyan, `#00FFFF`.
+    CYAN = 0x00FFFFFF;
+
+    /// Dark blue, `#00008B`.
+    DARK_BLUE = 0x00008BFF;
+
+    /// Dark cyan, `#008B8B`.
+    DARK_CYAN = 0x008B8BFF;
+
+    /// Dark goldenrod, `#B8860B`.
+    DARK_GOLDENROD = 0xB8860BFF;
+
+    /// Dark gray, `#A9A9A9`.
+    DARK_GRAY = 0xA9A9A9FF;
+
+    /// Dark green, `#006400`.
+    DARK_GREEN = 0x006400FF;
+
+    /// Dark khaki, `#BDB76B`.
+    DARK_KHAKI = 0xBDB76BFF;
+
+    /// Dark magenta, `#8B008B`.
+    DARK_MAGENTA = 0x8B008BFF;
+
+    /// Dark olive green, `#556B2F`.
+    DARK_OLIVE_GREEN = 0x556B2FFF;
+
+    /// Dark orange, `#FF8C00`.
+    DARK_ORANGE = 0xFF8C00FF;
+
+    /// Dark orchid, `#9932CC`.
+    DARK_ORCHID = 0x9932CCFF;
+
+    /// Dark red, `#8B0000`.
+    DARK_RED = 0x8B0000FF;
+
+    /// Dark salmon, `#E9967A`.
+    DARK_SALMON = 0xE9967AFF;
+
+    /// Dark sea green, `#8FBC8F`.
+    DARK_SEA_GREEN = 0x8FBC8FFF;
+
+    /// Dark slate blue, `#483D8B`.
+    DARK_SLATE_BLUE = 0x483D8BFF;
+
+    /// Dark slate gray, `#2F4F4F`.
+    DARK_SLATE_GRAY = 0x2F4F4FFF;
+
+    /// Dark turquoise, `#00CED1`.
+    DARK_TURQUOISE = 0x00CED1FF;
+
+    /// Dark violet, `#9400D3`.
+    DARK_VIOLET = 0x9400D3FF;
+
+    /// Deep pink, `#FF1493`.
+    DEEP_PINK = 0xFF1493FF;
+
+    /// Deep sky blue, `#00BFFF`.
+    DEEP_SKY_BLUE = 0x00BFFFFF;
+
+    /// Dim gray, `#696969`.
+    DIM_GRAY = 0x696969FF;
+
+    /// Dodger blue, `#1E90FF`.
+    DODGER_BLUE = 0x1E90FFFF;
+
+    /// Firebrick, `#B22222`.
+    FIREBRICK = 0xB22222FF;
+
+    /// Floral white, `#FFFAF0`.
+    FLORAL_WHITE = 0xFFFAF0FF;
+
+    /// Forest green, `#228B22`.
+    FOREST_GREEN = 0x228B22FF;
+
+    /// Fuchsia, `#FF00FF`.
+    FUCHSIA = 0xFF00FFFF;
+
+    /// Gainsboro, `#DCDCDC`.
+    GAINSBORO = 0xDCDCDCFF;
+
+    /// Ghost white, `#F8F8FF`.
+    GHOST_WHITE = 0xF8F8FFFF;
+
+    /// Gold, `#FFD700`.
+    GOLD = 0xFFD700FF;
+
+    /// Goldenrod, `#DAA520`.
+    GOLDENROD = 0xDAA520FF;
+
+    /// Gray, `#BEBEBE`.
+    GRAY = 0xBEBEBEFF;
+
+    /// Green, `#00FF00`.
+    GREEN = 0x00FF00FF;
+
+    /// Green yellow, `#ADFF2F`.
+    GREEN_YELLOW = 0xADFF2FFF;
+
+    /// Honeydew, `#F0FFF0`.
+    HONEYDEW = 0xF0FFF0FF;
+
+    /// Hot pink, `#FF69B4`.
+    HOT_PINK = 0xFF69B4FF;
+
+    /// Indian red, `#CD5C5C`.
+    INDIAN_RED = 0xCD5C5CFF;
+
+    /// Indigo, `#4B0082`.
+    INDIGO = 0x4B0082FF;
+
+    /// Ivory, `#FFFFF0`.
+    IVORY = 0xFFFFF0FF;
+
+    /// Khaki, `#F0E68C`.
+    KHAKI = 0xF0E68CFF;
+
+    /// Lavender, `#E6E6FA`.
+    LAVENDER = 0xE6E6FAFF;
+
+    /// Lavender blush, `#FFF0F5`.
+    LAVENDER_BLUSH = 0xFFF0F5FF;
+
+    /// Lawn green, `#7CFC00`.
+    LAWN_GREEN = 0x7CFC00FF;
+
+    /// Lemon chiffon, `#FFFACD`.
+    LEMON_CHIFFON = 0xFFFACDFF;
+
+    /// Light blue, `#ADD8E6`.
+    LIGHT_BLUE = 0xADD8E6FF;
+
+    /// Light coral, `#F08080`.
+    LIGHT_CORAL = 0xF08080FF;
+
+    /// Light cyan, `#E0FFFF`.
+    LIGHT_CYAN = 0xE0FFFFFF;
+
+    /// Light goldenrod, `#FAFAD2`.
+    LIGHT_GOLDENROD = 0xFAFAD2FF;
+
+    /// Light gray, `#D3D3D3`.
+    LIGHT_GRAY = 0xD3D3D3FF;
+
+    /// Light green, `#90EE90`.
+    LIGHT_GREEN = 0x90EE90FF;
+
+    /// Light pink, `#FFB6C1`.
+    LIGHT_PINK = 0xFFB6C1FF;
+
+    /// Light salmon, `#FFA07A`.
+    LIGHT_SALMON = 0xFFA07AFF;
+
+    /// Light sea green, `#20B2AA`.
+    LIGHT_SEA_GREEN = 0x20B2AAFF;
+
+    /// Light sky blue, `#87CEFA`.
+    LIGHT_SKY_BLUE = 0x87CEFAFF;
+
+    /// Light slate gray, `#778899`.
+    LIGHT_SLATE_GRAY = 0x778899FF;
+
+    /// Light steel blue, `#B0C4DE`.
+    LIGHT_STEEL_BLUE = 0xB0C4DEFF;
+
+    /// Light yellow, `#FFFFE0`.
+    LIGHT_YELLOW = 0xFFFFE0FF;
+
+    /// Lime, `#00FF00`.
+    LIME = 0x00FF00FF;
+
+    /// Lime green, `#32CD32`.
+    LIME_GREEN = 0x32CD32FF;
+
+    /// Linen, `#FAF0E6`.
+    LINEN = 0xFAF0E6FF;
+
+    /// Magenta, `#FF00FF`.
+    MAGENTA = 0xFF00FFFF;
+
+    /// Maroon, `#B03060`.
+    MAROON = 0xB03060FF;
+
+    /// Medium aquamarine, `#66CDAA`.
+    MEDIUM_AQUAMARINE = 0x66CDAAFF;
+
+    /// Medium blue, `#0000CD`.
+    MEDIUM_BLUE = 0x0000CDFF;
+
+    /// Medium orchid, `#BA55D3`.
+    MEDIUM_ORCHID = 0xBA55D3FF;
+
+    /// Medium purple, `#9370DB`.
+    MEDIUM_PURPLE = 0x9370DBFF;
+
+    /// Medium sea green, `#3CB371`.
+    MEDIUM_SEA_GREEN = 0x3CB371FF;
+
+    /// Medium slate blue, `#7B68EE`.
+    MEDIUM_SLATE_BLUE = 0x7B68EEFF;
+
+    /// Medium spring green, `#00FA9A`.
+    MEDIUM_SPRING_GREEN = 0x00FA9AFF;
+
+    /// Medium turquoise, `#48D1CC`.
+    MEDIUM_TURQUOISE = 0x48D1CCFF;
+
+    /// Medium violet red, `#C71585`.
+    MEDIUM_VIOLET_RED = 0xC71585FF;
+
+    /// Midnight blue, `#191970`.
+    MIDNIGHT_BLUE = 0x191970FF;
+
+    /// Mint cream, `#F5FFFA`.
+    MINT_CREAM = 0xF5FFFAFF;
+
+    /// Misty rose, `#FFE4E1`.
+    MISTY_ROSE = 0xFFE4E1FF;
+
+    /// Moccasin, `#FFE4B5`.
+    MOCCASIN = 0xFFE4B5FF;
+
+    /// Navajo white, `#FFDEAD`.
+    NAVAJO_WHITE = 0xFFDEADFF;
+
+    /// Navy blue, `#000080`.
+    NAVY_BLUE = 0x000080FF;
+
+    /// Old lace, `#FDF5E6`.
+    OLD_LACE = 0xFDF5E6FF;
+
+    /// Olive, `#808000`.
+    OLIVE = 0x808000FF;
+
+    /// Olive drab, `#6B8E23`.
+    OLIVE_DRAB = 0x6B8E23FF;
+
+    /// Orange, `#FFA500`.
+    ORANGE = 0xFFA500FF;
+
+    /// Orange red, `#FF4500`.
+    ORANGE_RED = 0xFF4500FF;
+
+    /// Orchid, `#DA70D6`.
+    ORCHID = 0xDA70D6FF;
+
+    /// Pale goldenrod, `#EEE8AA`.
+    PALE_GOLDENROD = 0xEEE8AAFF;
+
+    /// Pale green, `#98FB98`.
+    PALE_GREEN = 0x98FB98FF;
+
+    /// Pale turquoise, `#AFEEEE`.
+    PALE_TURQUOISE = 0xAFEEEEFF;
+
+    /// Pale violet red, `#DB7093`.
+    PALE_VIOLET_RED = 0xDB7093FF;
+
+    /// Papaya whip, `#FFEFD5`.
+    PAPAYA_WHIP = 0xFFEFD5FF;
+
+    /// Peach puff, `#FFDAB9`.
+    PEACH_PUFF = 0xFFDAB9FF;
+
+    /// Peru, `#CD853F`.
+    PERU = 0xCD853FFF;
+
+    /// Pink, `#FFC0CB`.
+    PINK = 0xFFC0CBFF;
+
+    /// Plum, `#DDA0DD`.
+    PLUM = 0xDDA0DDFF;
+
+    /// Powder blue, `#B0E0E6`.
+    POWDER_BLUE = 0xB0E0E6FF;
+
+    /// Purple, `#A020F0`.
+    PURPLE = 0xA020F0FF;
+
+    /// Rebecca purple, `#663399`.
+    REBECCA_PURPLE = 0x663399FF;
+
+    /// Red, `#FF0000`.
+    RED = 0xFF0000FF;
+
+    /// Rosy brown, `#BC8F8F`.
+    ROSY_BROWN = 0xBC8F8FFF;
+
+    /// Royal blue, `#4169E1`.
+    ROYAL_BLUE = 0x4169E1FF;
+
+    /// Saddle brown, `#8B4513`.
+    SADDLE_BROWN = 0x8B4513FF;
+
+    /// Salmon, `#FA8072`.
+    SALMON = 0xFA8072FF;
+
+    /// Sandy brown, `#F4A460`.
+    SANDY_BROWN = 0xF4A460FF;
+
+    /// Sea green, `#2E8B57`.
+    SEA_GREEN = 0x2E8B57FF;
+
+    /// Seashell, `#FFF5EE`.
+    SEASHELL = 0xFFF5EEFF;
+
+    /// Sienna, `#A0522D`.
+    SIENNA = 0xA0522DFF;
+
+    /// Silver, `#C0C0C0`.
+    SILVER = 0xC0C0C0FF;
+
+    /// Sky blue, `#87CEEB`.
+    SKY_BLUE = 0x87CEEBFF;
+
+    /// Slate blue, `#6A5ACD`.
+    SLATE_BLUE = 0x6A5ACDFF;
+
+    /// Slate gray, `#708090`.
+    SLATE_GRAY = 0x708090FF;
+
+    /// Snow, `#FFFAFA`.
+    SNOW = 0xFFFAFAFF;
+
+    /// Spring green, `#00FF7F`.
+    SPRING_GREEN = 0x00FF7FFF;
+
+    /// Steel blue, `#4682B4`.
+    STEEL_BLUE = 0x4682B4FF;
+
+    /// Tan, `#D2B48C`.
+    TAN = 0xD2B48CFF;
+
+    /// Teal, `#008080`.
+    TEAL = 0x008080FF;
+
+    /// Thistle, `#D8BFD8`.
+    THISTLE = 0xD8BFD8FF;
+
+    /// Tomato, `#FF6347`.
+    TOMATO = 0xFF6347FF;
+
+    /// Transparent white, `#FFFFFF00`. Same as [`TRANSPARENT_WHITE`][Self::TRANSPARENT_WHITE].
+    TRANSPARENT = 0xFFFFFF00;
+
+    /// Turquoise, `#40E0D0`.
+    TURQUOISE = 0x40E0D0FF;
+
+    /// Violet, `#EE82EE`.
+    VIOLET = 0xEE82EEFF;
+
+    /// Web gray, `#808080`.
+    WEB_GRAY = 0x808080FF;
+
+    /// Web green, `#008000`.
+    WEB_GREEN = 0x008000FF;
+
+    /// Web maroon, `#800000`.
+    WEB_MAROON = 0x800000FF;
+
+    /// Web purple, `#800080`.
+    WEB_PURPLE = 0x800080FF;
+
+    /// Wheat, `#F5DEB3`.
+    WHEAT = 0xF5DEB3FF;
+
+    /// White, `#FFFFFF`.
+    WHITE = 0xFFFFFFFF;
+
+    /// White smoke, `#F5F5F5`.
+    WHITE_SMOKE = 0xF5F5F5FF;
+
+    /// Yellow, `#FFFF00`.
+    YELLOW = 0xFFFF00FF;
+
+    /// Yellow green, `#9ACD32`.
+    YELLOW_GREEN = 0x9ACD32FF;
+}
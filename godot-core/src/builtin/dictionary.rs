@@ -7,7 +7,9 @@
 
 use godot_ffi as sys;
 
-use crate::builtin::meta::{FromGodot, ToGodot};
+use crate::builtin::meta::{
+    ConvertError, FromGodot, FromGodotError, GodotConvert, GodotType, ToGodot,
+};
 use crate::builtin::{inner, Variant, VariantArray};
 use crate::property::{Export, PropertyHintInfo, TypeStringHint, Var};
 use std::marker::PhantomData;
@@ -401,6 +403,358 @@ impl<K: ToGodot, V: ToGodot> FromIterator<(K, V)> for Dictionary {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Typed dictionary
+
+/// Dictionary with statically typed keys `K` and values `V`.
+///
+/// Godot versions supported by this crate have no notion of typed dictionaries, so the types are enforced on the Rust side: the
+/// typed API only accepts and returns `K` and `V`, and converting from an untyped [`Dictionary`] (including through [`FromGodot`]
+/// when passed from GDScript) checks every entry. Towards Godot, a `TypedDictionary` appears as a regular `Dictionary`; this also
+/// applies to the editor, when it is used with `#[export]`.
+///
+/// Like `Dictionary`, this type has reference semantics. If other code modifies the shared dictionary to hold entries of different
+/// types, the typed accessors panic when encountering them.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::{GString, TypedDictionary};
+///
+/// let mut scores = TypedDictionary::<GString, i64>::new();
+/// scores.insert("alice".into(), 12);
+///
+/// let score: Option<i64> = scores.get("alice".into());
+/// assert_eq!(score, Some(12));
+/// ```
+pub struct TypedDictionary<K, V> {
+    inner: Dictionary,
+    _types: PhantomData<fn() -> (K, V)>,
+}
+
+impl<K: GodotType, V: GodotType> TypedDictionary<K, V> {
+    /// Constructs an empty `TypedDictionary`.
+    pub fn new() -> Self {
+        Self::from_untyped_unchecked(Dictionary::new())
+    }
+
+    fn from_untyped_unchecked(inner: Dictionary) -> Self {
+        Self {
+            inner,
+            _types: PhantomData,
+        }
+    }
+
+    /// Converts an untyped dictionary, checking that all keys convert to `K` and all values convert to `V`.
+    ///
+    /// The result shares its data with `dictionary`; no copy is made.
+    pub fn try_from_untyped(dictionary: Dictionary) -> Result<Self, ConvertError> {
+        let bad_entry = dictionary.iter_shared().find(|(key, value)| {
+            K::try_from_variant(key).is_err() || V::try_from_variant(value).is_err()
+        });
+
+        match bad_entry {
+            None => Ok(Self::from_untyped_unchecked(dictionary)),
+            Some(entry) => Err(FromGodotError::BadDictionaryEntry {
+                key_type: std::any::type_name::<K>(),
+                value_type: std::any::type_name::<V>(),
+            }
+            .into_error(entry)),
+        }
+    }
+
+    /// Returns the underlying untyped dictionary, sharing its data.
+    pub fn as_untyped(&self) -> &Dictionary {
+        &self.inner
+    }
+
+    /// Converts into the underlying untyped dictionary, sharing its data.
+    pub fn into_untyped(self) -> Dictionary {
+        self.inner
+    }
+
+    /// Returns the number of entries in the dictionary.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Removes all key-value pairs from the dictionary.
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+
+    /// Returns a shallow copy of the dictionary, see [`Dictionary::duplicate_shallow()`].
+    pub fn duplicate_shallow(&self) -> Self {
+        Self::from_untyped_unchecked(self.inner.duplicate_shallow())
+    }
+
+    /// Returns a deep copy of the dictionary, see [`Dictionary::duplicate_deep()`].
+    pub fn duplicate_deep(&self) -> Self {
+        Self::from_untyped_unchecked(self.inner.duplicate_deep())
+    }
+
+    /// Returns the value for the given key, or `None`.
+    ///
+    /// # Panics
+    /// If the stored value cannot be converted to `V`.
+    pub fn get(&self, key: K) -> Option<V> {
+        self.inner.get(key).map(|value| V::from_variant(&value))
+    }
+
+    /// Returns `true` if the dictionary contains the given key.
+    ///
+    /// _Godot equivalent: `has`_
+    #[doc(alias = "has")]
+    pub fn contains_key(&self, key: K) -> bool {
+        self.inner.contains_key(key)
+    }
+
+    /// Insert a value at the given key, returning the previous value for that key (if available).
+    ///
+    /// If you don't need the previous value, use [`set()`][Self::set] instead.
+    ///
+    /// # Panics
+    /// If the previous value cannot be converted to `V`.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.inner
+            .insert(key, value)
+            .map(|old_value| V::from_variant(&old_value))
+    }
+
+    /// Set a key to a given value.
+    ///
+    /// _Godot equivalent: `dict[key] = value`_
+    pub fn set(&mut self, key: K, value: V) {
+        self.inner.set(key, value)
+    }
+
+    /// Removes a key from the dictionary, and returns the value associated with the key if it was present.
+    ///
+    /// # Panics
+    /// If the removed value cannot be converted to `V`.
+    ///
+    /// _Godot equivalent: `erase`_
+    #[doc(alias = "erase")]
+    pub fn remove(&mut self, key: K) -> Option<V> {
+        self.inner
+            .remove(key)
+            .map(|old_value| V::from_variant(&old_value))
+    }
+
+    /// Insert all the keys and values of another dictionary.
+    ///
+    /// If `overwrite` is true, entries with keys already present in `self` are replaced by the values in `other`.
+    ///
+    /// _Godot equivalent: `merge`_
+    #[doc(alias = "merge")]
+    pub fn extend_dictionary(&mut self, other: Self, overwrite: bool) {
+        self.inner.extend_dictionary(other.inner, overwrite)
+    }
+
+    /// Returns an iterator over the key-value pairs of the dictionary, see [`Dictionary::iter_shared()`].
+    ///
+    /// The iterator panics if it encounters an entry that cannot be converted to `(K, V)`.
+    pub fn iter_shared(&self) -> TypedIter<'_, K, V> {
+        self.inner.iter_shared().typed()
+    }
+
+    /// Returns an iterator over the keys of the dictionary, see [`Dictionary::keys_shared()`].
+    ///
+    /// The iterator panics if it encounters a key that cannot be converted to `K`.
+    pub fn keys_shared(&self) -> TypedKeys<'_, K> {
+        self.inner.keys_shared().typed()
+    }
+}
+
+impl<K: GodotType, V: GodotType> Default for TypedDictionary<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Creates a new reference to the data in this dictionary, like [`Dictionary::clone()`].
+impl<K, V> Clone for TypedDictionary<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            _types: PhantomData,
+        }
+    }
+}
+
+impl<K, V> PartialEq for TypedDictionary<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<K, V> fmt::Debug for TypedDictionary<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<K, V> fmt::Display for TypedDictionary<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl<K: GodotType, V: GodotType> GodotConvert for TypedDictionary<K, V> {
+    type Via = Dictionary;
+}
+
+impl<K: GodotType, V: GodotType> ToGodot for TypedDictionary<K, V> {
+    fn to_godot(&self) -> Self::Via {
+        self.inner.clone()
+    }
+
+    fn into_godot(self) -> Self::Via {
+        self.inner
+    }
+}
+
+impl<K: GodotType, V: GodotType> FromGodot for TypedDictionary<K, V> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Self::try_from_untyped(via)
+    }
+}
+
+impl<K: GodotType, V: GodotType> Var for TypedDictionary<K, V> {
+    type Intermediate = Self;
+
+    fn get_property(&self) -> Self::Intermediate {
+        self.clone()
+    }
+
+    fn set_property(&mut self, value: Self::Intermediate) {
+        *self = value;
+    }
+}
+
+impl<K: GodotType, V: GodotType> TypeStringHint for TypedDictionary<K, V> {
+    fn type_string() -> String {
+        Dictionary::type_string()
+    }
+}
+
+impl<K: GodotType, V: GodotType> Export for TypedDictionary<K, V> {
+    fn default_export_info() -> PropertyHintInfo {
+        Dictionary::default_export_info()
+    }
+}
+
+impl<K, V> From<TypedDictionary<K, V>> for Dictionary {
+    fn from(dictionary: TypedDictionary<K, V>) -> Self {
+        dictionary.inner
+    }
+}
+
+impl<K: GodotType, V: GodotType> Extend<(K, V)> for TypedDictionary<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl<K: GodotType, V: GodotType> FromIterator<(K, V)> for TypedDictionary<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut dict = Self::new();
+        dict.extend(iter);
+        dict
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use super::*;
+    use serde::de::{MapAccess, Visitor};
+    use serde::ser::SerializeMap;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serialized as a sequence of `[key, value]` pairs, since keys can be arbitrary variants, which formats like JSON
+    /// don't support as map keys. Use [`TypedDictionary`] with `GString` keys to get a map representation.
+    impl Serialize for Dictionary {
+        #[inline]
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(self.iter_shared())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Dictionary {
+        #[inline]
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let entries = Vec::<(Variant, Variant)>::deserialize(deserializer)?;
+            Ok(entries.into_iter().collect())
+        }
+    }
+
+    impl<K, V> Serialize for TypedDictionary<K, V>
+    where
+        K: GodotType + Serialize,
+        V: GodotType + Serialize,
+    {
+        #[inline]
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut map = serializer.serialize_map(Some(self.len()))?;
+            for (key, value) in self.iter_shared() {
+                map.serialize_entry(&key, &value)?;
+            }
+            map.end()
+        }
+    }
+
+    impl<'de, K, V> Deserialize<'de> for TypedDictionary<K, V>
+    where
+        K: GodotType + Deserialize<'de>,
+        V: GodotType + Deserialize<'de>,
+    {
+        #[inline]
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct TypedDictionaryVisitor<K, V>(PhantomData<(K, V)>);
+            impl<'de, K, V> Visitor<'de> for TypedDictionaryVisitor<K, V>
+            where
+                K: GodotType + Deserialize<'de>,
+                V: GodotType + Deserialize<'de>,
+            {
+                type Value = TypedDictionary<K, V>;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str(std::any::type_name::<Self::Value>())
+                }
+
+                fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+                where
+                    A: MapAccess<'de>,
+                {
+                    let mut dictionary = TypedDictionary::new();
+                    while let Some((key, value)) = map.next_entry::<K, V>()? {
+                        dictionary.set(key, value);
+                    }
+                    Ok(dictionary)
+                }
+            }
+
+            deserializer.deserialize_map(TypedDictionaryVisitor(PhantomData))
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Internal helper for different iterator impls -- not an iterator itself
@@ -0,0 +1,350 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::{Debug, Display};
+
+use crate::builtin::*;
+use crate::engine::global;
+use crate::godot_error;
+use crate::obj::{Gd, GodotClass};
+
+use super::{FromGodot, GodotType, ToGodot};
+
+/// Return type of a `#[func]` method, determining what is passed to Godot.
+///
+/// Implemented for all types that can be passed to Godot directly, which are returned unchanged. Additionally implemented for
+/// `Result<T, E>`, where `E` implements `Display`:
+/// * `Ok(value)` is passed to Godot like a regular return value.
+/// * `Err(err)` is reported as a script error, with the error's `Display` output as message. The function then returns a fallback
+///   value to Godot, determined by `T` -- see [`FuncResultOk`] -- unless overridden with `#[func(error_fallback = ...)]`, see
+///   [`FuncResult`].
+///
+/// This makes failures visible to GDScript callers, without resorting to panics.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::engine::{ResourceLoader, Texture2D};
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Skin {
+///     path: GString,
+/// }
+///
+/// #[godot_api]
+/// impl Skin {
+///     // Returns null to GDScript if the texture cannot be loaded.
+///     #[func]
+///     fn load_texture(&self) -> Result<Gd<Texture2D>, String> {
+///         ResourceLoader::singleton()
+///             .load(self.path.clone())
+///             .and_then(|res| res.try_cast::<Texture2D>().ok())
+///             .ok_or_else(|| format!("no texture at '{}'", self.path))
+///     }
+///
+///     // Returns Error.OK or Error.FAILED to GDScript.
+///     #[func]
+///     fn validate(&self) -> Result<(), String> {
+///         if self.path.is_empty() {
+///             return Err("path is empty".to_string());
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait FuncReturn: Sized {
+    /// Type that is returned to Godot.
+    type Via: ToGodot + FromGodot + Debug;
+
+    #[doc(hidden)]
+    fn into_func_return(self, method_name: &str) -> Self::Via;
+}
+
+impl<T> FuncReturn for T
+where
+    T: ToGodot + FromGodot + Debug,
+{
+    type Via = T;
+
+    fn into_func_return(self, _method_name: &str) -> Self::Via {
+        self
+    }
+}
+
+impl<T, E> FuncReturn for Result<T, E>
+where
+    T: FuncResultOk,
+    E: Display,
+{
+    type Via = T::Via;
+
+    fn into_func_return(self, method_name: &str) -> Self::Via {
+        self.into_func_return_or(method_name, T::error_fallback)
+    }
+}
+
+/// `Result<T, E>` returned from a `#[func]` method.
+///
+/// The value passed to Godot on `Err` can be overridden per method with `#[func(error_fallback = expr)]`, taking precedence over
+/// [`FuncResultOk::error_fallback()`]. `expr` must evaluate to the type seen by Godot, i.e. [`FuncResultOk::Via`] of `T`. This also
+/// works for built-in `Ok` types, for example to report a specific [`Error`][global::Error] code or `-1` instead of `0`:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::engine::global;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct SaveGame;
+///
+/// #[godot_api]
+/// impl SaveGame {
+///     // Returns Error.OK or Error.ERR_FILE_NOT_FOUND to GDScript.
+///     #[func(error_fallback = global::Error::ERR_FILE_NOT_FOUND)]
+///     fn load(&mut self, path: GString) -> Result<(), String> {
+///         Err(format!("no save game at '{path}'"))
+///     }
+///
+///     // Returns -1 to GDScript if the text is not a number.
+///     #[func(error_fallback = -1)]
+///     fn parse_slot(&self, text: GString) -> Result<i64, std::num::ParseIntError> {
+///         text.to_string().parse()
+///     }
+/// }
+/// ```
+pub trait FuncResult: FuncReturn {
+    #[doc(hidden)]
+    fn into_func_return_or(
+        self,
+        method_name: &str,
+        error_fallback: impl FnOnce() -> Self::Via,
+    ) -> Self::Via;
+}
+
+impl<T, E> FuncResult for Result<T, E>
+where
+    T: FuncResultOk,
+    E: Display,
+{
+    fn into_func_return_or(
+        self,
+        method_name: &str,
+        error_fallback: impl FnOnce() -> Self::Via,
+    ) -> Self::Via {
+        match self {
+            Ok(value) => value.into_ok_return(),
+            Err(err) => {
+                godot_error!("{method_name}: {err}");
+                error_fallback()
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Type `T` in `Result<T, E>` returned from a `#[func]` method; determines what Godot receives in the error case.
+///
+/// The following fallbacks are used if the method returns `Err`:
+///
+/// | `Ok` type                                                  | Type seen by Godot       | Value on error               |
+/// |------------------------------------------------------------|--------------------------|------------------------------|
+/// | `Gd<T>`, `Option<Gd<T>>`                                   | `T` (nullable)           | `null`                       |
+/// | `()`                                                       | [`Error`][global::Error] | `FAILED` (`OK` on success)   |
+/// | `bool`, `i8`-`i64`, `u8`-`u64`, `f32`, `f64`               | same as `Ok` type        | `false`, `0`, `0.0`          |
+/// | `Variant`                                                  | `Variant`                | `null`                       |
+/// | `GString`, `StringName`, `NodePath`                        | same as `Ok` type        | empty string/path            |
+/// | `Color`, vectors, `Rect2`, `Rect2i`, `Aabb`, `Quaternion`, <br> `Basis`, `Transform2D`, `Transform3D`, `Projection` | same as `Ok` type | default value (e.g. zero vector, identity) |
+/// | `Plane`                                                    | `Plane`                  | `Plane()`, i.e. zero normal  |
+/// | `Rid`                                                      | `RID`                    | invalid RID                  |
+/// | `Callable`, `Signal`                                       | same as `Ok` type        | null callable/signal         |
+/// | `Array<T>`, `Dictionary`, `TypedDictionary<K, V>`, packed arrays | same as `Ok` type  | empty collection             |
+/// | `Vec<T>`, `HashSet<T>`, `HashMap<K, V>`, `BTreeMap<K, V>`  | converted as usual       | empty collection             |
+/// | `[T; N]`, tuples                                           | converted as usual       | default value of each element |
+///
+/// You can implement this trait for your own types, to define a different fallback. To override the fallback of a single method,
+/// including for the types above, use `#[func(error_fallback = ...)]` as described in [`FuncResult`].
+pub trait FuncResultOk: Sized {
+    /// Type that is returned to Godot, both in the success and the error case.
+    type Via: ToGodot + FromGodot + Debug;
+
+    /// Converts the successfully returned value.
+    fn into_ok_return(self) -> Self::Via;
+
+    /// Value returned to Godot if the method returns `Err`.
+    fn error_fallback() -> Self::Via;
+}
+
+impl<T: GodotClass> FuncResultOk for Gd<T> {
+    type Via = Option<Gd<T>>;
+
+    fn into_ok_return(self) -> Self::Via {
+        Some(self)
+    }
+
+    fn error_fallback() -> Self::Via {
+        None
+    }
+}
+
+impl<T: GodotClass> FuncResultOk for Option<Gd<T>> {
+    type Via = Self;
+
+    fn into_ok_return(self) -> Self::Via {
+        self
+    }
+
+    fn error_fallback() -> Self::Via {
+        None
+    }
+}
+
+impl FuncResultOk for () {
+    type Via = global::Error;
+
+    fn into_ok_return(self) -> Self::Via {
+        global::Error::OK
+    }
+
+    fn error_fallback() -> Self::Via {
+        global::Error::FAILED
+    }
+}
+
+impl<T, const N: usize> FuncResultOk for [T; N]
+where
+    T: Default,
+    Self: ToGodot + FromGodot + Debug,
+{
+    type Via = Self;
+
+    fn into_ok_return(self) -> Self::Via {
+        self
+    }
+
+    fn error_fallback() -> Self::Via {
+        std::array::from_fn(|_| T::default())
+    }
+}
+
+macro_rules! impl_func_result_ok_default {
+    // Generic arm first: it fails on the first token for non-generic types, while a failed `ty` fragment would abort matching.
+    ($([$($generics:tt)*] $Type:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> FuncResultOk for $Type
+            where
+                Self: ToGodot + FromGodot + Debug + Default,
+            {
+                type Via = Self;
+
+                fn into_ok_return(self) -> Self::Via {
+                    self
+                }
+
+                fn error_fallback() -> Self::Via {
+                    Self::default()
+                }
+            }
+        )*
+    };
+
+    ($($Type:ty),* $(,)?) => {
+        $(
+            impl_func_result_ok_default!([] $Type);
+        )*
+    };
+}
+
+macro_rules! impl_func_result_ok_fallback {
+    ($($Type:ty => $fallback:expr),* $(,)?) => {
+        $(
+            impl FuncResultOk for $Type {
+                type Via = Self;
+
+                fn into_ok_return(self) -> Self::Via {
+                    self
+                }
+
+                fn error_fallback() -> Self::Via {
+                    $fallback
+                }
+            }
+        )*
+    };
+}
+
+impl_func_result_ok_default!(
+    bool,
+    i8,
+    i16,
+    i32,
+    i64,
+    u8,
+    u16,
+    u32,
+    u64,
+    f32,
+    f64,
+    Variant,
+    GString,
+    StringName,
+    NodePath,
+    Dictionary,
+    Color,
+    Vector2,
+    Vector2i,
+    Vector3,
+    Vector3i,
+    Vector4,
+    Vector4i,
+    Rect2,
+    Rect2i,
+    Aabb,
+    Quaternion,
+    Basis,
+    Transform2D,
+    Transform3D,
+    Projection,
+    PackedByteArray,
+    PackedInt32Array,
+    PackedInt64Array,
+    PackedFloat32Array,
+    PackedFloat64Array,
+    PackedStringArray,
+    PackedVector2Array,
+    PackedVector3Array,
+    PackedColorArray,
+);
+
+impl_func_result_ok_default!(
+    [T: GodotType] Array<T>,
+    [K: GodotType, V: GodotType] TypedDictionary<K, V>,
+    [T] Vec<T>,
+    [T, S] HashSet<T, S>,
+    [K, V, S] HashMap<K, V, S>,
+    [K, V] BTreeMap<K, V>,
+    [T0] (T0,),
+    [T0, T1] (T0, T1),
+    [T0, T1, T2] (T0, T1, T2),
+    [T0, T1, T2, T3] (T0, T1, T2, T3),
+    [T0, T1, T2, T3, T4] (T0, T1, T2, T3, T4),
+    [T0, T1, T2, T3, T4, T5] (T0, T1, T2, T3, T4, T5),
+    [T0, T1, T2, T3, T4, T5, T6] (T0, T1, T2, T3, T4, T5, T6),
+    [T0, T1, T2, T3, T4, T5, T6, T7] (T0, T1, T2, T3, T4, T5, T6, T7),
+    [T0, T1, T2, T3, T4, T5, T6, T7, T8] (T0, T1, T2, T3, T4, T5, T6, T7, T8),
+    [T0, T1, T2, T3, T4, T5, T6, T7, T8, T9] (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9),
+    [T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10] (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10),
+    [T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11] (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11),
+);
+
+// No meaningful default values; use the same values as the default constructors in GDScript.
+impl_func_result_ok_fallback!(
+    Rid => Rid::Invalid,
+    Callable => Callable::invalid(),
+    Signal => Signal::invalid(),
+    Plane => Plane::invalid(),
+);
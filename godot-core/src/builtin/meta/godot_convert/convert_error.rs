@@ -145,6 +145,16 @@ pub(crate) enum FromGodotError {
         expected: array_inner::TypeInfo,
         got: array_inner::TypeInfo,
     },
+    /// A fixed-size Rust array or tuple received a collection with a different number of elements.
+    BadArrayLength {
+        expected: usize,
+        got: usize,
+    },
+    /// A `TypedDictionary` entry whose key or value could not be converted.
+    BadDictionaryEntry {
+        key_type: &'static str,
+        value_type: &'static str,
+    },
     /// InvalidEnum is also used by bitfields.
     InvalidEnum,
     ZeroInstanceId,
@@ -192,6 +202,15 @@ impl FromGodotError {
                     got.class_name()
                 )
             }
+            Self::BadArrayLength { expected, got } => {
+                format!("expected array of length {expected}, got array of length {got}")
+            }
+            Self::BadDictionaryEntry {
+                key_type,
+                value_type,
+            } => format!(
+                "expected dictionary with keys of type {key_type} and values of type {value_type}, got incompatible entry"
+            ),
             Self::InvalidEnum => "invalid engine enum value".into(),
             Self::ZeroInstanceId => "`InstanceId` cannot be 0".into(),
         }
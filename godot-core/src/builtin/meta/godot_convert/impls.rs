@@ -5,10 +5,15 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
+
 use crate::builtin::meta::{
-    impl_godot_as_self, ConvertError, FromGodot, GodotConvert, GodotType, ToGodot,
+    impl_godot_as_self, CollectionElement, ConvertError, FromGodot, FromGodotError, GodotConvert,
+    GodotType, ToGodot,
 };
-use crate::builtin::Variant;
+use crate::builtin::*;
+use crate::obj::{Gd, GodotClass};
 use godot_ffi as sys;
 
 // The following ToGodot/FromGodot/Convert impls are auto-generated for each engine type, co-located with their definitions:
@@ -299,3 +304,288 @@ impl FromGodot for *mut std::ffi::c_void {
         Ok(via as Self)
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Collection elements
+
+macro_rules! impl_collection_element {
+    (packed: $( $T:ty => $Packed:ty ),* $(,)?) => {
+        $(
+            impl CollectionElement for $T {
+                type Collection = $Packed;
+
+                fn collection_from_iter<I>(iter: I) -> Self::Collection
+                where
+                    I: IntoIterator<Item = Self>,
+                {
+                    iter.into_iter().collect()
+                }
+
+                fn try_collection_to_vec(collection: &Self::Collection) -> Result<Vec<Self>, ConvertError> {
+                    Ok(collection.to_vec())
+                }
+            }
+        )*
+    };
+
+    (array: $( $T:ty ),* $(,)?) => {
+        $(
+            impl_collection_element!(@array [] $T);
+        )*
+    };
+
+    (@array [$($generics:tt)*] $T:ty $(where $($bounds:tt)+)?) => {
+        impl<$($generics)*> CollectionElement for $T
+        $(where $($bounds)+)?
+        {
+            type Collection = Array<Self>;
+
+            fn collection_from_iter<I>(iter: I) -> Self::Collection
+            where
+                I: IntoIterator<Item = Self>,
+            {
+                iter.into_iter().collect()
+            }
+
+            fn try_collection_to_vec(collection: &Self::Collection) -> Result<Vec<Self>, ConvertError> {
+                collection.try_to_vec()
+            }
+        }
+    };
+}
+
+impl_collection_element!(packed:
+    u8 => PackedByteArray,
+    i32 => PackedInt32Array,
+    i64 => PackedInt64Array,
+    f32 => PackedFloat32Array,
+    f64 => PackedFloat64Array,
+    GString => PackedStringArray,
+    Vector2 => PackedVector2Array,
+    Vector3 => PackedVector3Array,
+    Color => PackedColorArray,
+);
+
+impl_collection_element!(array:
+    bool, i8, i16, u16, u32, u64,
+    Aabb, Basis, Callable, Plane, Projection, Quaternion, Rect2, Rect2i, Rid, Signal, Transform2D, Transform3D,
+    Vector2i, Vector3i, Vector4, Vector4i,
+    StringName, NodePath, Variant, Dictionary,
+    PackedByteArray, PackedInt32Array, PackedInt64Array, PackedFloat32Array, PackedFloat64Array,
+    PackedStringArray, PackedVector2Array, PackedVector3Array, PackedColorArray,
+);
+
+impl_collection_element!(@array [T: GodotType] Array<T>);
+impl_collection_element!(@array [T: GodotClass] Gd<T>);
+impl_collection_element!(@array [T] Option<T> where T: GodotType, T::Ffi: sys::GodotNullableFfi);
+
+/// Converts the elements of a Godot collection back to their Rust type, failing on the first element that cannot be converted.
+fn try_vec_from_collection<T>(
+    collection: &<T::Via as CollectionElement>::Collection,
+) -> Result<Vec<T>, ConvertError>
+where
+    T: FromGodot,
+    T::Via: CollectionElement,
+{
+    <T::Via as CollectionElement>::try_collection_to_vec(collection)?
+        .into_iter()
+        .map(T::try_from_godot)
+        .collect()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Vec and fixed-size arrays
+
+impl<T: GodotConvert> GodotConvert for Vec<T>
+where
+    T::Via: CollectionElement,
+{
+    type Via = <T::Via as CollectionElement>::Collection;
+}
+
+impl<T: ToGodot> ToGodot for Vec<T>
+where
+    T::Via: CollectionElement,
+{
+    fn to_godot(&self) -> Self::Via {
+        CollectionElement::collection_from_iter(self.iter().map(ToGodot::to_godot))
+    }
+
+    fn into_godot(self) -> Self::Via {
+        CollectionElement::collection_from_iter(self.into_iter().map(ToGodot::into_godot))
+    }
+}
+
+impl<T: FromGodot> FromGodot for Vec<T>
+where
+    T::Via: CollectionElement,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        try_vec_from_collection(&via)
+    }
+}
+
+impl<T: GodotConvert, const N: usize> GodotConvert for [T; N]
+where
+    T::Via: CollectionElement,
+{
+    type Via = <T::Via as CollectionElement>::Collection;
+}
+
+impl<T: ToGodot, const N: usize> ToGodot for [T; N]
+where
+    T::Via: CollectionElement,
+{
+    fn to_godot(&self) -> Self::Via {
+        CollectionElement::collection_from_iter(self.iter().map(ToGodot::to_godot))
+    }
+
+    fn into_godot(self) -> Self::Via {
+        CollectionElement::collection_from_iter(self.into_iter().map(ToGodot::into_godot))
+    }
+}
+
+impl<T: FromGodot, const N: usize> FromGodot for [T; N]
+where
+    T::Via: CollectionElement,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        let elements = try_vec_from_collection::<T>(&via)?;
+
+        let got = elements.len();
+        elements
+            .try_into()
+            .map_err(|_| FromGodotError::BadArrayLength { expected: N, got }.into_error(via))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Sets and maps
+
+impl<T: GodotConvert, S> GodotConvert for HashSet<T, S>
+where
+    T::Via: CollectionElement,
+{
+    type Via = <T::Via as CollectionElement>::Collection;
+}
+
+impl<T: ToGodot, S> ToGodot for HashSet<T, S>
+where
+    T::Via: CollectionElement,
+{
+    fn to_godot(&self) -> Self::Via {
+        CollectionElement::collection_from_iter(self.iter().map(ToGodot::to_godot))
+    }
+
+    fn into_godot(self) -> Self::Via {
+        CollectionElement::collection_from_iter(self.into_iter().map(ToGodot::into_godot))
+    }
+}
+
+impl<T, S> FromGodot for HashSet<T, S>
+where
+    T: FromGodot + Eq + Hash,
+    T::Via: CollectionElement,
+    S: BuildHasher + Default,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        try_vec_from_collection(&via).map(|elements| elements.into_iter().collect())
+    }
+}
+
+impl<K: GodotConvert, V: GodotConvert, S> GodotConvert for HashMap<K, V, S> {
+    type Via = Dictionary;
+}
+
+impl<K: ToGodot, V: ToGodot, S> ToGodot for HashMap<K, V, S> {
+    fn to_godot(&self) -> Self::Via {
+        self.iter()
+            .map(|(key, value)| (key.to_variant(), value.to_variant()))
+            .collect()
+    }
+}
+
+impl<K, V, S> FromGodot for HashMap<K, V, S>
+where
+    K: FromGodot + Eq + Hash,
+    V: FromGodot,
+    S: BuildHasher + Default,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        via.iter_shared()
+            .map(|(key, value)| Ok((K::try_from_variant(&key)?, V::try_from_variant(&value)?)))
+            .collect()
+    }
+}
+
+impl<K: GodotConvert, V: GodotConvert> GodotConvert for BTreeMap<K, V> {
+    type Via = Dictionary;
+}
+
+impl<K: ToGodot, V: ToGodot> ToGodot for BTreeMap<K, V> {
+    fn to_godot(&self) -> Self::Via {
+        self.iter()
+            .map(|(key, value)| (key.to_variant(), value.to_variant()))
+            .collect()
+    }
+}
+
+impl<K, V> FromGodot for BTreeMap<K, V>
+where
+    K: FromGodot + Ord,
+    V: FromGodot,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        via.iter_shared()
+            .map(|(key, value)| Ok((K::try_from_variant(&key)?, V::try_from_variant(&value)?)))
+            .collect()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tuples
+
+// Tuples are passed as untyped arrays, with one element per field. `()` is a Godot type of its own and represents nil.
+macro_rules! impl_tuple {
+    ($len:literal: $( $T:ident $idx:tt ),+) => {
+        impl<$( $T: GodotConvert ),+> GodotConvert for ($( $T, )+) {
+            type Via = VariantArray;
+        }
+
+        impl<$( $T: ToGodot ),+> ToGodot for ($( $T, )+) {
+            fn to_godot(&self) -> Self::Via {
+                let mut array = VariantArray::new();
+                $(
+                    array.push(self.$idx.to_variant());
+                )+
+                array
+            }
+        }
+
+        impl<$( $T: FromGodot ),+> FromGodot for ($( $T, )+) {
+            fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+                if via.len() != $len {
+                    let got = via.len();
+                    return Err(FromGodotError::BadArrayLength { expected: $len, got }.into_error(via));
+                }
+
+                Ok(($(
+                    $T::try_from_variant(&via.get($idx))?,
+                )+))
+            }
+        }
+    };
+}
+
+impl_tuple!(1: T0 0);
+impl_tuple!(2: T0 0, T1 1);
+impl_tuple!(3: T0 0, T1 1, T2 2);
+impl_tuple!(4: T0 0, T1 1, T2 2, T3 3);
+impl_tuple!(5: T0 0, T1 1, T2 2, T3 3, T4 4);
+impl_tuple!(6: T0 0, T1 1, T2 2, T3 3, T4 4, T5 5);
+impl_tuple!(7: T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6);
+impl_tuple!(8: T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7);
+impl_tuple!(9: T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8);
+impl_tuple!(10: T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9);
+impl_tuple!(11: T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10);
+impl_tuple!(12: T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10, T11 11);
@@ -10,6 +10,8 @@ mod impls;
 
 pub use convert_error::ConvertError;
 
+use std::fmt;
+
 use crate::builtin::Variant;
 
 use super::{GodotFfiVariant, GodotType};
@@ -86,6 +88,31 @@ pub trait FromGodot: Sized + GodotConvert {
     }
 }
 
+/// Godot type which can be stored in a Godot collection, used to represent Rust sequences such as `Vec<T>`.
+///
+/// Element types with a dedicated packed array use that as their collection, e.g. `u8` is stored in a [`PackedByteArray`]
+/// and [`GString`] in a [`PackedStringArray`]. All other element types are stored in a typed [`Array<T>`].
+///
+/// This trait is implemented for all [`GodotType`]s except `()` and cannot be implemented by users. Rust collections of
+/// custom types are converted through the element's [`GodotConvert::Via`] type.
+///
+/// [`PackedByteArray`]: crate::builtin::PackedByteArray
+/// [`GString`]: crate::builtin::GString
+/// [`PackedStringArray`]: crate::builtin::PackedStringArray
+/// [`Array<T>`]: crate::builtin::Array
+pub trait CollectionElement: GodotType {
+    /// The Godot collection holding elements of this type.
+    type Collection: GodotType + fmt::Debug;
+
+    #[doc(hidden)]
+    fn collection_from_iter<I>(iter: I) -> Self::Collection
+    where
+        I: IntoIterator<Item = Self>;
+
+    #[doc(hidden)]
+    fn try_collection_to_vec(collection: &Self::Collection) -> Result<Vec<Self>, ConvertError>;
+}
+
 pub(crate) fn into_ffi<T: ToGodot>(value: T) -> <T::Via as GodotType>::Ffi {
     value.into_godot().into_ffi()
 }
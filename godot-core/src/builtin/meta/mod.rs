@@ -8,11 +8,13 @@
 pub mod registration;
 
 mod class_name;
+mod func_return;
 mod godot_convert;
 mod return_marshal;
 mod signature;
 
 pub use class_name::*;
+pub use func_return::*;
 pub(crate) use godot_convert::convert_error::*;
 pub use godot_convert::*;
 #[doc(hidden)]
@@ -28,6 +28,7 @@ impl MethodParamOrReturnInfo {
 pub struct ClassMethodInfo {
     class_name: ClassName,
     method_name: StringName,
+    method_userdata: *mut std::ffi::c_void,
     call_func: sys::GDExtensionClassMethodCall,
     ptrcall_func: sys::GDExtensionClassMethodPtrCall,
     method_flags: MethodFlags,
@@ -87,6 +88,7 @@ impl ClassMethodInfo {
         Self {
             class_name,
             method_name,
+            method_userdata: std::ptr::null_mut(),
             call_func,
             ptrcall_func,
             method_flags,
@@ -96,6 +98,41 @@ impl ClassMethodInfo {
         }
     }
 
+    /// Method info for a method whose signature is only known at runtime.
+    ///
+    /// Godot passes `method_userdata` to `call_func` and `ptrcall_func` on every invocation.
+    ///
+    /// # Safety
+    ///
+    /// `call_func` and `ptrcall_func`, if provided, must:
+    ///
+    /// - Interpret their parameters according to `arguments`, and return a value according to `return_value`.
+    /// - Be able to interpret `method_userdata`, which must stay valid as long as the method is registered.
+    /// - Follow the behavior expected from the `method_flags`.
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn from_runtime_info(
+        class_name: ClassName,
+        method_name: StringName,
+        method_userdata: *mut std::ffi::c_void,
+        call_func: sys::GDExtensionClassMethodCall,
+        ptrcall_func: sys::GDExtensionClassMethodPtrCall,
+        method_flags: MethodFlags,
+        return_value: Option<MethodParamOrReturnInfo>,
+        arguments: Vec<MethodParamOrReturnInfo>,
+    ) -> Self {
+        Self {
+            class_name,
+            method_name,
+            method_userdata,
+            call_func,
+            ptrcall_func,
+            method_flags,
+            return_value,
+            arguments,
+            default_arguments: Vec::new(),
+        }
+    }
+
     pub fn register_extension_class_method(&self) {
         use crate::obj::EngineBitfield as _;
 
@@ -123,7 +160,7 @@ impl ClassMethodInfo {
 
         let method_info_sys = sys::GDExtensionClassMethodInfo {
             name: self.method_name.string_sys(),
-            method_userdata: std::ptr::null_mut(),
+            method_userdata: self.method_userdata,
             call_func: self.call_func,
             ptrcall_func: self.ptrcall_func,
             method_flags: self.method_flags.ord() as u32,
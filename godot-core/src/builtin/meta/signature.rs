@@ -29,13 +29,18 @@ pub trait VarcallSignatureTuple: PtrcallSignatureTuple {
     // TODO(uninit) - can we use this for varcall/ptrcall?
     // ret: sys::GDExtensionUninitializedVariantPtr
     // ret: sys::GDExtensionUninitializedTypePtr
+    //
+    // `default_values` returns the default values of the trailing parameters; they are only evaluated if the caller omits arguments.
+    #[allow(clippy::too_many_arguments)]
     unsafe fn in_varcall(
         instance_ptr: sys::GDExtensionClassInstancePtr,
         method_name: &str,
         args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: sys::GDExtensionInt,
         ret: sys::GDExtensionVariantPtr,
         err: *mut sys::GDExtensionCallError,
         func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
+        default_values: fn() -> Vec<Variant>,
     );
 
     unsafe fn out_class_varcall(
@@ -158,11 +163,18 @@ macro_rules! impl_varcall_signature_for_tuple {
                 instance_ptr: sys::GDExtensionClassInstancePtr,
                 method_name: &str,
                 args_ptr: *const sys::GDExtensionConstVariantPtr,
+                arg_count: sys::GDExtensionInt,
                 ret: sys::GDExtensionVariantPtr,
                 err: *mut sys::GDExtensionCallError,
                 func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
+                default_values: fn() -> Vec<Variant>,
             ) {
                 //$crate::out!("in_varcall: {method_name}");
+                let Some(padded_args) = VarcallArgs::new(args_ptr, arg_count, $PARAM_COUNT, default_values, err) else {
+                    return;
+                };
+                let args_ptr = padded_args.args_ptr(args_ptr);
+
                 let args = ($(
                     unsafe { varcall_arg::<$Pn, $n>(args_ptr, method_name) },
                 )*) ;
@@ -379,6 +391,116 @@ macro_rules! impl_ptrcall_signature_for_tuple {
     };
 }
 
+/// Performs an incoming varcall into a `#[func(vararg)]` method, which receives all arguments as a slice.
+///
+/// # Safety
+/// - `args_ptr` must point to `arg_count` valid variant pointers.
+/// - `ret` must be a pointer to an initialized `Variant`.
+/// - It must be safe to write a `sys::GDExtensionCallError` once to `err`.
+#[doc(hidden)]
+pub unsafe fn in_varcall_varargs<R: ToGodot>(
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: sys::GDExtensionInt,
+    ret: sys::GDExtensionVariantPtr,
+    err: *mut sys::GDExtensionCallError,
+    func: fn(sys::GDExtensionClassInstancePtr, (&[Variant],)) -> R,
+) {
+    // Godot may pass a null pointer if there are no arguments.
+    let args: Vec<Variant> = if arg_count == 0 {
+        Vec::new()
+    } else {
+        Variant::unbounded_refs_from_sys(args_ptr, arg_count as usize)
+            .iter()
+            .map(|&arg| arg.clone())
+            .collect()
+    };
+
+    let rust_result = func(instance_ptr, (args.as_slice(),));
+    varcall_return::<R>(rust_result, ret, err)
+}
+
+/// Arguments of an incoming varcall, extended by default values if the caller omitted trailing arguments.
+struct VarcallArgs {
+    /// Pointers to all arguments, or empty if the caller passed all arguments.
+    padded_ptrs: Vec<sys::GDExtensionConstVariantPtr>,
+
+    /// Default values referenced by `padded_ptrs`.
+    _default_values: Vec<Variant>,
+}
+
+impl VarcallArgs {
+    /// Checks the number of arguments, and fills in default values for omitted ones.
+    ///
+    /// Returns `None` and sets `err` if too few or too many arguments were passed.
+    ///
+    /// # Safety
+    /// - `args_ptr` must point to `arg_count` valid variant pointers.
+    /// - It must be safe to write a `sys::GDExtensionCallError` once to `err`.
+    unsafe fn new(
+        args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: sys::GDExtensionInt,
+        param_count: usize,
+        default_values: fn() -> Vec<Variant>,
+        err: *mut sys::GDExtensionCallError,
+    ) -> Option<Self> {
+        let arg_count = arg_count as usize;
+
+        if arg_count > param_count {
+            (*err).error = sys::GDEXTENSION_CALL_ERROR_TOO_MANY_ARGUMENTS;
+            (*err).expected = param_count as i32;
+            return None;
+        }
+
+        if arg_count == param_count {
+            return Some(Self {
+                padded_ptrs: Vec::new(),
+                _default_values: Vec::new(),
+            });
+        }
+
+        let default_values = default_values();
+        let missing_count = param_count - arg_count;
+
+        if missing_count > default_values.len() {
+            (*err).error = sys::GDEXTENSION_CALL_ERROR_TOO_FEW_ARGUMENTS;
+            (*err).expected = (param_count - default_values.len()) as i32;
+            return None;
+        }
+
+        // Godot may pass a null pointer if there are no arguments.
+        let passed: &[sys::GDExtensionConstVariantPtr] = if arg_count == 0 {
+            &[]
+        } else {
+            std::slice::from_raw_parts(args_ptr, arg_count)
+        };
+
+        let defaults = &default_values[default_values.len() - missing_count..];
+        let padded_ptrs = passed
+            .iter()
+            .copied()
+            .chain(defaults.iter().map(|value| value.var_sys_const()))
+            .collect();
+
+        Some(Self {
+            padded_ptrs,
+            _default_values: default_values,
+        })
+    }
+
+    /// Returns the pointer to pass on to argument conversions; `args_ptr` if no default values were needed.
+    fn args_ptr(
+        &self,
+        args_ptr: *const sys::GDExtensionConstVariantPtr,
+    ) -> *const sys::GDExtensionConstVariantPtr {
+        if self.padded_ptrs.is_empty() {
+            args_ptr
+        } else {
+            self.padded_ptrs.as_ptr()
+        }
+    }
+}
+
 /// Convert the `N`th argument of `args_ptr` into a value of type `P`.
 ///
 /// # Safety
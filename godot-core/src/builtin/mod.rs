@@ -34,15 +34,14 @@
 //!   overloading would become impossible](https://github.com/kvark/mint/issues/75).
 
 // Re-export macros.
-pub use crate::{array, dict, real, reals, varray};
+pub use crate::{array, dict, real, reals, string_name, varray};
 
 pub use aabb::*;
 pub use array_inner::{Array, VariantArray};
 pub use basis::*;
 pub use callable::*;
 pub use color::*;
-pub use dictionary_inner::Dictionary;
-pub use others::*;
+pub use dictionary_inner::{Dictionary, TypedDictionary};
 pub use packed_array::*;
 pub use plane::*;
 pub use projection::*;
@@ -51,6 +50,7 @@ pub use real_inner::*;
 pub use rect2::*;
 pub use rect2i::*;
 pub use rid::*;
+pub use signal::*;
 pub use string::*;
 pub use transform2d::*;
 pub use transform3d::*;
@@ -84,7 +84,7 @@ mod aabb;
 mod basis;
 mod callable;
 mod color;
-mod others;
+mod color_constants;
 mod packed_array;
 mod plane;
 mod projection;
@@ -92,6 +92,7 @@ mod quaternion;
 mod rect2;
 mod rect2i;
 mod rid;
+mod signal;
 mod string;
 mod transform2d;
 mod transform3d;
@@ -491,6 +491,30 @@ macro_rules! impl_packed_array {
         }
 
         $crate::builtin::meta::impl_godot_as_self!($PackedArray);
+
+        // Serialized as a sequence of elements, like `Vec<$Element>`.
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $PackedArray {
+            #[inline]
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.collect_seq(self.as_slice())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $PackedArray {
+            #[inline]
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let elements = Vec::<$Element>::deserialize(deserializer)?;
+                Ok(Self::from(elements.as_slice()))
+            }
+        }
     }
 }
 
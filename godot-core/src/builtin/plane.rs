@@ -36,6 +36,30 @@ pub struct Plane {
 }
 
 impl Plane {
+    /// A plane that extends in the Y and Z axes (normal vector points +X).
+    ///
+    /// _Godot equivalent: `Plane.PLANE_YZ`_
+    pub const PLANE_YZ: Self = Self {
+        normal: Vector3::RIGHT,
+        d: 0.0,
+    };
+
+    /// A plane that extends in the X and Z axes (normal vector points +Y).
+    ///
+    /// _Godot equivalent: `Plane.PLANE_XZ`_
+    pub const PLANE_XZ: Self = Self {
+        normal: Vector3::UP,
+        d: 0.0,
+    };
+
+    /// A plane that extends in the X and Y axes (normal vector points +Z).
+    ///
+    /// _Godot equivalent: `Plane.PLANE_XY`_
+    pub const PLANE_XY: Self = Self {
+        normal: Vector3::BACK,
+        d: 0.0,
+    };
+
     /// Creates a new `Plane` from the `normal` and the distance from the origin `d`.
     ///
     /// # Panics
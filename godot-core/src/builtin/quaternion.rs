@@ -26,6 +26,16 @@ pub struct Quaternion {
 }
 
 impl Quaternion {
+    /// The identity quaternion, representing no rotation.
+    ///
+    /// _Godot equivalent: `Quaternion.IDENTITY`_
+    pub const IDENTITY: Self = Self {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 1.0,
+    };
+
     pub fn new(x: real, y: real, z: real, w: real) -> Self {
         Self { x, y, z, w }
     }
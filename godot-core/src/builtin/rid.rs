@@ -127,3 +127,30 @@ unsafe impl GodotFfi for Rid {
 }
 
 impl_godot_as_self!(Rid);
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use super::*;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serialized as the `u64` returned by [`Rid::to_u64()`]; invalid RIDs are `0`.
+    impl Serialize for Rid {
+        #[inline]
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_u64(self.to_u64())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Rid {
+        #[inline]
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            u64::deserialize(deserializer).map(Rid::new)
+        }
+    }
+}
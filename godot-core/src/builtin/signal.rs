@@ -0,0 +1,249 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+
+use crate::builtin::meta::{impl_godot_as_self, FromGodot, ToGodot};
+use crate::builtin::{inner, Callable, Dictionary, StringName, Variant};
+use crate::engine::{global, Object};
+use crate::obj::bounds::DynMemory;
+use crate::obj::{Bounds, EngineEnum};
+use crate::obj::{Gd, GodotClass, InstanceId};
+use std::{fmt, ptr};
+use sys::{ffi_methods, GodotFfi};
+
+/// A `Signal` represents a signal of an `Object` instance in Godot.
+///
+/// A signal is identified by the object it belongs to and its name. It can be emitted, and connected to [`Callable`]s that are
+/// invoked upon emission.
+///
+/// For signals declared in Rust with `#[signal]`, consider the type-safe API [`TypedSignal`][crate::obj::TypedSignal].
+///
+/// _Godot equivalent: `Signal`_
+#[repr(C, align(8))]
+pub struct Signal {
+    opaque: sys::types::OpaqueSignal,
+}
+
+impl Signal {
+    fn from_opaque(opaque: sys::types::OpaqueSignal) -> Self {
+        Self { opaque }
+    }
+
+    /// Create a signal for the signal `object::signal_name`.
+    ///
+    /// _Godot equivalent: `Signal(Object object, StringName signal)`_
+    pub fn from_object_signal<T, S>(object: &Gd<T>, signal_name: S) -> Self
+    where
+        T: GodotClass,
+        S: Into<StringName>,
+    {
+        let signal_name = signal_name.into();
+        unsafe {
+            sys::from_sys_init_or_init_default::<Self>(|self_ptr| {
+                let ctor = sys::builtin_fn!(signal_from_object_signal);
+                let raw = object.to_ffi();
+                let args = [raw.as_arg_ptr(), signal_name.sys_const()];
+                ctor(self_ptr, args.as_ptr());
+            })
+        }
+    }
+
+    /// Creates an invalid/empty signal that has no object and cannot be emitted or connected.
+    ///
+    /// _Godot equivalent: `Signal()`_
+    pub fn invalid() -> Self {
+        unsafe {
+            Self::from_sys_init(|self_ptr| {
+                let ctor = sys::builtin_fn!(signal_construct_default);
+                ctor(self_ptr, ptr::null_mut())
+            })
+        }
+    }
+
+    /// Returns the name of this signal.
+    ///
+    /// _Godot equivalent: `get_name`_
+    pub fn name(&self) -> StringName {
+        self.as_inner().get_name()
+    }
+
+    /// Returns the object to which this signal belongs.
+    ///
+    /// Returns `None` if the signal is null, or if the object has been freed.
+    ///
+    /// _Godot equivalent: `get_object`_
+    pub fn object(&self) -> Option<Gd<Object>> {
+        // Increment refcount because we're getting a reference, and `InnerSignal::get_object` doesn't
+        // increment the refcount.
+        self.as_inner().get_object().map(|mut object| {
+            <Object as Bounds>::DynMemory::maybe_inc_ref(&mut object.raw);
+            object
+        })
+    }
+
+    /// Returns the ID of the object to which this signal belongs, see also [`Gd::instance_id`].
+    ///
+    /// Returns `None` if the signal is null.
+    ///
+    /// _Godot equivalent: `get_object_id`_
+    pub fn object_id(&self) -> Option<InstanceId> {
+        let id = self.as_inner().get_object_id();
+        InstanceId::try_from_i64(id)
+    }
+
+    /// Returns true if this signal has no object.
+    ///
+    /// _Godot equivalent: `is_null`_
+    pub fn is_null(&self) -> bool {
+        self.as_inner().is_null()
+    }
+
+    /// Emits this signal with the given arguments.
+    ///
+    /// All callables connected to the signal are invoked. If the signal has no object, nothing happens.
+    ///
+    /// _Godot equivalent: `emit`_
+    pub fn emit(&self, args: &[Variant]) {
+        if let Some(mut object) = self.object() {
+            object.emit_signal(self.name(), args);
+        }
+    }
+
+    /// Connects this signal to the given callable.
+    ///
+    /// `flags` is a combination of [`ConnectFlags`][crate::engine::object::ConnectFlags] values; pass `0` for default behavior.
+    ///
+    /// _Godot equivalent: `connect`_
+    pub fn connect(&self, callable: Callable, flags: i64) -> global::Error {
+        let error = self.as_inner().connect(callable, flags);
+        global::Error::from_ord(error as i32)
+    }
+
+    /// Disconnects this signal from the given callable.
+    ///
+    /// If the callable is not connected, Godot prints an error.
+    ///
+    /// _Godot equivalent: `disconnect`_
+    pub fn disconnect(&self, callable: Callable) {
+        self.as_inner().disconnect(callable);
+    }
+
+    /// Returns true if the given callable is connected to this signal.
+    ///
+    /// _Godot equivalent: `is_connected`_
+    pub fn is_connected(&self, callable: Callable) -> bool {
+        self.as_inner().is_connected(callable)
+    }
+
+    /// Returns all connections to this signal.
+    ///
+    /// _Godot equivalent: `get_connections`_
+    pub fn connections(&self) -> Vec<SignalConnection> {
+        self.as_inner()
+            .get_connections()
+            .iter_shared()
+            .map(|variant| SignalConnection::from_dictionary(variant.to::<Dictionary>()))
+            .collect()
+    }
+
+    #[doc(hidden)]
+    pub fn as_inner(&self) -> inner::InnerSignal {
+        inner::InnerSignal::from_outer(self)
+    }
+
+    fn inc_ref(&self) {
+        std::mem::forget(self.clone())
+    }
+}
+
+impl_builtin_traits! {
+    for Signal {
+        // No Default::default(), for consistency with Callable.
+        PartialEq => signal_operator_equal;
+        Clone => signal_construct_copy;
+        Drop => signal_destroy;
+    }
+}
+
+// SAFETY:
+// The `opaque` in `Signal` holds an object ID and a `StringName`, and requires no special initialization or cleanup
+// beyond what is done in `from_opaque` and `drop`. So using `*mut Opaque` is safe.
+unsafe impl GodotFfi for Signal {
+    fn variant_type() -> sys::VariantType {
+        sys::VariantType::Signal
+    }
+
+    ffi_methods! { type sys::GDExtensionTypePtr = *mut Opaque;
+        fn from_sys;
+        fn sys;
+        fn from_sys_init;
+        fn move_return_ptr;
+    }
+
+    unsafe fn from_arg_ptr(ptr: sys::GDExtensionTypePtr, _call_type: sys::PtrcallType) -> Self {
+        let signal = Self::from_sys(ptr);
+        signal.inc_ref();
+        signal
+    }
+
+    unsafe fn from_sys_init_default(init_fn: impl FnOnce(sys::GDExtensionTypePtr)) -> Self {
+        let mut result = Self::invalid();
+        init_fn(result.sys_mut());
+        result
+    }
+}
+
+impl_godot_as_self!(Signal);
+
+impl fmt::Debug for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.name();
+        let object = self.object();
+
+        f.debug_struct("Signal")
+            .field("name", &name)
+            .field("object", &object)
+            .finish()
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_variant())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// A single connection of a [`Signal`], as returned by [`Signal::connections()`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct SignalConnection {
+    /// The signal that is connected.
+    pub signal: Signal,
+
+    /// The callable invoked upon emission.
+    pub callable: Callable,
+
+    /// Combination of [`ConnectFlags`][crate::engine::object::ConnectFlags] values used for the connection.
+    pub flags: i64,
+}
+
+impl SignalConnection {
+    fn from_dictionary(dict: Dictionary) -> Self {
+        let get = |key: &str| -> Variant {
+            dict.get(key)
+                .unwrap_or_else(|| panic!("signal connection is missing key '{key}'"))
+        };
+
+        Self {
+            signal: Signal::from_variant(&get("signal")),
+            callable: Callable::from_variant(&get("callable")),
+            flags: i64::from_variant(&get("flags")),
+        }
+    }
+}
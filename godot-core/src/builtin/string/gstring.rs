@@ -194,6 +194,8 @@ impl_builtin_traits! {
     }
 }
 
+impl_shared_string_api!(GString);
+
 impl fmt::Display for GString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s: String = self.chars_checked().iter().collect();
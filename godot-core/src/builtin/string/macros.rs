@@ -31,3 +31,215 @@ macro_rules! impl_rust_string_conv {
         }
     };
 }
+
+/// Implements the text processing API shared by `GString` and `StringName`.
+///
+/// Indices and lengths are measured in characters (Unicode code points), like in Godot.
+macro_rules! impl_shared_string_api {
+    ($Ty:ty) => {
+        impl $Ty {
+            /// Returns the index of the first occurrence of `what`, or `None` if not found.
+            pub fn find(&self, what: impl Into<$crate::builtin::GString>) -> Option<usize> {
+                self.find_from(what, 0)
+            }
+
+            /// Returns the index of the first occurrence of `what` at or after index `from`, or `None` if not found.
+            pub fn find_from(
+                &self,
+                what: impl Into<$crate::builtin::GString>,
+                from: usize,
+            ) -> Option<usize> {
+                let index = self
+                    .as_inner()
+                    .find(what.into(), $crate::builtin::to_i64(from));
+                (index >= 0).then(|| $crate::builtin::to_usize(index))
+            }
+
+            /// Returns the index of the first occurrence of `what`, ignoring case, or `None` if not found.
+            pub fn findn(&self, what: impl Into<$crate::builtin::GString>) -> Option<usize> {
+                let index = self.as_inner().findn(what.into(), 0);
+                (index >= 0).then(|| $crate::builtin::to_usize(index))
+            }
+
+            /// Returns the index of the last occurrence of `what`, or `None` if not found.
+            pub fn rfind(&self, what: impl Into<$crate::builtin::GString>) -> Option<usize> {
+                let index = self.as_inner().rfind(what.into(), -1);
+                (index >= 0).then(|| $crate::builtin::to_usize(index))
+            }
+
+            /// Returns `true` if the string contains `what`.
+            pub fn contains(&self, what: impl Into<$crate::builtin::GString>) -> bool {
+                self.as_inner().contains(what.into())
+            }
+
+            /// Returns `true` if the string starts with `prefix`.
+            ///
+            /// _Godot equivalent: `begins_with`_
+            #[doc(alias = "begins_with")]
+            pub fn starts_with(&self, prefix: impl Into<$crate::builtin::GString>) -> bool {
+                self.as_inner().begins_with(prefix.into())
+            }
+
+            /// Returns `true` if the string ends with `suffix`.
+            pub fn ends_with(&self, suffix: impl Into<$crate::builtin::GString>) -> bool {
+                self.as_inner().ends_with(suffix.into())
+            }
+
+            /// Splits the string at each occurrence of `delimiter`, keeping empty parts.
+            ///
+            /// An empty `delimiter` splits the string into single characters.
+            pub fn split(
+                &self,
+                delimiter: impl Into<$crate::builtin::GString>,
+            ) -> impl DoubleEndedIterator<Item = $crate::builtin::GString> + ExactSizeIterator {
+                self.as_inner()
+                    .split(delimiter.into(), true, 0)
+                    .to_vec()
+                    .into_iter()
+            }
+
+            /// Returns a copy of the string with all occurrences of `what` replaced by `with`.
+            pub fn replace(
+                &self,
+                what: impl Into<$crate::builtin::GString>,
+                with: impl Into<$crate::builtin::GString>,
+            ) -> $crate::builtin::GString {
+                self.as_inner().replace(what.into(), with.into())
+            }
+
+            /// Returns the characters in `range` as a new string.
+            ///
+            /// Bounds past the end of the string are clamped to its length.
+            ///
+            /// _Godot equivalent: `substr`_
+            #[doc(alias = "substr")]
+            pub fn substring(
+                &self,
+                range: impl std::ops::RangeBounds<usize>,
+            ) -> $crate::builtin::GString {
+                use std::ops::Bound;
+
+                let len = self.len();
+                let end = match range.end_bound() {
+                    Bound::Included(&end) => end.saturating_add(1),
+                    Bound::Excluded(&end) => end,
+                    Bound::Unbounded => len,
+                }
+                .min(len);
+                let begin = match range.start_bound() {
+                    Bound::Included(&begin) => begin,
+                    Bound::Excluded(&begin) => begin.saturating_add(1),
+                    Bound::Unbounded => 0,
+                }
+                .min(end);
+
+                self.as_inner().substr(
+                    $crate::builtin::to_i64(begin),
+                    $crate::builtin::to_i64(end - begin),
+                )
+            }
+
+            /// Returns the string converted to uppercase.
+            pub fn to_upper(&self) -> $crate::builtin::GString {
+                self.as_inner().to_upper()
+            }
+
+            /// Returns the string converted to lowercase.
+            pub fn to_lower(&self) -> $crate::builtin::GString {
+                self.as_inner().to_lower()
+            }
+
+            /// Returns the string with words separated by spaces and capitalized, e.g. `"Move Local X"` for `"move_local_x"`.
+            pub fn capitalize(&self) -> $crate::builtin::GString {
+                self.as_inner().capitalize()
+            }
+
+            /// Returns the string converted to `snake_case`.
+            pub fn to_snake_case(&self) -> $crate::builtin::GString {
+                self.as_inner().to_snake_case()
+            }
+
+            /// Returns the string converted to `camelCase`.
+            pub fn to_camel_case(&self) -> $crate::builtin::GString {
+                self.as_inner().to_camel_case()
+            }
+
+            /// Returns the string converted to `PascalCase`.
+            pub fn to_pascal_case(&self) -> $crate::builtin::GString {
+                self.as_inner().to_pascal_case()
+            }
+
+            /// Returns the string without leading and trailing whitespace and control characters.
+            ///
+            /// _Godot equivalent: `strip_edges`_
+            #[doc(alias = "strip_edges")]
+            pub fn trim(&self) -> $crate::builtin::GString {
+                self.as_inner().strip_edges(true, true)
+            }
+
+            /// Replaces the placeholders `{key}` with the values in `values`, which is typically a `Dictionary` (placeholders
+            /// are keys) or an array (placeholders are indices).
+            ///
+            /// # Example
+            /// ```no_run
+            /// # use godot::prelude::*;
+            /// let text = GString::from("{name} has {hp} HP");
+            /// let formatted = text.format(dict! { "name": "Godette", "hp": 42 });
+            /// assert_eq!(formatted, "Godette has 42 HP");
+            /// ```
+            pub fn format(
+                &self,
+                values: impl $crate::builtin::meta::ToGodot,
+            ) -> $crate::builtin::GString {
+                self.as_inner()
+                    .format(values.to_variant(), $crate::builtin::GString::from("{_}"))
+            }
+
+            /// Parses the string as an integer, e.g. `"-42"`.
+            ///
+            /// Returns an error if the string is not a valid integer, as determined by Godot's `is_valid_int()`.
+            ///
+            /// _Godot equivalent: `to_int`_
+            #[doc(alias = "to_int")]
+            pub fn parse_int(&self) -> Result<i64, $crate::builtin::meta::ConvertError> {
+                if self.as_inner().is_valid_int() {
+                    Ok(self.as_inner().to_int())
+                } else {
+                    Err($crate::builtin::meta::ConvertError::with_cause_value(
+                        "string is not a valid integer",
+                        self,
+                    ))
+                }
+            }
+
+            /// Parses the string as a floating-point number, e.g. `"1.5e3"`.
+            ///
+            /// Returns an error if the string is not a valid float, as determined by Godot's `is_valid_float()`.
+            ///
+            /// _Godot equivalent: `to_float`_
+            #[doc(alias = "to_float")]
+            pub fn parse_float(&self) -> Result<f64, $crate::builtin::meta::ConvertError> {
+                if self.as_inner().is_valid_float() {
+                    Ok(self.as_inner().to_float())
+                } else {
+                    Err($crate::builtin::meta::ConvertError::with_cause_value(
+                        "string is not a valid float",
+                        self,
+                    ))
+                }
+            }
+        }
+
+        impl PartialEq<&str> for $Ty {
+            fn eq(&self, other: &&str) -> bool {
+                String::from(self) == *other
+            }
+        }
+
+        impl PartialEq<$Ty> for &str {
+            fn eq(&self, other: &$Ty) -> bool {
+                other == self
+            }
+        }
+    };
+}
@@ -10,8 +10,8 @@ use std::fmt;
 use godot_ffi as sys;
 use godot_ffi::{ffi_methods, GDExtensionTypePtr, GodotFfi};
 
-use crate::builtin::inner;
 use crate::builtin::meta::impl_godot_as_self;
+use crate::builtin::{inner, to_i64, to_usize};
 
 use super::{GString, StringName};
 
@@ -34,6 +34,118 @@ impl NodePath {
             .expect("Godot hashes are uint32_t")
     }
 
+    /// Returns `true` if the path is empty, i.e. `""`.
+    pub fn is_empty(&self) -> bool {
+        self.as_inner().is_empty()
+    }
+
+    /// Returns `true` if the path starts at the root of the scene tree, i.e. with `/`.
+    pub fn is_absolute(&self) -> bool {
+        self.as_inner().is_absolute()
+    }
+
+    /// Number of node names in the path, e.g. 2 for `"Path2D/PathFollow2D:position:x"`.
+    ///
+    /// _Godot equivalent: `get_name_count`_
+    #[doc(alias = "get_name_count")]
+    pub fn name_count(&self) -> usize {
+        to_usize(self.as_inner().get_name_count())
+    }
+
+    /// Number of subnames (property or resource names) in the path, e.g. 2 for `"Path2D/PathFollow2D:position:x"`.
+    ///
+    /// _Godot equivalent: `get_subname_count`_
+    #[doc(alias = "get_subname_count")]
+    pub fn subname_count(&self) -> usize {
+        to_usize(self.as_inner().get_subname_count())
+    }
+
+    /// Iterates over the node names, e.g. `Path2D` and `PathFollow2D` for `"/root/Path2D/PathFollow2D:position:x"`.
+    ///
+    /// The leading `/` of absolute paths is not part of the names, see [`is_absolute()`][Self::is_absolute].
+    ///
+    /// _Godot equivalent: `get_name`_
+    #[doc(alias = "get_name")]
+    pub fn names(&self) -> impl DoubleEndedIterator<Item = StringName> + ExactSizeIterator + '_ {
+        (0..self.name_count()).map(|index| self.as_inner().get_name(to_i64(index)))
+    }
+
+    /// Iterates over the subnames, e.g. `position` and `x` for `"Path2D/PathFollow2D:position:x"`.
+    ///
+    /// _Godot equivalent: `get_subname`_
+    #[doc(alias = "get_subname")]
+    pub fn subnames(&self) -> impl DoubleEndedIterator<Item = StringName> + ExactSizeIterator + '_ {
+        (0..self.subname_count()).map(|index| self.as_inner().get_subname(to_i64(index)))
+    }
+
+    /// Returns the path as a property path, by prefixing it with `:`, e.g. `":Sprite2D:texture:load_path"` for
+    /// `"Sprite2D:texture:load_path"`.
+    ///
+    /// This is useful to access nested properties of the object owning the path, e.g. with `Object::get_indexed()`.
+    ///
+    /// _Godot equivalent: `get_as_property_path`_
+    #[doc(alias = "get_as_property_path")]
+    pub fn as_property_path(&self) -> NodePath {
+        self.as_inner().get_as_property_path()
+    }
+
+    /// Appends `other` to this path, e.g. `"a/b".join("c:x")` is `"a/b/c:x"`.
+    ///
+    /// If `other` is absolute, it is returned unchanged. Subnames of `self` are kept only if `other` has no node names,
+    /// since subnames can only appear at the end of a path.
+    #[doc(alias = "concat")]
+    pub fn join(&self, other: &NodePath) -> NodePath {
+        if other.is_absolute() || self.is_empty() {
+            return other.clone();
+        }
+
+        let names = self.names().chain(other.names());
+        if other.name_count() == 0 {
+            Self::compose(
+                self.is_absolute(),
+                names,
+                self.subnames().chain(other.subnames()),
+            )
+        } else {
+            Self::compose(self.is_absolute(), names, other.subnames())
+        }
+    }
+
+    /// Returns the path to the parent node, i.e. without the last node name and without subnames.
+    ///
+    /// Returns `None` if the path has less than two node names.
+    pub fn parent(&self) -> Option<NodePath> {
+        let count = self.name_count();
+        if count < 2 {
+            return None;
+        }
+
+        let names = self.names().take(count - 1);
+        Some(Self::compose(self.is_absolute(), names, std::iter::empty()))
+    }
+
+    /// Builds a path following Godot's syntax `/name/name:subname:subname`.
+    fn compose(
+        absolute: bool,
+        names: impl Iterator<Item = StringName>,
+        subnames: impl Iterator<Item = StringName>,
+    ) -> NodePath {
+        let mut path = String::new();
+        if absolute {
+            path.push('/');
+        }
+
+        let names: Vec<String> = names.map(|name| name.to_string()).collect();
+        path.push_str(&names.join("/"));
+
+        for subname in subnames {
+            path.push(':');
+            path.push_str(&subname.to_string());
+        }
+
+        NodePath::from(path)
+    }
+
     #[doc(hidden)]
     pub fn as_inner(&self) -> inner::InnerNodePath {
         inner::InnerNodePath::from_outer(self)
@@ -152,6 +264,21 @@ impl From<StringName> for NodePath {
     }
 }
 
+/// Builds a relative path by joining node names with `/`, e.g. `["a", "b"]` becomes `"a/b"`.
+impl<S> FromIterator<S> for NodePath
+where
+    S: AsRef<str>,
+{
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let names: Vec<String> = iter
+            .into_iter()
+            .map(|name| name.as_ref().to_string())
+            .collect();
+
+        Self::from(names.join("/"))
+    }
+}
+
 #[cfg(feature = "serde")]
 mod serialize {
     use super::*;
@@ -67,6 +67,29 @@ impl StringName {
         result
     }
 
+    /// Returns a `StringName` for `string` from a process-wide interning cache.
+    ///
+    /// The first call for a given string constructs the `StringName` through FFI; all later calls with an equal string return a
+    /// (cheap, ref-counted) clone of the cached instance. This avoids repeated allocation and hashing of the same name in hot paths,
+    /// at the cost of a mutex lock and a hash-map lookup on the Rust side.
+    ///
+    /// Cached names are never released, so only use this for a bounded set of strings, such as method, signal or property names.
+    /// For string literals, the [`string_name!`][crate::builtin::string_name] macro is cheaper, as it caches per call site without locking.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::StringName;
+    ///
+    /// let a = StringName::interned("damage_taken");
+    /// let b = StringName::interned("damage_taken");
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn interned(string: &str) -> Self {
+        static CACHE: sys::InternCache<StringName> = sys::InternCache::new();
+
+        CACHE.get_or_insert_with(string, StringName::from)
+    }
+
     /// Returns the number of characters in the string.
     ///
     /// _Godot equivalent: `length`_
@@ -158,6 +181,21 @@ impl_builtin_traits! {
     }
 }
 
+impl_shared_string_api!(StringName);
+
+// Godot's `operator <` on StringName is broken (see above), so the order is established by the string contents.
+impl Ord for StringName {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        GString::from(self).cmp(&GString::from(other))
+    }
+}
+
+impl PartialOrd for StringName {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl fmt::Display for StringName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = GString::from(self);
@@ -180,6 +218,40 @@ unsafe impl Sync for StringName {}
 // That is, it's safe to construct a StringName on thread A and destroy it on thread B.
 unsafe impl Send for StringName {}
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Interned literals
+
+/// Creates a [`StringName`][crate::builtin::StringName] from a string literal, constructing it only once per call site.
+///
+/// The first evaluation of a `string_name!` invocation constructs the `StringName` through FFI and stores it in a static;
+/// later evaluations of the same invocation return a ref-counted clone, without allocating or hashing the string again.
+/// This makes it well-suited for hot paths, such as emitting signals or calling methods by name in `process()`.
+///
+/// Multiple call sites with the same literal each hold their own instance; they still compare equal. To share one instance
+/// between arbitrary (non-literal) strings, see [`StringName::interned()`][crate::builtin::StringName::interned].
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::{string_name, StringName};
+///
+/// fn signal_name() -> StringName {
+///     string_name!("health_changed")
+/// }
+///
+/// assert_eq!(signal_name(), StringName::from("health_changed"));
+/// ```
+#[macro_export]
+macro_rules! string_name {
+    ($string:literal) => {{
+        static CACHED: ::std::sync::OnceLock<$crate::builtin::StringName> =
+            ::std::sync::OnceLock::new();
+
+        ::std::clone::Clone::clone(
+            CACHED.get_or_init(|| $crate::builtin::StringName::from($string)),
+        )
+    }};
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Conversion from/into other string-types
 
@@ -12,6 +12,8 @@ use sys::types::OpaqueVariant;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
 mod impls;
+#[cfg(feature = "serde")]
+mod serialize;
 
 pub use sys::{VariantOperator, VariantType};
 
@@ -0,0 +1,127 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Self-describing serde representation of `Variant`.
+//!
+//! A variant is serialized like an externally tagged Rust enum, with the name of its `VariantType` as the tag, e.g.
+//! `{"Int":5}` or `{"Vector2":{"x":1.0,"y":2.0}}` in JSON. `Nil` is serialized as the unit variant `"Nil"`.
+//!
+//! Types without value semantics -- `Object`, `Callable` and `Signal` -- cannot be serialized and yield an error.
+//! Typed arrays are serialized like untyped ones and thus deserialized as `VariantArray`.
+
+use serde::ser::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::*;
+
+macro_rules! impl_variant_repr {
+    ($( $Name:ident($Ty:ty) ),* $(,)?) => {
+        /// Mirror of all variant types that can be serialized, with the types' serde implementations.
+        #[derive(Serialize, Deserialize)]
+        #[serde(rename = "Variant")]
+        enum VariantRepr {
+            Nil,
+            Array(VariantArray),
+            $( $Name($Ty), )*
+        }
+
+        impl VariantRepr {
+            fn from_variant(variant: &Variant) -> Result<Self, String> {
+                let repr = match variant.get_type() {
+                    VariantType::Nil => Self::Nil,
+                    VariantType::Array => Self::Array(untyped_array(variant)),
+                    $(
+                        VariantType::$Name => Self::$Name(variant.to::<$Ty>()),
+                    )*
+                    other => return Err(format!("cannot serialize Variant of type {other:?}")),
+                };
+
+                Ok(repr)
+            }
+
+            fn into_variant(self) -> Variant {
+                match self {
+                    Self::Nil => Variant::nil(),
+                    Self::Array(array) => array.to_variant(),
+                    $(
+                        Self::$Name(value) => value.to_variant(),
+                    )*
+                }
+            }
+        }
+    };
+}
+
+impl_variant_repr!(
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(GString),
+    Vector2(Vector2),
+    Vector2i(Vector2i),
+    Rect2(Rect2),
+    Rect2i(Rect2i),
+    Vector3(Vector3),
+    Vector3i(Vector3i),
+    Transform2D(Transform2D),
+    Vector4(Vector4),
+    Vector4i(Vector4i),
+    Plane(Plane),
+    Quaternion(Quaternion),
+    Aabb(Aabb),
+    Basis(Basis),
+    Transform3D(Transform3D),
+    Projection(Projection),
+    Color(Color),
+    StringName(StringName),
+    NodePath(NodePath),
+    Rid(Rid),
+    Dictionary(Dictionary),
+    PackedByteArray(PackedByteArray),
+    PackedInt32Array(PackedInt32Array),
+    PackedInt64Array(PackedInt64Array),
+    PackedFloat32Array(PackedFloat32Array),
+    PackedFloat64Array(PackedFloat64Array),
+    PackedStringArray(PackedStringArray),
+    PackedVector2Array(PackedVector2Array),
+    PackedVector3Array(PackedVector3Array),
+    PackedColorArray(PackedColorArray),
+);
+
+/// Copies the elements of an array variant, which may be typed, into a `VariantArray`.
+///
+/// Typed arrays cannot be converted to `VariantArray` directly, so the elements are accessed through Godot's dynamic API.
+fn untyped_array(variant: &Variant) -> VariantArray {
+    let len = variant.call("size", &[]).to::<i64>();
+
+    (0..len)
+        .map(|index| variant.call("get", &[index.to_variant()]))
+        .collect()
+}
+
+impl Serialize for Variant {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        VariantRepr::from_variant(self)
+            .map_err(S::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Variant {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        VariantRepr::deserialize(deserializer).map(VariantRepr::into_variant)
+    }
+}
@@ -42,6 +42,12 @@ impl Vector2i {
     /// Vector with all components set to `1`.
     pub const ONE: Self = Self::splat(1);
 
+    /// Vector with all components set to `i32::MIN`.
+    pub const MIN: Self = Self::splat(i32::MIN);
+
+    /// Vector with all components set to `i32::MAX`.
+    pub const MAX: Self = Self::splat(i32::MAX);
+
     /// Unit vector in -X direction (right in 2D coordinate system).
     pub const LEFT: Self = Self::new(-1, 0);
 
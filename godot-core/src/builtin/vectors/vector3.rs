@@ -46,6 +46,9 @@ impl Vector3 {
     /// Vector with all components set to `1.0`.
     pub const ONE: Self = Self::splat(1.0);
 
+    /// Vector with all components set to `real::INFINITY`.
+    pub const INF: Self = Self::splat(real::INFINITY);
+
     /// Unit vector in -X direction. Can be interpreted as left in an untransformed 3D world.
     pub const LEFT: Self = Self::new(-1.0, 0.0, 0.0);
 
@@ -64,6 +67,30 @@ impl Vector3 {
     /// Unit vector in +Z direction. Can be interpreted as "out of the screen" in an untransformed 3D world.
     pub const BACK: Self = Self::new(0.0, 0.0, 1.0);
 
+    /// Unit vector pointing towards the left side of imported 3D assets (+X).
+    #[cfg(since_api = "4.3")]
+    pub const MODEL_LEFT: Self = Self::new(1.0, 0.0, 0.0);
+
+    /// Unit vector pointing towards the right side of imported 3D assets (-X).
+    #[cfg(since_api = "4.3")]
+    pub const MODEL_RIGHT: Self = Self::new(-1.0, 0.0, 0.0);
+
+    /// Unit vector pointing towards the top side (up) of imported 3D assets (+Y).
+    #[cfg(since_api = "4.3")]
+    pub const MODEL_TOP: Self = Self::new(0.0, 1.0, 0.0);
+
+    /// Unit vector pointing towards the bottom side (down) of imported 3D assets (-Y).
+    #[cfg(since_api = "4.3")]
+    pub const MODEL_BOTTOM: Self = Self::new(0.0, -1.0, 0.0);
+
+    /// Unit vector pointing towards the front side (facing forward) of imported 3D assets (+Z).
+    #[cfg(since_api = "4.3")]
+    pub const MODEL_FRONT: Self = Self::new(0.0, 0.0, 1.0);
+
+    /// Unit vector pointing towards the rear side (back) of imported 3D assets (-Z).
+    #[cfg(since_api = "4.3")]
+    pub const MODEL_REAR: Self = Self::new(0.0, 0.0, -1.0);
+
     /// Returns a `Vector3` with the given components.
     pub const fn new(x: real, y: real, z: real) -> Self {
         Self { x, y, z }
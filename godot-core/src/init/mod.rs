@@ -7,6 +7,8 @@
 
 use godot_ffi as sys;
 
+use std::any::Any;
+use std::backtrace::Backtrace;
 use std::cell;
 use sys::GodotFfi;
 
@@ -23,6 +25,8 @@ pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
     init: *mut sys::GDExtensionInitialization,
 ) -> sys::GDExtensionBool {
     let init_code = || {
+        crate::private::set_panic_policy(E::panic_policy());
+
         let tool_only_in_editor = match E::editor_run_behavior() {
             EditorRunBehavior::ToolClassesOnly => true,
             EditorRunBehavior::AllClasses => false,
@@ -64,7 +68,7 @@ unsafe extern "C" fn ffi_initialize_layer<E: ExtensionLibrary>(
     let level = InitLevel::from_sys(init_level);
     let ctx = || format!("failed to initialize GDExtension level `{:?}`", level);
 
-    // Swallow panics. TODO consider crashing if gdext init fails.
+    // Panics are handled according to the library's panic policy; by default, they are logged and initialization continues.
     let _ = crate::private::handle_panic(ctx, || {
         gdext_on_level_init(level);
         E::on_level_init(level);
@@ -78,7 +82,7 @@ unsafe extern "C" fn ffi_deinitialize_layer<E: ExtensionLibrary>(
     let level = InitLevel::from_sys(init_level);
     let ctx = || format!("failed to deinitialize GDExtension level `{:?}`", level);
 
-    // Swallow panics.
+    // Panics are handled according to the library's panic policy.
     let _ = crate::private::handle_panic(ctx, || {
         E::on_level_deinit(level);
         gdext_on_level_deinit(level);
@@ -172,6 +176,31 @@ pub unsafe trait ExtensionLibrary {
     fn on_level_deinit(_level: InitLevel) {
         // Nothing by default.
     }
+
+    /// Determines what happens when Rust code called from Godot panics (log and continue by default).
+    ///
+    /// The policy applies to all calls from Godot into the extension: `#[func]` methods, virtual callbacks such as `ready()`,
+    /// and the init/deinit levels. It takes effect when the library is loaded.
+    ///
+    /// ```
+    /// # use godot::init::*;
+    /// struct MyExtension;
+    ///
+    /// #[gdextension]
+    /// unsafe impl ExtensionLibrary for MyExtension {
+    ///     fn panic_policy() -> PanicPolicy {
+    ///         // Make CI runs fail on panics, but keep shipped games running.
+    ///         if cfg!(debug_assertions) {
+    ///             PanicPolicy::Abort
+    ///         } else {
+    ///             PanicPolicy::LogAndContinue
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    fn panic_policy() -> PanicPolicy {
+        PanicPolicy::LogAndContinue
+    }
 }
 
 /// Determines if and how an extension's code is run in the editor.
@@ -202,6 +231,115 @@ pub enum EditorRunBehavior {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Determines how panics in Rust code called from Godot are handled.
+///
+/// Panics never unwind into Godot; they are always caught at the boundary. The call that panicked then returns to Godot without a
+/// result (e.g. `null` to GDScript).
+///
+/// See also [`ExtensionLibrary::panic_policy()`].
+#[derive(Copy, Clone, Default)]
+#[non_exhaustive]
+pub enum PanicPolicy {
+    /// Prints the panic as Godot error, including location and message, and continues running.
+    #[default]
+    LogAndContinue,
+
+    /// Prints the panic like `LogAndContinue`, then aborts the process with a non-zero exit code.
+    ///
+    /// Useful for CI and automated tests, which should not silently continue after a panic.
+    Abort,
+
+    /// Invokes a user-defined handler instead of printing the panic, then continues running.
+    ///
+    /// The handler can inspect the panic through [`PanicReport`], e.g. to forward it to a crash reporter. It may itself decide to
+    /// abort the process. If the handler panics, that panic is printed and otherwise ignored.
+    Custom(fn(&PanicReport)),
+}
+
+// Manual impl, since Debug is not implemented for all higher-ranked fn pointers in older Rust versions.
+impl std::fmt::Debug for PanicPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LogAndContinue => f.write_str("LogAndContinue"),
+            Self::Abort => f.write_str("Abort"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Information about a panic caught at the Godot boundary, passed to [`PanicPolicy::Custom`] handlers.
+pub struct PanicReport<'a> {
+    payload: &'a (dyn Any + Send),
+    context: &'a str,
+    location: Option<(&'a str, u32)>,
+    backtrace: Option<&'a Backtrace>,
+}
+
+impl<'a> PanicReport<'a> {
+    pub(crate) fn new(
+        payload: &'a (dyn Any + Send),
+        context: &'a str,
+        location: Option<(&'a str, u32)>,
+        backtrace: Option<&'a Backtrace>,
+    ) -> Self {
+        Self {
+            payload,
+            context,
+            location,
+            backtrace,
+        }
+    }
+
+    /// The panic message, if the panic was raised with a string (as is the case for `panic!`, `assert!` etc).
+    pub fn message(&self) -> Option<&str> {
+        if let Some(s) = self.payload.downcast_ref::<&'static str>() {
+            Some(s)
+        } else {
+            self.payload.downcast_ref::<String>().map(String::as_str)
+        }
+    }
+
+    /// The raw payload passed to `panic!` or `std::panic::panic_any()`.
+    pub fn payload(&self) -> &(dyn Any + Send) {
+        self.payload
+    }
+
+    /// Describes the call from Godot during which the panic occurred, e.g. the method name.
+    pub fn context(&self) -> &str {
+        self.context
+    }
+
+    /// Source file in which the panic occurred, if available.
+    pub fn file(&self) -> Option<&str> {
+        self.location.map(|(file, _)| file)
+    }
+
+    /// Line at which the panic occurred, if available.
+    pub fn line(&self) -> Option<u32> {
+        self.location.map(|(_, line)| line)
+    }
+
+    /// Backtrace of the panic, if captured.
+    ///
+    /// Capturing follows the rules of [`Backtrace::capture()`], i.e. requires the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`
+    /// environment variables. Check [`Backtrace::status()`] to see if it was captured.
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        self.backtrace
+    }
+}
+
+impl std::fmt::Debug for PanicReport<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PanicReport")
+            .field("message", &self.message())
+            .field("context", &self.context)
+            .field("location", &self.location)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// Stage of the Godot initialization process.
 ///
 /// Godot's initialization and deinitialization processes are split into multiple stages, like a stack. At each level,
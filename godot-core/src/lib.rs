@@ -46,6 +46,8 @@ mod gen;
 
 #[doc(hidden)]
 pub mod private {
+    use std::backtrace::Backtrace;
+    use std::panic::AssertUnwindSafe;
     use std::sync::{Arc, Mutex};
 
     pub use crate::gen::classes::class_macros;
@@ -53,6 +55,9 @@ pub mod private {
     pub use crate::storage::{as_storage, Storage};
     pub use sys::out;
 
+    use crate::builtin::meta::{ConvertError, FromGodot, GodotType, ToGodot};
+    use crate::builtin::{Dictionary, TypedDictionary};
+    use crate::init::{PanicPolicy, PanicReport};
     use crate::{log, sys};
 
     // If someone forgets #[godot_api], this causes a compile error, rather than virtual functions not being called at runtime.
@@ -85,6 +90,10 @@ pub mod private {
     }
 
     pub fn print_panic(err: Box<dyn std::any::Any + Send>) {
+        print_panic_payload(&*err);
+    }
+
+    fn print_panic_payload(err: &(dyn std::any::Any + Send)) {
         if let Some(s) = err.downcast_ref::<&'static str>() {
             print_panic_message(s);
         } else if let Some(s) = err.downcast_ref::<String>() {
@@ -98,6 +107,145 @@ pub mod private {
         l.init_auto();
     }
 
+    // Called by #[init(node = "path")] fields; panics if the node cannot be found or has the wrong type.
+    pub fn auto_init_node<T, B>(
+        l: &mut crate::obj::OnReady<crate::obj::Gd<T>>,
+        base: &crate::obj::Base<B>,
+        path: impl Into<crate::builtin::NodePath>,
+        class_name: &str,
+        field_name: &str,
+    ) where
+        T: crate::obj::GodotClass + crate::obj::Inherits<crate::engine::Node>,
+        B: crate::obj::GodotClass + crate::obj::Inherits<crate::engine::Node>,
+    {
+        let base = base.to_gd().upcast::<crate::engine::Node>();
+        l.init_node(&base, path.into(), class_name, field_name);
+    }
+
+    // Used by #[rpc] to find the virtual functions that the RPC `_ready` hook delegates to. Resolved at compile time through autoref
+    // specialization: a `#[godot_api] impl I*` block takes precedence over the callbacks generated by #[derive(GodotClass)].
+    pub struct NextVirtual<T>(pub std::marker::PhantomData<T>);
+
+    pub trait NextVirtualFromImpl {
+        fn next_virtual_call(&self, name: &str) -> sys::GDExtensionClassCallVirtual;
+    }
+
+    impl<T: crate::obj::cap::ImplementsGodotVirtual> NextVirtualFromImpl for &NextVirtual<T> {
+        fn next_virtual_call(&self, name: &str) -> sys::GDExtensionClassCallVirtual {
+            T::__virtual_call(name)
+        }
+    }
+
+    pub trait NextVirtualFromDerive {
+        fn next_virtual_call(&self, name: &str) -> sys::GDExtensionClassCallVirtual;
+    }
+
+    impl<T: crate::obj::UserClass> NextVirtualFromDerive for NextVirtual<T> {
+        fn next_virtual_call(&self, name: &str) -> sys::GDExtensionClassCallVirtual {
+            T::__default_virtual_call(name)
+        }
+    }
+
+    // Called by #[constant(getter)]; the name appears in the compile error if the type is not representable in Godot.
+    pub fn constant_getter_must_implement_to_godot<T: crate::builtin::meta::ToGodot>() {}
+
+    // Called by #[constant] without `getter`; the name appears in the compile error if the type is not an integer.
+    pub fn constant_must_be_integer_or_use_getter<T: TryInto<i64> + std::fmt::Debug + Copy>() {}
+
+    // Implemented by #[derive(ToGodot)] and #[derive(FromGodot)] for structs with named fields. Converts the fields alone, without
+    // the `{ "Name": ... }` wrapper, so that #[variant(flatten)] merges the fields of nested structs.
+    pub trait DerivedToFields {
+        fn __to_fields(&self) -> Dictionary;
+    }
+
+    pub trait DerivedFromFields: Sized {
+        fn __from_fields(fields: Dictionary) -> Result<Self, ConvertError>;
+    }
+
+    // Bound on the `Via` type of #[variant(flatten)] fields that don't derive ToGodot/FromGodot themselves; the name appears in the
+    // compile error if the field is not represented as a dictionary.
+    #[allow(non_camel_case_types)]
+    pub trait Flattened_field_must_convert_to_Dictionary: Sized {
+        fn into_dictionary(self) -> Dictionary;
+        fn try_from_dictionary(dict: Dictionary) -> Result<Self, ConvertError>;
+    }
+
+    impl Flattened_field_must_convert_to_Dictionary for Dictionary {
+        fn into_dictionary(self) -> Dictionary {
+            self
+        }
+
+        fn try_from_dictionary(dict: Dictionary) -> Result<Self, ConvertError> {
+            Ok(dict)
+        }
+    }
+
+    impl<K: GodotType, V: GodotType> Flattened_field_must_convert_to_Dictionary
+        for TypedDictionary<K, V>
+    {
+        fn into_dictionary(self) -> Dictionary {
+            self.into_godot()
+        }
+
+        fn try_from_dictionary(dict: Dictionary) -> Result<Self, ConvertError> {
+            Self::try_from_godot(dict)
+        }
+    }
+
+    // Used by #[variant(flatten)]. Resolved at compile time through autoref specialization: structs deriving ToGodot/FromGodot take
+    // precedence and are flattened through their fields, other types are converted through their dictionary `Via` type.
+    pub struct Flatten<T>(pub std::marker::PhantomData<T>);
+
+    pub trait FlattenToDerived<T> {
+        fn to_flattened(&self, value: &T) -> Dictionary;
+    }
+
+    impl<T: DerivedToFields> FlattenToDerived<T> for &Flatten<T> {
+        fn to_flattened(&self, value: &T) -> Dictionary {
+            value.__to_fields()
+        }
+    }
+
+    pub trait FlattenToDictionary<T> {
+        fn to_flattened(&self, value: &T) -> Dictionary;
+    }
+
+    impl<T> FlattenToDictionary<T> for Flatten<T>
+    where
+        T: ToGodot,
+        T::Via: Flattened_field_must_convert_to_Dictionary,
+    {
+        fn to_flattened(&self, value: &T) -> Dictionary {
+            value.to_godot().into_dictionary()
+        }
+    }
+
+    pub trait FlattenFromDerived<T> {
+        fn try_from_flattened(&self, rest: Dictionary) -> Result<T, ConvertError>;
+    }
+
+    impl<T: DerivedFromFields> FlattenFromDerived<T> for &Flatten<T> {
+        fn try_from_flattened(&self, rest: Dictionary) -> Result<T, ConvertError> {
+            T::__from_fields(rest)
+        }
+    }
+
+    pub trait FlattenFromDictionary<T> {
+        fn try_from_flattened(&self, rest: Dictionary) -> Result<T, ConvertError>;
+    }
+
+    impl<T> FlattenFromDictionary<T> for Flatten<T>
+    where
+        T: FromGodot,
+        T::Via: Flattened_field_must_convert_to_Dictionary,
+    {
+        fn try_from_flattened(&self, rest: Dictionary) -> Result<T, ConvertError> {
+            let via =
+                <T::Via as Flattened_field_must_convert_to_Dictionary>::try_from_dictionary(rest)?;
+            T::try_from_godot(via)
+        }
+    }
+
     fn print_panic_message(msg: &str) {
         // If the message contains newlines, print all of the lines after a line break, and indent them.
         let lbegin = "\n  ";
@@ -113,10 +261,18 @@ pub mod private {
     struct GodotPanicInfo {
         line: u32,
         file: String,
-        //backtrace: Backtrace, // for future use
+        backtrace: Backtrace,
     }
 
-    /// Executes `code`. If a panic is thrown, it is caught and an error message is printed to Godot.
+    static PANIC_POLICY: sys::Global<PanicPolicy> = sys::Global::default();
+
+    /// Sets the policy applied by [`handle_panic()`]; called when the library is loaded.
+    pub(crate) fn set_panic_policy(policy: PanicPolicy) {
+        *PANIC_POLICY.lock() = policy;
+    }
+
+    /// Executes `code`. If a panic is thrown, it is caught and handled according to the library's [`PanicPolicy`]. By default, an
+    /// error message is printed to Godot.
     ///
     /// Returns `None` if a panic occurred, and `Some(result)` with the result of `code` otherwise.
     #[must_use]
@@ -125,6 +281,33 @@ pub mod private {
         E: FnOnce() -> S,
         F: FnOnce() -> R + std::panic::UnwindSafe,
         S: std::fmt::Display,
+    {
+        // Copy policy out of the lock, so a custom handler can access the global state.
+        handle_panic_impl(|| *PANIC_POLICY.lock(), error_context, code)
+    }
+
+    /// Like [`handle_panic()`], but applies `policy` instead of the library's policy. Used for testing.
+    #[must_use]
+    pub fn handle_panic_with_policy<E, F, R, S>(
+        policy: PanicPolicy,
+        error_context: E,
+        code: F,
+    ) -> Option<R>
+    where
+        E: FnOnce() -> S,
+        F: FnOnce() -> R + std::panic::UnwindSafe,
+        S: std::fmt::Display,
+    {
+        handle_panic_impl(|| policy, error_context, code)
+    }
+
+    // The policy is only fetched if a panic occurs, to keep the lock out of the hot path.
+    fn handle_panic_impl<P, E, F, R, S>(policy: P, error_context: E, code: F) -> Option<R>
+    where
+        P: FnOnce() -> PanicPolicy,
+        E: FnOnce() -> S,
+        F: FnOnce() -> R + std::panic::UnwindSafe,
+        S: std::fmt::Display,
     {
         let info: Arc<Mutex<Option<GodotPanicInfo>>> = Arc::new(Mutex::new(None));
 
@@ -137,7 +320,7 @@ pub mod private {
                     *info.lock().unwrap() = Some(GodotPanicInfo {
                         file: location.file().to_string(),
                         line: location.line(),
-                        //backtrace: Backtrace::capture(),
+                        backtrace: Backtrace::capture(),
                     });
                 } else {
                     println!("panic occurred but can't get location information...");
@@ -156,21 +339,50 @@ pub mod private {
                 // TODO write custom panic handler and move this there, before panic backtrace printing
                 flush_stdout();
 
-                let guard = info.lock().unwrap();
-                let info = guard.as_ref().expect("no panic info available");
-                log::godot_error!(
-                    "Rust function panicked in file {} at line {}. Context: {}",
-                    info.file,
-                    info.line,
-                    error_context()
+                let info = info.lock().unwrap().take();
+                let context = error_context().to_string();
+                let report = PanicReport::new(
+                    &*err,
+                    &context,
+                    info.as_ref().map(|info| (info.file.as_str(), info.line)),
+                    info.as_ref().map(|info| &info.backtrace),
                 );
-                //eprintln!("Backtrace:\n{}", info.backtrace);
-                print_panic(err);
+
+                match policy() {
+                    PanicPolicy::LogAndContinue => print_panic_report(&report),
+                    PanicPolicy::Abort => {
+                        print_panic_report(&report);
+                        log::godot_error!("Aborting process due to panic policy.");
+                        flush_stdout();
+                        std::process::abort();
+                    }
+                    PanicPolicy::Custom(handler) => {
+                        let handled =
+                            std::panic::catch_unwind(AssertUnwindSafe(|| handler(&report)));
+                        if let Err(handler_err) = handled {
+                            log::godot_error!("Custom panic handler panicked itself.");
+                            print_panic_payload(&*handler_err);
+                        }
+                    }
+                }
+
                 None
             }
         }
     }
 
+    fn print_panic_report(report: &PanicReport) {
+        match (report.file(), report.line()) {
+            (Some(file), Some(line)) => log::godot_error!(
+                "Rust function panicked in file {file} at line {line}. Context: {}",
+                report.context()
+            ),
+            _ => log::godot_error!("Rust function panicked. Context: {}", report.context()),
+        }
+
+        print_panic_payload(report.payload());
+    }
+
     pub fn flush_stdout() {
         use std::io::Write;
         std::io::stdout().flush().expect("flush stdout");
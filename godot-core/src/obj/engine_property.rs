@@ -0,0 +1,113 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{StringName, Variant};
+use crate::obj::{Gd, GodotClass, Inherits};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Metadata of a property declared by the engine class `C`.
+///
+/// Returned by the accessors of generated property collections, such as `Node2D::properties().position()`. Can be used wherever a
+/// property name is expected, or to read and write the property dynamically on an object.
+pub struct EngineProperty<C: GodotClass> {
+    name: &'static str,
+    godot_type: &'static str,
+    getter: &'static str,
+    setter: &'static str,
+    _class: PhantomData<fn(C)>,
+}
+
+impl<C: GodotClass> EngineProperty<C> {
+    #[doc(hidden)]
+    pub const fn __new(
+        name: &'static str,
+        godot_type: &'static str,
+        getter: &'static str,
+        setter: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            godot_type,
+            getter,
+            setter,
+            _class: PhantomData,
+        }
+    }
+
+    /// Name of the property, as registered in Godot.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Type of the property, as specified by Godot's extension API (e.g. `"Vector2"` or `"Texture2D"`).
+    pub fn godot_type(&self) -> &'static str {
+        self.godot_type
+    }
+
+    /// Name of the getter method, or `None` if the property is write-only.
+    pub fn getter(&self) -> Option<&'static str> {
+        (!self.getter.is_empty()).then_some(self.getter)
+    }
+
+    /// Name of the setter method, or `None` if the property is read-only.
+    pub fn setter(&self) -> Option<&'static str> {
+        (!self.setter.is_empty()).then_some(self.setter)
+    }
+
+    /// Reads the property from `object`.
+    ///
+    /// _Godot equivalent: `Object.get()`_
+    pub fn get<T>(&self, object: &Gd<T>) -> Variant
+    where
+        T: Inherits<C>,
+    {
+        object.upcast_object().get(StringName::from(self.name))
+    }
+
+    /// Writes the property on `object`.
+    ///
+    /// _Godot equivalent: `Object.set()`_
+    pub fn set<T>(&self, object: &mut Gd<T>, value: Variant)
+    where
+        T: Inherits<C>,
+    {
+        object
+            .upcast_object()
+            .set(StringName::from(self.name), value);
+    }
+}
+
+impl<C: GodotClass> Clone for EngineProperty<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: GodotClass> Copy for EngineProperty<C> {}
+
+impl<C: GodotClass> From<EngineProperty<C>> for StringName {
+    fn from(property: EngineProperty<C>) -> Self {
+        StringName::from(property.name)
+    }
+}
+
+impl<C: GodotClass> fmt::Debug for EngineProperty<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EngineProperty")
+            .field("class", &C::class_name())
+            .field("name", &self.name)
+            .field("type", &self.godot_type)
+            .finish()
+    }
+}
+
+impl<C: GodotClass> fmt::Display for EngineProperty<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
@@ -0,0 +1,86 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Callable, StringName};
+use crate::obj::{Gd, GodotClass, Inherits, SignalParams};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Compile-time checked reference to a `#[func]` of the user class `C`, taking parameters `Ps`.
+///
+/// Obtained through the `callable!` macro, e.g. `callable!(MyClass::on_hit)`. The referenced method must exist and be declared
+/// with `#[func]`; renaming it breaks compilation rather than signal connections at runtime. The name seen by Godot takes
+/// `#[func(rename = ...)]` into account.
+///
+/// The parameter tuple `Ps` allows connecting only to signals with matching parameters, see
+/// [`TypedSignal::connect_func()`][crate::obj::TypedSignal::connect_func].
+pub struct FuncRef<C: GodotClass, Ps> {
+    name: &'static str,
+    _signature: PhantomData<fn(C, Ps)>,
+}
+
+impl<C: GodotClass, Ps> FuncRef<C, Ps> {
+    #[doc(hidden)]
+    pub const fn __new(name: &'static str) -> Self {
+        Self {
+            name,
+            _signature: PhantomData,
+        }
+    }
+
+    /// Name of the method, as registered in Godot.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Number of parameters of the method.
+    pub fn arity(&self) -> usize
+    where
+        Ps: SignalParams,
+    {
+        Ps::PARAM_COUNT
+    }
+
+    /// Creates a callable invoking this method on `object`.
+    ///
+    /// _Godot equivalent: `Callable(object, method)`_
+    pub fn callable<T>(&self, object: &Gd<T>) -> Callable
+    where
+        T: Inherits<C>,
+    {
+        Callable::from_object_method(object, self.name)
+    }
+}
+
+impl<C: GodotClass, Ps> Clone for FuncRef<C, Ps> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: GodotClass, Ps> Copy for FuncRef<C, Ps> {}
+
+impl<C: GodotClass, Ps> From<FuncRef<C, Ps>> for StringName {
+    fn from(func: FuncRef<C, Ps>) -> Self {
+        StringName::from(func.name)
+    }
+}
+
+impl<C: GodotClass, Ps> fmt::Debug for FuncRef<C, Ps> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FuncRef")
+            .field("class", &C::class_name())
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+impl<C: GodotClass, Ps> fmt::Display for FuncRef<C, Ps> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
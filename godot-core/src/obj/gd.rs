@@ -18,7 +18,8 @@ use crate::builtin::meta::{
 use crate::builtin::{Callable, StringName};
 use crate::obj::raw::RawGd;
 use crate::obj::{
-    bounds, cap, Bounds, EngineEnum, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits, InstanceId,
+    bounds, cap, BorrowError, Bounds, EngineEnum, GdDerefTarget, GdMut, GdRef, GodotClass,
+    Inherits, InstanceId, WeakGd, WithSignals,
 };
 use crate::property::{Export, PropertyHintInfo, TypeStringHint, Var};
 use crate::{callbacks, engine, out};
@@ -195,6 +196,44 @@ where
     pub fn bind_mut(&mut self) -> GdMut<T> {
         self.raw.bind_mut()
     }
+
+    /// Hands out a guard for a shared borrow, or returns an error if that is not possible.
+    ///
+    /// Non-panicking variant of [`bind()`](Self::bind). This is useful for code that may be re-entered while the instance is bound,
+    /// such as signal handlers, and which can defer or skip its work in that case.
+    ///
+    /// # Errors
+    /// * [`BorrowError::MutablyBound`] if a `GdMut` guard or `&mut T` reference to the user instance is live.
+    /// * [`BorrowError::DeadInstance`] if the object has been freed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # #[derive(GodotClass)]
+    /// # #[class(init)]
+    /// # struct Enemy { health: i32 }
+    /// fn report_health(enemy: &Gd<Enemy>) {
+    ///     match enemy.try_bind() {
+    ///         Ok(enemy) => godot_print!("health: {}", enemy.health),
+    ///         Err(err) => godot_print!("enemy busy, skipping report: {err}"),
+    ///     }
+    /// }
+    /// ```
+    pub fn try_bind(&self) -> Result<GdRef<T>, BorrowError> {
+        self.raw.try_bind()
+    }
+
+    /// Hands out a guard for an exclusive borrow, or returns an error if that is not possible.
+    ///
+    /// Non-panicking variant of [`bind_mut()`](Self::bind_mut). See [`try_bind()`](Self::try_bind) for when to use it.
+    ///
+    /// # Errors
+    /// * [`BorrowError::MutablyBound`] if a `GdMut` guard or `&mut T` reference to the user instance is live.
+    /// * [`BorrowError::SharedBound`] if a `GdRef` guard or `&T` reference to the user instance is live.
+    /// * [`BorrowError::DeadInstance`] if the object has been freed.
+    pub fn try_bind_mut(&mut self) -> Result<GdMut<T>, BorrowError> {
+        self.raw.try_bind_mut()
+    }
 }
 
 /// _The methods in this impl block are available for any `T`._ <br><br>
@@ -267,6 +306,13 @@ impl<T: GodotClass> Gd<T> {
         unsafe { self.raw.instance_id_unchecked().unwrap_unchecked() }
     }
 
+    /// Creates a weak pointer to this object, which does not keep it alive.
+    ///
+    /// See [`WeakGd`] for details. This method never panics; if the object is already dead, upgrading the weak pointer will fail.
+    pub fn downgrade(&self) -> WeakGd<T> {
+        WeakGd::new(self.instance_id_unchecked())
+    }
+
     /// Checks if this smart pointer points to a live object (read description!).
     ///
     /// Using this method is often indicative of bad design -- you should dispose of your pointers once an object is
@@ -428,12 +474,29 @@ impl<T: GodotClass> Gd<T> {
         self.raw.obj_sys()
     }
 
+    /// Returns another `Gd<Object>` to the same object, also if `T` is not statically known to inherit `Object`.
+    pub(crate) fn upcast_object(&self) -> Gd<engine::Object> {
+        // SAFETY: every Godot class derives from Object.
+        unsafe { Gd::from_obj_sys(self.obj_sys()) }
+    }
+
     /// Returns a callable referencing a method from this object named `method_name`.
     ///
     /// This is shorter syntax for [`Callable::from_object_method(self, method_name)`][Callable::from_object_method].
+    /// For `#[func]` methods of user classes, the `callable!` macro checks the method name at compile time.
     pub fn callable<S: Into<StringName>>(&self, method_name: S) -> Callable {
         Callable::from_object_method(self, method_name)
     }
+
+    /// Returns the type-safe signals declared with `#[signal]` in the class' `#[godot_api]` block.
+    ///
+    /// See [`TypedSignal`][crate::obj::TypedSignal] for how to emit and connect them.
+    pub fn signals(&self) -> T::SignalCollection<'static>
+    where
+        T: WithSignals,
+    {
+        T::__signals_new(self.upcast_object(), None)
+    }
 }
 
 impl<T: GodotClass> Deref for Gd<T> {
@@ -8,6 +8,7 @@
 use godot_cell::{InaccessibleGuard, MutGuard, RefGuard};
 use godot_ffi::out;
 
+use std::fmt;
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut};
 
@@ -81,6 +82,39 @@ impl<T: GodotClass> Drop for GdMut<'_, T> {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Error returned by [`Gd::try_bind()`][crate::obj::Gd::try_bind] and [`Gd::try_bind_mut()`][crate::obj::Gd::try_bind_mut].
+///
+/// Unlike the panicking [`bind()`][crate::obj::Gd::bind] and [`bind_mut()`][crate::obj::Gd::bind_mut], these allow code that may be
+/// re-entered (e.g. signal handlers) to detect an existing borrow and defer or skip its work.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BorrowError {
+    /// The instance is currently bound mutably, through a [`GdMut`] guard or a `&mut self` method call.
+    MutablyBound,
+
+    /// The instance is currently bound shared, through a [`GdRef`] guard or a `&self` method call. Only blocks mutable borrows.
+    SharedBound,
+
+    /// The object has been freed, so there is no instance to bind.
+    DeadInstance,
+}
+
+impl fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::MutablyBound => "instance is already bound mutably",
+            Self::SharedBound => "instance is already bound shared, cannot bind mutably",
+            Self::DeadInstance => "object has been freed, cannot bind instance",
+        };
+
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for BorrowError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// Shared reference guard for a [`Base`](crate::obj::Base) pointer.
 ///
 /// This can be used to call methods on the base object of a rust object that take `&self` as the receiver.
@@ -12,22 +12,30 @@
 //! * [`Gd`], a smart pointer that manages instances of Godot classes.
 
 mod base;
+mod engine_property;
+mod func_ref;
 mod gd;
 mod guards;
 mod instance_id;
 mod onready;
 mod raw;
 mod traits;
+mod typed_signal;
+mod weak_gd;
 
 pub(crate) mod rtti;
 
 pub use base::*;
+pub use engine_property::*;
+pub use func_ref::*;
 pub use gd::*;
 pub use guards::*;
 pub use instance_id::*;
 pub use onready::*;
 pub use raw::*;
 pub use traits::*;
+pub use typed_signal::*;
+pub use weak_gd::*;
 
 pub mod bounds;
 pub use bounds::private::Bounds;
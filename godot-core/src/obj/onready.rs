@@ -5,6 +5,9 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::builtin::NodePath;
+use crate::engine::Node;
+use crate::obj::{Gd, GodotClass, Inherits};
 use crate::property::{Export, PropertyHintInfo, Var};
 use std::mem;
 
@@ -14,14 +17,18 @@ use std::mem;
 /// Godot in particular encourages initialization inside `ready()`, e.g. to access the scene tree after a node is inserted into it.
 /// The alternative to using this pattern is [`Option<T>`][option], which needs to be explicitly unwrapped with `unwrap()` or `expect()` each time.
 ///
-/// `OnReady<T>` should always be used as a field. There are two modes to use it:
+/// `OnReady<T>` should always be used as a field. There are three modes to use it:
 ///
 /// 1. **Automatic mode, using [`new()`](Self::new).**<br>
 ///    Before `ready()` is called, all `OnReady` fields constructed with `new()` are automatically initialized, in the order of
 ///    declaration. This means that you can safely access them in `ready()`.<br><br>
 /// 2. **Manual mode, using [`manual()`](Self::manual).**<br>
 ///    These fields are left uninitialized until you call [`init()`][Self::init] on them. This is useful if you need more complex
-///    initialization scenarios than a closure allows. If you forget initialization, a panic will occur on first access.
+///    initialization scenarios than a closure allows. If you forget initialization, a panic will occur on first access.<br><br>
+/// 3. **Node lookup, using `#[init(node = "path")]`.**<br>
+///    For `OnReady<Gd<T>>` fields in classes with `#[class(init)]` and a `#[base]` field, the node at the given path (relative to the
+///    base node) is fetched before `ready()`, like with [`NodeExt::get_node_as()`][crate::engine::NodeExt::get_node_as]. If there is no node
+///    at that path or it is not of type `T`, a panic occurs which names the class, field and path.
 ///
 /// Conceptually, `OnReady<T>` is very close to [once_cell's `Lazy<T>`][lazy], with additional hooks into the Godot lifecycle.
 /// The absence of methods to check initialization state is deliberate: you don't need them if you follow the above two patterns.
@@ -148,6 +155,37 @@ impl<T> OnReady<T> {
     }
 }
 
+impl<T> OnReady<Gd<T>>
+where
+    T: GodotClass + Inherits<Node>,
+{
+    /// Initializes with the node at `path`, relative to `base`.
+    ///
+    /// # Panics
+    /// If there is no such node, it is not of type `T`, or the value is already initialized.
+    pub(crate) fn init_node(
+        &mut self,
+        base: &Gd<Node>,
+        path: NodePath,
+        class_name: &str,
+        field_name: &str,
+    ) {
+        let Some(node) = base.get_node_or_null(path.clone()) else {
+            panic!("{class_name}::{field_name}: #[init(node)] found no node at path `{path}`");
+        };
+
+        let node = node.try_cast::<T>().unwrap_or_else(|node| {
+            panic!(
+                "{class_name}::{field_name}: #[init(node)] expected node at path `{path}` to be of type {expected}, but it is {actual}",
+                expected = T::class_name(),
+                actual = node.get_class(),
+            )
+        });
+
+        self.init(node);
+    }
+}
+
 // Panicking Deref is not best practice according to Rust, but constant get() calls are significantly less ergonomic and make it harder to
 // migrate between T and LateInit<T>, because all the accesses need to change.
 impl<T> std::ops::Deref for OnReady<T> {
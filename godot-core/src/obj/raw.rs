@@ -17,7 +17,9 @@ use crate::builtin::meta::{
 use crate::builtin::Variant;
 use crate::obj::bounds::DynMemory as _;
 use crate::obj::rtti::ObjectRtti;
-use crate::obj::{bounds, Bounds, GdDerefTarget, GdMut, GdRef, GodotClass, InstanceId};
+use crate::obj::{
+    bounds, BorrowError, Bounds, GdDerefTarget, GdMut, GdRef, GodotClass, InstanceId,
+};
 use crate::storage::{InstanceStorage, Storage};
 use crate::{engine, out};
 
@@ -368,6 +370,30 @@ where
         GdMut::from_guard(self.storage().unwrap().get_mut())
     }
 
+    /// Non-panicking variant of [`bind()`](Self::bind), see [`crate::obj::Gd::try_bind()`].
+    pub(crate) fn try_bind(&self) -> Result<GdRef<T>, BorrowError> {
+        self.check_bindable("try_bind")?;
+        let guard = self.storage().unwrap().try_get()?;
+        Ok(GdRef::from_guard(guard))
+    }
+
+    /// Non-panicking variant of [`bind_mut()`](Self::bind_mut), see [`crate::obj::Gd::try_bind_mut()`].
+    pub(crate) fn try_bind_mut(&mut self) -> Result<GdMut<T>, BorrowError> {
+        self.check_bindable("try_bind_mut")?;
+        let guard = self.storage().unwrap().try_get_mut()?;
+        Ok(GdMut::from_guard(guard))
+    }
+
+    /// Like [`check_rtti()`](Self::check_rtti), but reports a freed object as error instead of panicking.
+    fn check_bindable(&self, context: &'static str) -> Result<(), BorrowError> {
+        if !self.is_instance_valid() {
+            return Err(BorrowError::DeadInstance);
+        }
+
+        self.check_rtti(context);
+        Ok(())
+    }
+
     /// Storage object associated with the extension instance.
     ///
     /// Returns `None` if self is null.
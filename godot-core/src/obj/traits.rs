@@ -371,6 +371,39 @@ pub trait WithBaseField: GodotClass + Bounds<Declarer = bounds::DeclUser> {
 
         BaseMut::new(base_gd, guard)
     }
+
+    /// Returns the type-safe signals declared with `#[signal]` in this class' `#[godot_api]` block.
+    ///
+    /// Like [`base_mut()`][Self::base_mut], this keeps `self` accessible to other code while a signal is emitted. Receivers may thus
+    /// call `#[func]` methods of this object or bind it again.
+    ///
+    /// See [`TypedSignal`](crate::obj::TypedSignal) for how to emit and connect them.
+    fn signals(&mut self) -> Self::SignalCollection<'_>
+    where
+        Self: WithSignals,
+    {
+        let base = self.base_mut();
+        let object = base.upcast_object();
+        Self::__signals_new(object, Some(base))
+    }
+}
+
+/// Implemented for user classes that declare at least one `#[signal]` in their `#[godot_api]` block.
+///
+/// Provides access to the generated signal collection, through [`WithBaseField::signals()`] or [`Gd::signals()`].
+pub trait WithSignals: GodotClass + Bounds<Declarer = bounds::DeclUser> {
+    /// Type generated by `#[godot_api]`, with one accessor method per signal.
+    ///
+    /// `'c` is the lifetime of the `base_mut()` guard held when obtained through [`WithBaseField::signals()`].
+    type SignalCollection<'c>
+    where
+        Self: 'c;
+
+    #[doc(hidden)]
+    fn __signals_new(
+        object: Gd<crate::engine::Object>,
+        base_guard: Option<BaseMut<'_, Self>>,
+    ) -> Self::SignalCollection<'_>;
 }
 
 /// Extension trait for all reference-counted classes.
@@ -489,6 +522,16 @@ pub mod cap {
         fn __register_methods();
         #[doc(hidden)]
         fn __register_constants();
+
+        /// Applies the `#[rpc]` configurations to a node instance. Only generated if the `impl` block declares RPCs.
+        #[doc(hidden)]
+        fn __register_rpcs(_object: Gd<Self>) {}
+
+        /// Virtual function lookup which the `_ready` hook for RPCs delegates to. Only generated if the `impl` block declares RPCs.
+        #[doc(hidden)]
+        fn __next_virtual_call(_name: &str) -> sys::GDExtensionClassCallVirtual {
+            None
+        }
     }
 
     pub trait ImplementsGodotExports: GodotClass {
@@ -0,0 +1,321 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::{ConvertError, FromGodot, ToGodot};
+use crate::builtin::{Callable, StringName, Variant};
+use crate::engine::Object;
+use crate::obj::{BaseMut, FuncRef, Gd, GodotClass, Inherits};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Type-safe handle to a signal declared with `#[signal]` inside a `#[godot_api]` block, or by an engine class.
+///
+/// Obtained through the signal accessors that `#[godot_api]` generates, for example `self.signals().hit()` (from within the class, if it has a
+/// `#[base]` field) or `gd.signals().hit()` (on any `Gd<T>` pointer). For engine classes, see [`EngineSignal::on()`].
+///
+/// `C` is the class declaring the signal and `Ps` is a tuple of the parameter types in the signal declaration, so emitting or
+/// connecting with mismatched arguments fails at compile time.
+///
+/// A signal obtained through `self.signals()` holds a [`base_mut()`](crate::obj::WithBaseField::base_mut) guard for its lifetime `'c`.
+/// This lets connected receivers bind the emitting object again, for example by calling one of its `#[func]` methods, while the emission
+/// happens inside a `&mut self` method.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base = Node)]
+/// struct Player {
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[signal]
+///     fn hit(damage: i32, source: Gd<Node>);
+///
+///     #[func]
+///     fn take_damage(&mut self, damage: i32, source: Gd<Node>) {
+///         self.signals().hit().emit(damage, source);
+///     }
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base = Node)]
+/// struct Hud {}
+///
+/// #[godot_api]
+/// impl Hud {
+///     fn on_hit(&mut self, damage: i32, _source: Gd<Node>) {
+///         godot_print!("Player took {damage} damage");
+///     }
+/// }
+///
+/// fn wire_up(player: &Gd<Player>, hud: &Gd<Hud>) {
+///     player.signals().hit().connect(hud, Hud::on_hit);
+/// }
+/// ```
+pub struct TypedSignal<'c, C: GodotClass, Ps> {
+    object: Gd<Object>,
+    name: &'static str,
+    _base_guard: Option<BaseMut<'c, C>>,
+    _signature: PhantomData<fn(C, Ps)>,
+}
+
+impl<'c, C: GodotClass, Ps: SignalParams> TypedSignal<'c, C, Ps> {
+    #[doc(hidden)]
+    pub fn __new(
+        object: Gd<Object>,
+        name: &'static str,
+        base_guard: Option<BaseMut<'c, C>>,
+    ) -> Self {
+        Self {
+            object,
+            name,
+            _base_guard: base_guard,
+            _signature: PhantomData,
+        }
+    }
+
+    /// Name of the signal, as registered in Godot.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Emits the signal, with all arguments passed as a tuple.
+    ///
+    /// Equivalent to the positional `emit(arg0, arg1, ...)` method, which is usually more readable.
+    pub fn emit_tuple(&mut self, args: Ps) {
+        let args = args.to_variants();
+        self.object.emit_signal(StringName::from(self.name), &args);
+    }
+
+    /// Connects the signal to an arbitrary callable.
+    ///
+    /// This is not type-checked against the signal's parameters; prefer [`connect()`][Self::connect] where possible.
+    pub fn connect_callable(&mut self, callable: Callable) {
+        self.object.connect(StringName::from(self.name), callable);
+    }
+
+    /// Connects the signal to a `#[func]` of `receiver`, referenced through the `callable!` macro.
+    ///
+    /// The method's parameters must match those of the signal, which is checked at compile time. Unlike with
+    /// [`connect()`][Self::connect], the method is invoked through Godot, like a connection made from GDScript.
+    pub fn connect_func<R, T>(&mut self, receiver: &Gd<T>, func: FuncRef<R, Ps>)
+    where
+        R: GodotClass,
+        T: Inherits<R>,
+    {
+        self.connect_callable(func.callable(receiver));
+    }
+
+    /// Connects the signal to a method of a user-defined class.
+    ///
+    /// `method` is typically a path like `Receiver::on_hit`, referring to a method taking `&mut self` followed by the exact parameters
+    /// of the signal. Upon emission, the receiver is looked up by instance ID and bound mutably for the duration of the call.
+    /// If the receiver has been destroyed in the meantime, or the emitted arguments cannot be converted, the call is skipped and
+    /// the reason is logged as a Godot error.
+    #[cfg(since_api = "4.2")]
+    pub fn connect<R, F>(&mut self, receiver: &Gd<R>, method: F)
+    where
+        R: GodotClass + crate::obj::Bounds<Declarer = crate::obj::bounds::DeclUser>,
+        F: SignalReceiver<R, Ps>,
+    {
+        let instance_id = receiver.instance_id();
+        let mut method = method;
+
+        let callable_name = format!("{}::{} -> {}", C::class_name(), self.name, R::class_name());
+        let error_context = callable_name.clone();
+        let callable = Callable::from_fn(callable_name, move |args: &[&Variant]| {
+            let params = Ps::try_from_variants(args).map_err(|err| {
+                godot_error!("{error_context}: invalid signal arguments: {err}");
+            })?;
+            let mut receiver = Gd::<R>::try_from_instance_id(instance_id).map_err(|_| {
+                godot_error!("{error_context}: receiver freed (instance ID {instance_id})");
+            })?;
+
+            let mut guard = receiver.bind_mut();
+            method.invoke(&mut guard, params);
+
+            Ok(Variant::nil())
+        });
+
+        self.connect_callable(callable);
+    }
+}
+
+impl<C: GodotClass, Ps> fmt::Debug for TypedSignal<'_, C, Ps> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedSignal")
+            .field("class", &C::class_name())
+            .field("name", &self.name)
+            .field("object", &self.object)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Name and parameter types of a signal declared by the engine class `C`.
+///
+/// Returned by the accessors of generated signal collections, such as `Button::signals().pressed()`. Can be used wherever a signal
+/// name is expected, or bound to an object with [`on()`][Self::on] for type-safe emission and connection.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::Button;
+///
+/// fn on_pressed(button: &mut Gd<Button>, callable: Callable) {
+///     // Instead of the string "pressed":
+///     button.connect(Button::signals().pressed().into(), callable);
+/// }
+/// ```
+pub struct EngineSignal<C: GodotClass, Ps> {
+    name: &'static str,
+    _signature: PhantomData<fn(C, Ps)>,
+}
+
+impl<C: GodotClass, Ps: SignalParams> EngineSignal<C, Ps> {
+    #[doc(hidden)]
+    pub const fn __new(name: &'static str) -> Self {
+        Self {
+            name,
+            _signature: PhantomData,
+        }
+    }
+
+    /// Name of the signal, as registered in Godot.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Binds the signal to a concrete object, allowing type-safe emission and connection.
+    pub fn on<T>(&self, object: &Gd<T>) -> TypedSignal<'static, C, Ps>
+    where
+        T: Inherits<C>,
+    {
+        TypedSignal::__new(object.upcast_object(), self.name, None)
+    }
+}
+
+impl<C: GodotClass, Ps> Clone for EngineSignal<C, Ps> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: GodotClass, Ps> Copy for EngineSignal<C, Ps> {}
+
+impl<C: GodotClass, Ps> From<EngineSignal<C, Ps>> for StringName {
+    fn from(signal: EngineSignal<C, Ps>) -> Self {
+        StringName::from(signal.name)
+    }
+}
+
+impl<C: GodotClass, Ps> fmt::Debug for EngineSignal<C, Ps> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EngineSignal")
+            .field("class", &C::class_name())
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+impl<C: GodotClass, Ps> fmt::Display for EngineSignal<C, Ps> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Tuple of parameter types, as declared by a `#[signal]` function.
+///
+/// Implemented for tuples of up to 10 elements, each of which must implement [`ToGodot`] and [`FromGodot`].
+pub trait SignalParams: Sized + 'static {
+    /// Number of parameters in the signal.
+    const PARAM_COUNT: usize;
+
+    #[doc(hidden)]
+    fn to_variants(&self) -> Vec<Variant>;
+
+    #[doc(hidden)]
+    fn try_from_variants(args: &[&Variant]) -> Result<Self, ConvertError>;
+}
+
+/// Function that can be connected to a [`TypedSignal`] with parameters `Ps`, invoked on an instance of class `C`.
+///
+/// Implemented for all `FnMut(&mut C, P0, P1, ...)`, in particular methods with a `&mut self` receiver.
+pub trait SignalReceiver<C, Ps>: 'static + Send + Sync {
+    #[doc(hidden)]
+    fn invoke(&mut self, instance: &mut C, params: Ps);
+}
+
+macro_rules! impl_signal_for_tuple {
+    (
+        $PARAM_COUNT:literal
+        $(, ($pn:ident, $n:tt) : $Pn:ident)* // $n cannot be literal if substituted as tuple index .0
+    ) => {
+        #[allow(unused_variables)]
+        impl<$($Pn,)*> SignalParams for ($($Pn,)*)
+        where
+            $( $Pn: ToGodot + FromGodot + 'static, )*
+        {
+            const PARAM_COUNT: usize = $PARAM_COUNT;
+
+            fn to_variants(&self) -> Vec<Variant> {
+                vec![
+                    $( self.$n.to_variant(), )*
+                ]
+            }
+
+            fn try_from_variants(args: &[&Variant]) -> Result<Self, ConvertError> {
+                if args.len() != $PARAM_COUNT {
+                    return Err(ConvertError::with_value(args));
+                }
+
+                Ok((
+                    $( $Pn::try_from_variant(args[$n])?, )*
+                ))
+            }
+        }
+
+        impl<C, F, $($Pn,)*> SignalReceiver<C, ($($Pn,)*)> for F
+        where
+            F: FnMut(&mut C, $($Pn,)*) + 'static + Send + Sync,
+        {
+            fn invoke(&mut self, instance: &mut C, ($($pn,)*): ($($Pn,)*)) {
+                self(instance, $($pn,)*)
+            }
+        }
+
+        impl<C: GodotClass, $($Pn,)*> TypedSignal<'_, C, ($($Pn,)*)>
+        where
+            $( $Pn: ToGodot + FromGodot + 'static, )*
+        {
+            /// Emits the signal with the given arguments.
+            pub fn emit(&mut self, $($pn: $Pn,)*) {
+                self.emit_tuple(($($pn,)*))
+            }
+        }
+    };
+}
+
+impl_signal_for_tuple!(0);
+impl_signal_for_tuple!(1, (p0, 0): P0);
+impl_signal_for_tuple!(2, (p0, 0): P0, (p1, 1): P1);
+impl_signal_for_tuple!(3, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2);
+impl_signal_for_tuple!(4, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3);
+impl_signal_for_tuple!(5, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4);
+impl_signal_for_tuple!(6, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5);
+impl_signal_for_tuple!(7, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6);
+impl_signal_for_tuple!(8, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7);
+impl_signal_for_tuple!(9, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7, (p8, 8): P8);
+impl_signal_for_tuple!(10, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7, (p8, 8): P8, (p9, 9): P9);
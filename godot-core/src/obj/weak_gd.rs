@@ -0,0 +1,117 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use crate::builtin::meta::{GodotConvert, ToGodot};
+use crate::engine::{self, WeakRef};
+use crate::obj::{Gd, GodotClass, InstanceId, NewGd};
+
+/// Weak pointer to a Godot object, which does not keep it alive.
+///
+/// Obtained through [`Gd::downgrade()`]. A `WeakGd` only stores the object's [`InstanceId`]: it never touches the reference count of
+/// `RefCounted` objects, and it does not prevent manually managed objects from being freed. This makes it suitable for caches and
+/// back-references (e.g. from a child resource to its parent), which would otherwise create reference cycles.
+///
+/// To access the object, [`upgrade()`](Self::upgrade) the weak pointer to a `Gd<T>`, which fails once the object has been destroyed.
+/// This works the same way for manually managed and reference-counted objects.
+///
+/// When passed to Godot, a `WeakGd` is converted to a [`WeakRef`], the GDScript equivalent.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::obj::WeakGd;
+///
+/// let strong = RefCounted::new_gd();
+/// let weak: WeakGd<RefCounted> = strong.downgrade();
+/// assert_eq!(weak.upgrade(), Some(strong.clone()));
+///
+/// drop(strong); // Last reference: object is destroyed.
+/// assert_eq!(weak.upgrade(), None);
+/// ```
+pub struct WeakGd<T: GodotClass> {
+    instance_id: InstanceId,
+
+    // Like Gd<T>, not Send/Sync: upgrading on another thread would create a Gd<T> there.
+    _marker: PhantomData<*const T>,
+}
+
+impl<T: GodotClass> WeakGd<T> {
+    pub(crate) fn new(instance_id: InstanceId) -> Self {
+        Self {
+            instance_id,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a strong pointer to the object, or `None` if it has been destroyed.
+    ///
+    /// For `RefCounted` objects, the returned `Gd<T>` increments the reference count as usual, keeping the object alive while it exists.
+    pub fn upgrade(&self) -> Option<Gd<T>> {
+        Gd::try_from_instance_id(self.instance_id).ok()
+    }
+
+    /// Returns `true` if the object is still alive, i.e. [`upgrade()`](Self::upgrade) would currently succeed.
+    pub fn is_instance_valid(&self) -> bool {
+        engine::utilities::is_instance_id_valid(self.instance_id.to_i64())
+    }
+
+    /// Returns the instance ID of the referenced object, whether it is alive or not.
+    pub fn instance_id(&self) -> InstanceId {
+        self.instance_id
+    }
+}
+
+// Manual impls to avoid bounds on T.
+impl<T: GodotClass> Clone for WeakGd<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GodotClass> Copy for WeakGd<T> {}
+
+impl<T: GodotClass> PartialEq for WeakGd<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.instance_id == other.instance_id
+    }
+}
+
+impl<T: GodotClass> Eq for WeakGd<T> {}
+
+impl<T: GodotClass> Hash for WeakGd<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.instance_id.hash(state);
+    }
+}
+
+impl<T: GodotClass> Debug for WeakGd<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        // Static class name, since the object may no longer be alive.
+        let id = self.instance_id;
+        let class = T::class_name();
+        write!(f, "WeakGd {{ id: {id}, class: {class} }}")
+    }
+}
+
+impl<T: GodotClass> GodotConvert for WeakGd<T> {
+    type Via = Gd<WeakRef>;
+}
+
+impl<T: GodotClass> ToGodot for WeakGd<T> {
+    /// Returns a `WeakRef` to the object. If the object has been destroyed, the `WeakRef` is empty (`get_ref()` returns null), just
+    /// like a GDScript `WeakRef` whose target was freed.
+    fn to_godot(&self) -> Self::Via {
+        match self.upgrade() {
+            Some(obj) => engine::utilities::weakref(obj.to_variant()).to::<Gd<WeakRef>>(),
+            None => WeakRef::new_gd(),
+        }
+    }
+}
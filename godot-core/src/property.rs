@@ -7,6 +7,9 @@
 
 //! Registration support for property types.
 
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::builtin::meta::{FromGodot, GodotConvert, ToGodot};
 use crate::builtin::GString;
 use crate::engine::global::PropertyHint;
 
@@ -90,6 +93,65 @@ where
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Blanket impls for Rust collections
+
+// Rust collections are stored by value and registered as the Godot collection they are converted to, e.g. `Vec<u8>` is exported
+// like a `PackedByteArray`, and `HashMap<K, V>` like a `Dictionary`.
+macro_rules! impl_property_by_via {
+    ($( [$($generics:tt)*] $Ty:ty ),* $(,)?) => {
+        $(
+            impl<$($generics)*> Var for $Ty
+            where
+                Self: ToGodot + FromGodot + Clone,
+                <Self as GodotConvert>::Via: Var,
+            {
+                type Intermediate = Self;
+
+                fn get_property(&self) -> Self::Intermediate {
+                    self.clone()
+                }
+
+                fn set_property(&mut self, value: Self::Intermediate) {
+                    *self = value;
+                }
+
+                fn property_hint() -> PropertyHintInfo {
+                    <<Self as GodotConvert>::Via as Var>::property_hint()
+                }
+            }
+
+            impl<$($generics)*> Export for $Ty
+            where
+                Self: ToGodot + FromGodot + Clone,
+                <Self as GodotConvert>::Via: Export,
+            {
+                fn default_export_info() -> PropertyHintInfo {
+                    <<Self as GodotConvert>::Via as Export>::default_export_info()
+                }
+            }
+
+            impl<$($generics)*> TypeStringHint for $Ty
+            where
+                Self: GodotConvert,
+                <Self as GodotConvert>::Via: TypeStringHint,
+            {
+                fn type_string() -> String {
+                    <<Self as GodotConvert>::Via as TypeStringHint>::type_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_property_by_via!(
+    [T] Vec<T>,
+    [T, const N: usize] [T; N],
+    [T, S] HashSet<T, S>,
+    [K, V, S] HashMap<K, V, S>,
+    [K, V] BTreeMap<K, V>,
+);
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Export machinery
 
@@ -101,6 +101,11 @@ pub enum PluginComponent {
         ///
         /// Always present since that's the entire point of this `impl` block.
         register_methods_constants_fn: ErasedRegisterFn,
+
+        /// Callback for virtuals, which hooks into `_ready` to configure the `#[rpc]` methods of each node instance.
+        ///
+        /// Only present if the `impl` block contains at least one `#[rpc]` method.
+        rpc_virtual_fn: sys::GDExtensionClassGetVirtual,
     },
 
     /// Collected from `#[godot_api] impl GodotExt for MyClass`
@@ -171,6 +176,7 @@ struct ClassRegistrationInfo {
     register_methods_constants_fn: Option<ErasedRegisterFn>,
     register_properties_fn: Option<ErasedRegisterFn>,
     user_register_fn: Option<ErasedRegisterFn>,
+    rpc_virtual_fn: sys::GDExtensionClassGetVirtual, // Option (set if a `#[godot_api] impl MyClass` declares RPCs)
     default_virtual_fn: sys::GDExtensionClassGetVirtual, // Option (set if there is at least one OnReady field)
     user_virtual_fn: sys::GDExtensionClassGetVirtual, // Option (set if there is a `#[godot_api] impl I*`)
 
@@ -238,6 +244,7 @@ pub fn register_class<
         user_register_fn: Some(ErasedRegisterFn {
             raw: callbacks::register_class_by_builder::<T>,
         }),
+        rpc_virtual_fn: None,
         user_virtual_fn: None,
         default_virtual_fn: None,
         godot_params,
@@ -363,8 +370,10 @@ fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
 
         PluginComponent::UserMethodBinds {
             register_methods_constants_fn,
+            rpc_virtual_fn,
         } => {
             c.register_methods_constants_fn = Some(register_methods_constants_fn);
+            c.rpc_virtual_fn = rpc_virtual_fn;
         }
 
         PluginComponent::UserVirtuals {
@@ -426,8 +435,12 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
 
     // Register virtual functions -- if the user provided some via #[godot_api], take those; otherwise, use the
     // ones generated alongside #[derive(GodotClass)]. The latter can also be null, if no OnReady is provided.
+    // Classes with RPCs hook into _ready, delegating to the above for everything else.
     if info.godot_params.get_virtual_func.is_none() {
-        info.godot_params.get_virtual_func = info.user_virtual_fn.or(info.default_virtual_fn);
+        info.godot_params.get_virtual_func = info
+            .rpc_virtual_fn
+            .or(info.user_virtual_fn)
+            .or(info.default_virtual_fn);
     }
 
     unsafe {
@@ -464,8 +477,9 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
 
     // ...then custom symbols
 
-    //let mut class_builder = crate::builder::ClassBuilder::<?>::new();
-    let mut class_builder = 0; // TODO dummy argument; see callbacks
+    // Placeholder for the type-erased functions below. The class type is not known here, so each of them creates its own
+    // `ClassBuilder<T>` where needed.
+    let mut class_builder = 0;
 
     // Order of the following registrations is crucial:
     // 1. Methods and constants.
@@ -618,6 +632,43 @@ pub mod callbacks {
         T::__default_virtual_call(method_name.as_str())
     }
 
+    pub unsafe extern "C" fn rpc_get_virtual<T: cap::ImplementsGodotApi + UserClass>(
+        _class_user_data: *mut std::ffi::c_void,
+        name: sys::GDExtensionConstStringNamePtr,
+    ) -> sys::GDExtensionClassCallVirtual {
+        // This string is not ours, so we cannot call the destructor on it.
+        let borrowed_string = StringName::from_string_sys(sys::force_mut_ptr(name));
+        let method_name = borrowed_string.to_string();
+        std::mem::forget(borrowed_string);
+
+        if method_name != "_ready" {
+            return T::__next_virtual_call(method_name.as_str());
+        }
+
+        if crate::private::is_class_inactive(T::__config().is_tool) {
+            return None;
+        }
+
+        Some(rpc_ready::<T>)
+    }
+
+    /// Configures the RPCs of the instance, then continues with the regular `_ready` callback (OnReady fields, user `ready()`).
+    unsafe extern "C" fn rpc_ready<T: cap::ImplementsGodotApi>(
+        instance: sys::GDExtensionClassInstancePtr,
+        args: *const sys::GDExtensionConstTypePtr,
+        ret: sys::GDExtensionTypePtr,
+    ) {
+        // Does not bind the instance, so this works while the user object is borrowed (e.g. add_child() inside a method).
+        let _success = crate::private::handle_panic(
+            || format!("{}::_ready (RPC configuration)", T::class_name()),
+            || T::__register_rpcs(as_storage::<T>(instance).get_gd()),
+        );
+
+        if let Some(next_ready) = T::__next_virtual_call("_ready") {
+            next_ready(instance, args, ret);
+        }
+    }
+
     pub unsafe extern "C" fn to_string<T: cap::GodotToString>(
         instance: sys::GDExtensionClassInstancePtr,
         _is_valid: *mut sys::GDExtensionBool,
@@ -684,11 +735,7 @@ pub mod callbacks {
     }
 
     pub fn register_class_by_builder<T: cap::GodotRegisterClass>(_class_builder: &mut dyn Any) {
-        // TODO use actual argument, once class builder carries state
-        // let class_builder = class_builder
-        //     .downcast_mut::<ClassBuilder<T>>()
-        //     .expect("bad type erasure");
-
+        // ClassBuilder registers symbols directly with Godot and carries no state, so a fresh one can be used.
         let mut class_builder = ClassBuilder::new();
         T::__godot_register_class(&mut class_builder);
     }
@@ -722,6 +769,7 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
         register_methods_constants_fn: None,
         register_properties_fn: None,
         user_register_fn: None,
+        rpc_virtual_fn: None,
         default_virtual_fn: None,
         user_virtual_fn: None,
         godot_params: default_creation_info(),
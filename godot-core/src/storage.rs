@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::obj::{Base, Gd, GodotClass, Inherits};
+use crate::obj::{Base, BorrowError, Gd, GodotClass, Inherits};
 use crate::{godot_error, out};
 use godot_ffi as sys;
 
@@ -91,6 +91,16 @@ pub unsafe trait Storage {
     /// they are violated.
     fn get_mut(&self) -> godot_cell::MutGuard<'_, Self::Instance>;
 
+    /// Returns a shared reference to this storage's instance, or an error if it is currently bound mutably.
+    ///
+    /// Non-panicking variant of [`get()`](Storage::get()).
+    fn try_get(&self) -> Result<godot_cell::RefGuard<'_, Self::Instance>, BorrowError>;
+
+    /// Returns a mutable/exclusive reference to this storage's instance, or an error if it is currently bound.
+    ///
+    /// Non-panicking variant of [`get_mut()`](Storage::get_mut()).
+    fn try_get_mut(&self) -> Result<godot_cell::MutGuard<'_, Self::Instance>, BorrowError>;
+
     /// Returns a guard that allows calling methods on `Gd<Base>` that take `&mut self`.
     ///
     /// This can use the provided `instance` to provide extra safety guarantees such as allowing reentrant
@@ -165,6 +175,22 @@ pub(crate) trait StorageRefCounted: Storage {
     fn on_dec_ref(&self);
 }
 
+/// Maps an error from `GdCell::borrow()`/`borrow_mut()` to the public [`BorrowError`].
+///
+/// Only conflicts with existing borrows are recoverable. Other errors (e.g. poisoned state) indicate a bug and panic.
+fn map_borrow_error<T: GodotClass>(err: Box<dyn std::error::Error>) -> BorrowError {
+    match err.downcast_ref::<godot_cell::BorrowStateErr>() {
+        Some(godot_cell::BorrowStateErr::AccessibleMutBorrow) => BorrowError::MutablyBound,
+        Some(godot_cell::BorrowStateErr::SharedBorrow) => BorrowError::SharedBound,
+        _ => panic!(
+            "binding instance failed for type T = {}.\n  \
+            This is most likely a bug, please report it.\n  \
+            Details: {err}.",
+            std::any::type_name::<T>()
+        ),
+    }
+}
+
 #[cfg(not(feature = "experimental-threads"))]
 pub type InstanceStorage<T> = single_threaded::InstanceStorage<T>;
 
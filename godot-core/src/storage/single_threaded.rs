@@ -9,10 +9,10 @@ use std::any::type_name;
 use std::cell;
 use std::pin::Pin;
 
-use crate::obj::{Base, GodotClass};
+use crate::obj::{Base, BorrowError, GodotClass};
 use crate::out;
 
-use super::{Lifecycle, Storage, StorageRefCounted};
+use super::{map_borrow_error, Lifecycle, Storage, StorageRefCounted};
 
 pub struct InstanceStorage<T: GodotClass> {
     user_instance: Pin<Box<godot_cell::GdCell<T>>>,
@@ -56,7 +56,7 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
     }
 
     fn get(&self) -> godot_cell::RefGuard<'_, T> {
-        self.user_instance.as_ref().borrow().unwrap_or_else(|err| {
+        self.try_get().unwrap_or_else(|err| {
             panic!(
                 "\
                     Gd<T>::bind() failed, already bound; T = {}.\n  \
@@ -69,19 +69,30 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
     }
 
     fn get_mut(&self) -> godot_cell::MutGuard<'_, T> {
-        self.user_instance
-            .as_ref()
-            .borrow_mut()
-            .unwrap_or_else(|err| {
-                panic!(
-                    "\
+        self.try_get_mut().unwrap_or_else(|err| {
+            panic!(
+                "\
                     Gd<T>::bind_mut() failed, already bound; T = {}.\n  \
                     Make sure to use `self.base_mut()` instead of `self.to_gd()` when possible.\n  \
                     Details: {err}.\
                 ",
-                    type_name::<T>()
-                )
-            })
+                type_name::<T>()
+            )
+        })
+    }
+
+    fn try_get(&self) -> Result<godot_cell::RefGuard<'_, T>, BorrowError> {
+        self.user_instance
+            .as_ref()
+            .borrow()
+            .map_err(map_borrow_error::<T>)
+    }
+
+    fn try_get_mut(&self) -> Result<godot_cell::MutGuard<'_, T>, BorrowError> {
+        self.user_instance
+            .as_ref()
+            .borrow_mut()
+            .map_err(map_borrow_error::<T>)
     }
 
     fn get_inaccessible<'a: 'b, 'b>(
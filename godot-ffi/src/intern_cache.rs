@@ -0,0 +1,83 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use crate::Global;
+
+/// Thread-safe global cache that interns values by their string key.
+///
+/// Each distinct key is constructed only once, on first access; subsequent lookups return a clone of the cached value.
+/// This is useful for values whose construction crosses the FFI boundary and allocates, while cloning is cheap (e.g. ref-counted
+/// Godot strings).
+///
+/// The cache is opt-in: it only holds what is explicitly requested through [`get_or_insert_with()`](Self::get_or_insert_with).
+/// Entries are never removed, so it should be used for a bounded set of keys (identifiers, method or signal names), not arbitrary
+/// user input. Since it is typically stored in a `static`, the cached values are never dropped.
+///
+/// # Example
+/// ```
+/// use godot_ffi::InternCache;
+///
+/// static LENGTHS: InternCache<usize> = InternCache::new();
+///
+/// let len = LENGTHS.get_or_insert_with("hello", |key| key.len());
+/// assert_eq!(len, 5);
+/// assert_eq!(LENGTHS.len(), 1);
+/// ```
+pub struct InternCache<T> {
+    entries: Global<HashMap<Box<str>, T>>,
+}
+
+impl<T> InternCache<T> {
+    /// Creates an empty cache. Usable in `static` declarations.
+    pub const fn new() -> Self {
+        Self {
+            entries: Global::default(),
+        }
+    }
+
+    /// Returns the value cached for `key`, constructing it with `make_value` if it is not yet present.
+    ///
+    /// The cache is locked while `make_value` runs, which must thus not access the same cache.
+    pub fn get_or_insert_with(&self, key: &str, make_value: impl FnOnce(&str) -> T) -> T
+    where
+        T: Clone,
+    {
+        let mut entries = self.entries.lock();
+
+        // Look up by &str first, to avoid allocating a key for cache hits.
+        if let Some(value) = entries.get(key) {
+            return value.clone();
+        }
+
+        let value = make_value(key);
+        entries.insert(Box::from(key), value.clone());
+        value
+    }
+
+    /// Returns `true` if a value for `key` has been cached.
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.lock().contains_key(key)
+    }
+
+    /// Number of cached values.
+    pub fn len(&self) -> usize {
+        self.entries.lock().len()
+    }
+
+    /// Returns `true` if no values have been cached yet.
+    pub fn is_empty(&self) -> bool {
+        self.entries.lock().is_empty()
+    }
+}
+
+impl<T> Default for InternCache<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -35,6 +35,7 @@ mod compat;
 mod extras;
 mod global;
 mod godot_ffi;
+mod intern_cache;
 mod opaque;
 mod plugins;
 mod string_cache;
@@ -71,6 +72,7 @@ pub use gen::central::*;
 pub use gen::gdextension_interface::*;
 pub use gen::interface::*;
 pub use global::*;
+pub use intern_cache::InternCache;
 pub use string_cache::StringCache;
 pub use toolbox::*;
 
@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+
+use crate::class::func_ref_const_name;
+use crate::util::bail;
+use crate::ParseResult;
+
+/// Expands `callable!(Class::method)` to the method's `FuncRef`, and `callable!(object, Class::method)` to a `Callable`.
+pub fn callable(input: TokenStream) -> ParseResult<TokenStream> {
+    let mut args = split_top_level_commas(input);
+
+    // Allow trailing comma.
+    if args.last().is_some_and(|arg| arg.is_empty()) {
+        args.pop();
+    }
+
+    match args.len() {
+        1 => make_func_ref_path(args.pop().unwrap()),
+        2 => {
+            let func_ref = make_func_ref_path(args.pop().unwrap())?;
+            let object: TokenStream = args.pop().unwrap().into_iter().collect();
+            Ok(quote! {
+                #func_ref.callable(&(#object))
+            })
+        }
+        _ => bail!(
+            TokenStream::new(),
+            "callable! expects `Class::method` or `object, Class::method`"
+        ),
+    }
+}
+
+/// Maps the path `Class::method` to the associated constant generated by `#[godot_api]` for the method.
+fn make_func_ref_path(path: Vec<TokenTree>) -> ParseResult<TokenStream> {
+    let path_tokens: TokenStream = path.iter().cloned().collect();
+
+    let (method, class_path) = match path.split_last() {
+        Some((TokenTree::Ident(method), class_path)) => (method, class_path),
+        _ => {
+            return bail!(
+                path_tokens,
+                "callable! expects a path `Class::method` to a #[func]"
+            )
+        }
+    };
+
+    let class_path = match class_path {
+        [class_path @ .., TokenTree::Punct(first), TokenTree::Punct(second)]
+            if !class_path.is_empty() && first.as_char() == ':' && second.as_char() == ':' =>
+        {
+            class_path
+        }
+        _ => {
+            return bail!(
+                path_tokens,
+                "callable! expects a path `Class::method` to a #[func]"
+            )
+        }
+    };
+
+    let class_path: TokenStream = class_path.iter().cloned().collect();
+    let const_name = func_ref_const_name(method);
+
+    Ok(quote! {
+        <#class_path>::#const_name
+    })
+}
+
+fn split_top_level_commas(input: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut args = vec![vec![]];
+
+    for token in input {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => args.push(vec![]),
+            token => args.last_mut().unwrap().push(token),
+        }
+    }
+
+    args
+}
@@ -12,6 +12,8 @@ pub struct Field {
     pub name: Ident,
     pub ty: venial::TyExpr,
     pub default: Option<TokenStream>,
+    /// Path of the node assigned to an `OnReady<Gd<T>>` field, from `#[init(node = "path")]`.
+    pub node_path: Option<TokenStream>,
     pub var: Option<FieldVar>,
     pub export: Option<FieldExport>,
     pub is_onready: bool,
@@ -23,6 +25,7 @@ impl Field {
             name: field.name.clone(),
             ty: field.ty.clone(),
             default: None,
+            node_path: None,
             var: None,
             export: None,
             is_onready: false,
@@ -203,6 +203,10 @@ impl GetterSetterImpl {
                 external_attributes: Vec::new(),
                 rename: None,
                 has_gd_self: false,
+                default_params: Vec::new(),
+                is_vararg: false,
+                rpc_info: None,
+                error_fallback: None,
             },
         );
 
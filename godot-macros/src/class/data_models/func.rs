@@ -5,6 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::class::RpcInfo;
 use crate::util;
 use crate::util::ident;
 use proc_macro2::{Ident, TokenStream};
@@ -19,6 +20,24 @@ pub struct FuncDefinition {
     /// The name the function will be exposed as in Godot. If `None`, the Rust function name is used.
     pub rename: Option<String>,
     pub has_gd_self: bool,
+    /// Default value expressions of the trailing parameters, declared with `#[opt(default = ...)]`.
+    pub default_params: Vec<TokenStream>,
+    /// Whether the function receives all arguments as `&[Variant]`, declared with `#[func(vararg)]`.
+    pub is_vararg: bool,
+    /// Remote procedure call configuration, declared with `#[rpc]`.
+    pub rpc_info: Option<RpcInfo>,
+    /// Value passed to Godot if the function returns `Err`, declared with `#[func(error_fallback = ...)]`.
+    pub error_fallback: Option<TokenStream>,
+}
+
+impl FuncDefinition {
+    /// The name under which the function is registered in Godot.
+    pub fn godot_name(&self) -> String {
+        match &self.rename {
+            Some(rename) => rename.clone(),
+            None => self.func.name.to_string(),
+        }
+    }
 }
 
 /// Returns a C function which acts as the callback when a virtual method of this instance is invoked.
@@ -32,11 +51,17 @@ pub fn make_virtual_callback(
 ) -> TokenStream {
     let method_name = &signature_info.method_name;
 
-    let wrapped_method = make_forwarding_closure(class_name, &signature_info, before_kind);
+    let wrapped_method = make_forwarding_closure(
+        class_name,
+        &signature_info,
+        before_kind,
+        ReturnConversion::None,
+    );
     let sig_tuple =
         util::make_signature_tuple_type(&signature_info.ret_type, &signature_info.param_types);
 
     let invocation = make_ptrcall_invocation(method_name, &sig_tuple, &wrapped_method, true);
+    let method_name_str = method_name.to_string();
 
     quote! {
         {
@@ -47,7 +72,11 @@ pub fn make_virtual_callback(
                 args_ptr: *const sys::GDExtensionConstTypePtr,
                 ret: sys::GDExtensionTypePtr,
             ) {
-                #invocation;
+                // Panics must not unwind into Godot; they are handled according to the library's panic policy.
+                let _success = ::godot::private::handle_panic(
+                    || #method_name_str,
+                    || #invocation
+                );
             }
             Some(function)
         }
@@ -59,20 +88,49 @@ pub fn make_method_registration(
     class_name: &Ident,
     func_definition: FuncDefinition,
 ) -> TokenStream {
-    let signature_info = get_signature_info(&func_definition.func, func_definition.has_gd_self);
-    let sig_tuple =
-        util::make_signature_tuple_type(&signature_info.ret_type, &signature_info.param_types);
-
+    let mut signature_info = get_signature_info(&func_definition.func, func_definition.has_gd_self);
     let method_name = &signature_info.method_name;
-    let param_idents = &signature_info.param_idents;
 
-    let method_flags = make_method_flags(signature_info.receiver_type);
+    // #[func] may return types which are not directly passed to Godot, such as Result<T, E>. The forwarding closure converts them.
+    let declared_ret_type = &signature_info.ret_type;
+    signature_info.ret_type =
+        quote! { <#declared_ret_type as ::godot::builtin::meta::FuncReturn>::Via };
 
-    let forwarding_closure =
-        make_forwarding_closure(class_name, &signature_info, BeforeKind::Without);
+    let method_flags = make_method_flags(signature_info.receiver_type, func_definition.is_vararg);
 
-    let varcall_func = make_varcall_func(method_name, &sig_tuple, &forwarding_closure);
-    let ptrcall_func = make_ptrcall_func(method_name, &sig_tuple, &forwarding_closure);
+    let return_conversion = ReturnConversion::FuncReturn {
+        error_fallback: func_definition.error_fallback.as_ref(),
+    };
+    let forwarding_closure = make_forwarding_closure(
+        class_name,
+        &signature_info,
+        BeforeKind::Without,
+        return_conversion,
+    );
+    let default_values =
+        make_default_values_closure(&signature_info, &func_definition.default_params);
+
+    // Vararg methods don't declare any parameters towards Godot, and are never ptrcalled.
+    let (sig_tuple, param_idents, varcall_func, ptrcall_func);
+    if func_definition.is_vararg {
+        sig_tuple = util::make_signature_tuple_type(&signature_info.ret_type, &[]);
+        param_idents = &[][..];
+        varcall_func = make_vararg_varcall_func(method_name, &forwarding_closure);
+        ptrcall_func = quote! { None };
+    } else {
+        sig_tuple =
+            util::make_signature_tuple_type(&signature_info.ret_type, &signature_info.param_types);
+        param_idents = &signature_info.param_idents[..];
+        varcall_func = make_varcall_func(
+            method_name,
+            &sig_tuple,
+            &forwarding_closure,
+            &default_values,
+        );
+
+        let ptrcall = make_ptrcall_func(method_name, &sig_tuple, &forwarding_closure);
+        ptrcall_func = quote! { Some(#ptrcall) };
+    }
 
     // String literals
     let class_name_str = class_name.to_string();
@@ -103,6 +161,7 @@ pub fn make_method_registration(
 
             let varcall_func = #varcall_func;
             let ptrcall_func = #ptrcall_func;
+            let default_values: fn() -> Vec<Variant> = #default_values;
 
             // SAFETY:
             // `get_varcall_func` upholds all the requirements for `call_func`.
@@ -112,12 +171,12 @@ pub fn make_method_registration(
                 #class_name::class_name(),
                 method_name,
                 Some(varcall_func),
-                Some(ptrcall_func),
+                ptrcall_func,
                 #method_flags,
                 &[
                     #( #param_ident_strs ),*
                 ],
-                Vec::new()
+                default_values()
                 )
             };
 
@@ -133,6 +192,35 @@ pub fn make_method_registration(
     }
 }
 
+/// Generates a hidden associated constant referring to the function, as used by the `callable!` macro.
+///
+/// Must be placed inside an `impl` block of the class.
+pub fn make_func_ref(func_definition: &FuncDefinition) -> TokenStream {
+    let signature_info = get_signature_info(&func_definition.func, func_definition.has_gd_self);
+    let param_types = &signature_info.param_types;
+
+    let method_name = &signature_info.method_name;
+    let const_name = func_ref_const_name(method_name);
+    let method_name_str = func_definition.godot_name();
+
+    let cfg_attrs = util::extract_cfg_attrs(&func_definition.external_attributes)
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    quote! {
+        #(#cfg_attrs)*
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals)]
+        pub const #const_name: ::godot::obj::FuncRef<Self, (#(#param_types,)*)> =
+            ::godot::obj::FuncRef::__new(#method_name_str);
+    }
+}
+
+/// Name of the associated constant generated by [`make_func_ref`] for the Rust method `method_name`.
+pub fn func_ref_const_name(method_name: &Ident) -> Ident {
+    format_ident!("__godot_func_{}", method_name, span = method_name.span())
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Implementation
 
@@ -175,11 +263,23 @@ pub enum BeforeKind {
     OnlyBefore,
 }
 
+/// How the forwarding closure converts the method's return value.
+enum ReturnConversion<'a> {
+    /// Return the value unchanged, as for virtual methods.
+    None,
+
+    /// Convert through `FuncReturn`, as required for `#[func]` methods. With an error fallback, the method must return a `Result`.
+    FuncReturn {
+        error_fallback: Option<&'a TokenStream>,
+    },
+}
+
 /// Returns a closure expression that forwards the parameters to the Rust instance.
 fn make_forwarding_closure(
     class_name: &Ident,
     signature_info: &SignatureInfo,
     before_kind: BeforeKind,
+    return_conversion: ReturnConversion,
 ) -> TokenStream {
     let method_name = &signature_info.method_name;
     let params = &signature_info.param_idents;
@@ -194,6 +294,25 @@ fn make_forwarding_closure(
         _ => quote! {},
     };
 
+    let method_path_str = format!("{class_name}::{method_name}");
+    let wrap_return = |method_call: TokenStream| match &return_conversion {
+        ReturnConversion::None => method_call,
+        ReturnConversion::FuncReturn {
+            error_fallback: None,
+        } => quote! {
+            ::godot::builtin::meta::FuncReturn::into_func_return(#method_call, #method_path_str)
+        },
+        ReturnConversion::FuncReturn {
+            error_fallback: Some(fallback),
+        } => quote! {
+            ::godot::builtin::meta::FuncResult::into_func_return_or(
+                #method_call,
+                #method_path_str,
+                || #fallback,
+            )
+        },
+    };
+
     let before_method_call = match before_kind {
         BeforeKind::WithBefore | BeforeKind::OnlyBefore => {
             let before_method = format_ident!("__before_{}", method_name);
@@ -209,7 +328,7 @@ fn make_forwarding_closure(
             let method_call = if matches!(before_kind, BeforeKind::OnlyBefore) {
                 TokenStream::new()
             } else {
-                quote! { instance.#method_name(#(#params),*) }
+                wrap_return(quote! { instance.#method_name(#(#params),*) })
             };
 
             quote! {
@@ -228,6 +347,10 @@ fn make_forwarding_closure(
         ReceiverType::GdSelf => {
             // Method call is always present, since GdSelf implies that the user declares the method.
             // (Absent method is only used in the case of a generated default virtual method, e.g. for ready()).
+            let method_call = wrap_return(quote! {
+                <#class_name>::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*)
+            });
+
             quote! {
                 |instance_ptr, params| {
                     let ( #(#params,)* ) = params;
@@ -236,16 +359,18 @@ fn make_forwarding_closure(
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
 
                     #before_method_call
-                    <#class_name>::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*)
+                    #method_call
                 }
             }
         }
         ReceiverType::Static => {
             // No before-call needed, since static methods are not virtual.
+            let method_call = wrap_return(quote! { <#class_name>::#method_name(#(#params),*) });
+
             quote! {
                 |_, params| {
                     let ( #(#params,)* ) = params;
-                    <#class_name>::#method_name(#(#params),*)
+                    #method_call
                 }
             }
         }
@@ -309,14 +434,42 @@ pub(crate) fn get_signature_info(signature: &venial::Function, has_gd_self: bool
     }
 }
 
-fn make_method_flags(method_type: ReceiverType) -> TokenStream {
-    match method_type {
+fn make_method_flags(method_type: ReceiverType, is_vararg: bool) -> TokenStream {
+    let flags = match method_type {
         ReceiverType::Ref | ReceiverType::Mut | ReceiverType::GdSelf => {
             quote! { ::godot::engine::global::MethodFlags::METHOD_FLAGS_DEFAULT }
         }
         ReceiverType::Static => {
             quote! { ::godot::engine::global::MethodFlags::METHOD_FLAG_STATIC }
         }
+    };
+
+    if is_vararg {
+        quote! { #flags | ::godot::engine::global::MethodFlags::METHOD_FLAG_VARARG }
+    } else {
+        flags
+    }
+}
+
+/// Returns a closure expression evaluating to the default values of the trailing parameters, as `Vec<Variant>`.
+fn make_default_values_closure(
+    signature_info: &SignatureInfo,
+    default_params: &[TokenStream],
+) -> TokenStream {
+    let first_default = signature_info.param_types.len() - default_params.len();
+    let default_types = &signature_info.param_types[first_default..];
+
+    quote! {
+        || -> Vec<::godot::builtin::Variant> {
+            vec![
+                #(
+                    {
+                        let default_value: #default_types = #default_params;
+                        ::godot::builtin::meta::ToGodot::to_variant(&default_value)
+                    }
+                ),*
+            ]
+        }
     }
 }
 
@@ -325,8 +478,10 @@ fn make_varcall_func(
     method_name: &Ident,
     sig_tuple: &TokenStream,
     wrapped_method: &TokenStream,
+    default_values: &TokenStream,
 ) -> TokenStream {
-    let invocation = make_varcall_invocation(method_name, sig_tuple, wrapped_method);
+    let invocation =
+        make_varcall_invocation(method_name, sig_tuple, wrapped_method, default_values);
     let method_name_str = method_name.to_string();
 
     quote! {
@@ -335,7 +490,7 @@ fn make_varcall_func(
                 _method_data: *mut std::ffi::c_void,
                 instance_ptr: sys::GDExtensionClassInstancePtr,
                 args_ptr: *const sys::GDExtensionConstVariantPtr,
-                _arg_count: sys::GDExtensionInt,
+                arg_count: sys::GDExtensionInt,
                 ret: sys::GDExtensionVariantPtr,
                 err: *mut sys::GDExtensionCallError,
             ) {
@@ -358,6 +513,46 @@ fn make_varcall_func(
     }
 }
 
+/// Generate code for a C FFI function that performs a varcall into a `#[func(vararg)]` method.
+fn make_vararg_varcall_func(method_name: &Ident, wrapped_method: &TokenStream) -> TokenStream {
+    let method_name_str = method_name.to_string();
+
+    quote! {
+        {
+            unsafe extern "C" fn function(
+                _method_data: *mut std::ffi::c_void,
+                instance_ptr: sys::GDExtensionClassInstancePtr,
+                args_ptr: *const sys::GDExtensionConstVariantPtr,
+                arg_count: sys::GDExtensionInt,
+                ret: sys::GDExtensionVariantPtr,
+                err: *mut sys::GDExtensionCallError,
+            ) {
+                let success = ::godot::private::handle_panic(
+                    || #method_name_str,
+                    || ::godot::builtin::meta::in_varcall_varargs(
+                        instance_ptr,
+                        args_ptr,
+                        arg_count,
+                        ret,
+                        err,
+                        #wrapped_method,
+                    )
+                );
+
+                if success.is_none() {
+                    // Signal error and set return type to Nil
+                    (*err).error = sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD; // no better fitting enum?
+
+                    // TODO(uninit)
+                    sys::interface_fn!(variant_new_nil)(sys::AsUninit::as_uninit(ret));
+                }
+            }
+
+            function
+        }
+    }
+}
+
 /// Generate code for a C FFI function that performs a ptrcall.
 fn make_ptrcall_func(
     method_name: &Ident,
@@ -422,6 +617,7 @@ fn make_varcall_invocation(
     method_name: &Ident,
     sig_tuple: &TokenStream,
     wrapped_method: &TokenStream,
+    default_values: &TokenStream,
 ) -> TokenStream {
     let method_name_str = method_name.to_string();
 
@@ -430,9 +626,11 @@ fn make_varcall_invocation(
             instance_ptr,
             #method_name_str,
             args_ptr,
+            arg_count,
             ret,
             err,
             #wrapped_method,
+            #default_values,
         )
     }
 }
@@ -0,0 +1,201 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::class::{get_signature_info, FuncDefinition};
+use crate::util::{bail, KvParser};
+use crate::{util, ParseResult};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+/// Configuration of a `#[func]` as remote procedure call, declared with `#[rpc]`.
+pub struct RpcInfo {
+    /// Variant of `RPCMode`; `None` for Godot's default.
+    pub rpc_mode: Option<TokenStream>,
+    /// Variant of `TransferMode`; `None` for Godot's default.
+    pub transfer_mode: Option<TokenStream>,
+    pub call_local: bool,
+    /// Channel expression; `None` for Godot's default.
+    pub channel: Option<TokenStream>,
+    /// Whether typed `rpc_*` and `rpc_id_*` methods are generated, declared with `#[rpc(helpers)]`.
+    pub has_helpers: bool,
+    /// Visibility of the method, applied to the helpers.
+    pub vis_marker: Option<venial::VisMarker>,
+}
+
+impl RpcInfo {
+    /// Parses the keys of a `#[rpc(...)]` attribute.
+    pub fn parse(mut parser: KvParser, vis_marker: Option<venial::VisMarker>) -> ParseResult<Self> {
+        let rpc_mode = handle_one_of(&mut parser, &["any_peer", "authority"])?.map(|key| {
+            let variant = format_ident!("RPC_MODE_{}", key.to_uppercase());
+            quote! { ::godot::engine::multiplayer_api::RPCMode::#variant }
+        });
+
+        let transfer_mode = handle_one_of(
+            &mut parser,
+            &["reliable", "unreliable", "unreliable_ordered"],
+        )?
+        .map(|key| {
+            let variant = format_ident!("TRANSFER_MODE_{}", key.to_uppercase());
+            quote! { ::godot::engine::multiplayer_peer::TransferMode::#variant }
+        });
+
+        let call_local = handle_one_of(&mut parser, &["call_local", "call_remote"])?
+            .is_some_and(|key| key == "call_local");
+
+        let channel = parser.handle_expr("channel")?;
+        let has_helpers = parser.handle_alone("helpers")?;
+        parser.finish()?;
+
+        Ok(Self {
+            rpc_mode,
+            transfer_mode,
+            call_local,
+            channel,
+            has_helpers,
+            vis_marker,
+        })
+    }
+}
+
+/// Handles mutually exclusive keys without value, returning the one that is present (if any).
+fn handle_one_of<'k>(parser: &mut KvParser, keys: &[&'k str]) -> ParseResult<Option<&'k str>> {
+    let mut found: Option<&str> = None;
+
+    for &key in keys {
+        if let Some(ident) = parser.handle_alone_ident(key)? {
+            if let Some(previous) = found {
+                return bail!(
+                    ident,
+                    "#[rpc]: keys `{previous}` and `{key}` are mutually exclusive"
+                );
+            }
+            found = Some(key);
+        }
+    }
+
+    Ok(found)
+}
+
+/// Generates `ImplementsGodotApi::__register_rpcs()`, which applies the RPC configurations to a node instance, as well as
+/// `__next_virtual_call()`, which the `_ready` hook for RPCs delegates to.
+///
+/// Returns `None` if no function is declared as RPC.
+pub fn make_rpc_registrations(class_name: &Ident, funcs: &[FuncDefinition]) -> Option<TokenStream> {
+    let registrations: Vec<TokenStream> = funcs
+        .iter()
+        .filter_map(|func_def| {
+            let rpc_info = func_def.rpc_info.as_ref()?;
+            let method_name_str = func_def.godot_name();
+            let cfg_attrs = util::extract_cfg_attrs(&func_def.external_attributes)
+                .into_iter()
+                .collect::<Vec<_>>();
+
+            let rpc_mode = rpc_info
+                .rpc_mode
+                .as_ref()
+                .map(|rpc_mode| quote! { rpc_mode: #rpc_mode, });
+            let transfer_mode = rpc_info
+                .transfer_mode
+                .as_ref()
+                .map(|transfer_mode| quote! { transfer_mode: #transfer_mode, });
+            let call_local = rpc_info.call_local;
+            let channel = rpc_info
+                .channel
+                .as_ref()
+                .map(|channel| quote! { channel: #channel, });
+
+            Some(quote! {
+                #(#cfg_attrs)*
+                RpcConfig {
+                    #rpc_mode
+                    #transfer_mode
+                    call_local: #call_local,
+                    #channel
+                    ..RpcConfig::default()
+                }
+                .configure_node(&mut node, ::godot::builtin::StringName::from(#method_name_str));
+            })
+        })
+        .collect();
+
+    if registrations.is_empty() {
+        return None;
+    }
+
+    Some(quote! {
+        fn __register_rpcs(object: ::godot::obj::Gd<Self>) {
+            use ::godot::register::builder::RpcConfig;
+
+            // RPCs can only be declared on classes inheriting Node.
+            let mut node = object.upcast::<::godot::engine::Node>();
+
+            #( #registrations )*
+        }
+
+        fn __next_virtual_call(name: &str) -> ::godot::sys::GDExtensionClassCallVirtual {
+            use ::godot::private::{NextVirtualFromDerive as _, NextVirtualFromImpl as _};
+
+            (&&::godot::private::NextVirtual::<#class_name>(::std::marker::PhantomData))
+                .next_virtual_call(name)
+        }
+    })
+}
+
+/// Generates the typed `rpc_*` and `rpc_id_*` methods for functions declared with `#[rpc(helpers)]`.
+///
+/// Must be placed inside an `impl` block of the class.
+pub fn make_rpc_helpers(funcs: &[FuncDefinition]) -> Vec<TokenStream> {
+    funcs
+        .iter()
+        .filter_map(|func_def| {
+            let rpc_info = func_def.rpc_info.as_ref()?;
+            if !rpc_info.has_helpers {
+                return None;
+            }
+
+            let signature_info = get_signature_info(&func_def.func, func_def.has_gd_self);
+            let method_name = &signature_info.method_name;
+            let param_idents = &signature_info.param_idents;
+            let param_types = &signature_info.param_types;
+
+            let vis_marker = &rpc_info.vis_marker;
+            let method_name_str = func_def.godot_name();
+            let rpc_name = format_ident!("rpc_{}", method_name);
+            let rpc_id_name = format_ident!("rpc_id_{}", method_name);
+            let rpc_doc = format!("Calls `{method_name_str}()` remotely on all peers, see `Node::rpc()`.");
+            let rpc_id_doc = format!("Calls `{method_name_str}()` remotely on the peer `peer_id`, see `Node::rpc_id()`.");
+
+            let cfg_attrs = util::extract_cfg_attrs(&func_def.external_attributes)
+                .into_iter()
+                .collect::<Vec<_>>();
+
+            Some(quote! {
+                #(#cfg_attrs)*
+                #[doc = #rpc_doc]
+                #vis_marker fn #rpc_name(&mut self, #( #param_idents: #param_types ),*) -> ::godot::engine::global::Error {
+                    let args: &[::godot::builtin::Variant] = &[
+                        #( ::godot::builtin::meta::ToGodot::to_variant(&#param_idents) ),*
+                    ];
+
+                    ::godot::obj::WithBaseField::base_mut(self)
+                        .rpc(::godot::builtin::StringName::from(#method_name_str), args)
+                }
+
+                #(#cfg_attrs)*
+                #[doc = #rpc_id_doc]
+                #vis_marker fn #rpc_id_name(&mut self, peer_id: i64, #( #param_idents: #param_types ),*) -> ::godot::engine::global::Error {
+                    let args: &[::godot::builtin::Variant] = &[
+                        #( ::godot::builtin::meta::ToGodot::to_variant(&#param_idents) ),*
+                    ];
+
+                    ::godot::obj::WithBaseField::base_mut(self)
+                        .rpc_id(peer_id, ::godot::builtin::StringName::from(#method_name_str), args)
+                }
+            })
+        })
+        .collect()
+}
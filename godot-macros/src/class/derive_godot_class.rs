@@ -82,8 +82,27 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
         quote! {}
     };
 
-    let (user_class_impl, has_default_virtual) =
-        make_user_class_impl(class_name, struct_cfg.is_tool, &fields.all_fields);
+    if let Some(node_field) = fields.all_fields.iter().find(|f| f.node_path.is_some()) {
+        if fields.base_field.is_none() {
+            return bail!(
+                &node_field.name,
+                "#[init(node = ...)] requires a #[base] field to look up the node"
+            );
+        }
+        if !struct_cfg.has_generated_init {
+            return bail!(
+                &node_field.name,
+                "#[init(node = ...)] requires a generated constructor, i.e. #[class(init)]"
+            );
+        }
+    }
+
+    let (user_class_impl, has_default_virtual) = make_user_class_impl(
+        class_name,
+        struct_cfg.is_tool,
+        &fields.all_fields,
+        fields.base_field.as_ref(),
+    );
 
     let (godot_init_impl, create_fn, recreate_fn);
     if struct_cfg.has_generated_init {
@@ -166,14 +185,33 @@ fn make_user_class_impl(
     class_name: &Ident,
     is_tool: bool,
     all_fields: &[Field],
+    base_field: Option<&Field>,
 ) -> (TokenStream, bool) {
     let onready_field_inits = all_fields
         .iter()
         .filter(|&field| field.is_onready)
         .map(|field| {
-            let field = &field.name;
-            quote! {
-                ::godot::private::auto_init(&mut self.#field);
+            let field_name = &field.name;
+
+            match (&field.node_path, base_field) {
+                (Some(path), Some(base_field)) => {
+                    let base_name = &base_field.name;
+                    let class_name_str = class_name.to_string();
+                    let field_name_str = field_name.to_string();
+
+                    quote! {
+                        ::godot::private::auto_init_node(
+                            &mut self.#field_name,
+                            &self.#base_name,
+                            #path,
+                            #class_name_str,
+                            #field_name_str,
+                        );
+                    }
+                }
+                _ => quote! {
+                    ::godot::private::auto_init(&mut self.#field_name);
+                },
             }
         });
 
@@ -319,7 +357,25 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
         // #[init]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "init")? {
             let default = parser.handle_expr("default")?;
+            let node_path = parser.handle_expr("node")?;
+
+            if node_path.is_some() {
+                if default.is_some() {
+                    return bail!(
+                        parser.span(),
+                        "#[init] can have either `default` or `node` key, not both"
+                    );
+                }
+                if !field.is_onready {
+                    return bail!(
+                        parser.span(),
+                        "#[init(node = ...)] requires a field of type `OnReady<Gd<T>>`"
+                    );
+                }
+            }
+
             field.default = default;
+            field.node_path = node_path;
             parser.finish()?;
         }
 
@@ -375,9 +431,11 @@ fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
 
     let rest_init = fields.all_fields.into_iter().map(|field| {
         let field_name = field.name;
-        let value_expr = match field.default {
-            None => quote! { ::std::default::Default::default() },
-            Some(default) => default,
+        let value_expr = match (field.default, field.node_path) {
+            (Some(default), _) => default,
+            // Assigned in __before_ready(), once the node is in the scene tree.
+            (None, Some(_)) => quote! { ::godot::obj::OnReady::manual() },
+            (None, None) => quote! { ::std::default::Default::default() },
         };
         quote! { #field_name: #value_expr, }
     });
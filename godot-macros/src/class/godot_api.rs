@@ -5,17 +5,18 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use proc_macro2::{Delimiter, Ident, TokenStream, TokenTree};
 use quote::spanned::Spanned;
+use quote::{format_ident, quote, quote_spanned};
 use venial::{
     Attribute, AttributeValue, Constant, Declaration, Error, FnParam, Function, Impl, ImplMember,
     TyExpr,
 };
 
 use crate::class::{
-    get_signature_info, make_method_registration, make_virtual_callback, BeforeKind,
-    FuncDefinition, SignatureInfo,
+    get_signature_info, make_func_ref, make_method_registration, make_rpc_helpers,
+    make_rpc_registrations, make_virtual_callback, BeforeKind, FuncDefinition, RpcInfo,
+    SignatureInfo,
 };
 use crate::util;
 use crate::util::{bail, KvParser};
@@ -54,9 +55,13 @@ enum BoundAttrType {
     Func {
         rename: Option<String>,
         has_gd_self: bool,
+        is_vararg: bool,
+        error_fallback: Option<TokenStream>,
     },
     Signal(AttributeValue),
-    Const(AttributeValue),
+    Const {
+        has_getter: bool,
+    },
 }
 
 struct BoundAttr {
@@ -78,23 +83,53 @@ struct SignalDefinition {
 
     /// The signal's non-gdext attributes (all except #[signal]).
     external_attributes: Vec<Attribute>,
+
+    /// Visibility of the signal declaration, applied to its type-safe accessor.
+    vis_marker: Option<venial::VisMarker>,
+}
+
+/// Holds information known from a constant's definition
+struct ConstDefinition {
+    /// The constant's declaration, including non-gdext attributes (all except #[constant]).
+    constant: Constant,
+
+    /// Whether the constant is exposed as static getter method instead of an integer constant, declared with `#[constant(getter)]`.
+    has_getter: bool,
 }
 
 /// Codegen for `#[godot_api] impl MyType`
 fn transform_inherent_impl(mut original_impl: Impl) -> Result<TokenStream, Error> {
     let class_name = util::validate_impl(&original_impl, None, "godot_api")?;
     let class_name_obj = util::class_name_obj(&class_name);
-    let (funcs, signals) = process_godot_fns(&mut original_impl)?;
+    let (mut funcs, signals) = process_godot_fns(&mut original_impl)?;
+    let prv = quote! { ::godot::private };
+
+    // Vararg functions have no fixed parameter list, so they cannot be referenced in a type-safe way.
+    let func_refs: Vec<TokenStream> = funcs
+        .iter()
+        .filter(|func_def| !func_def.is_vararg)
+        .map(make_func_ref)
+        .collect();
+
+    let rpc_helpers = make_rpc_helpers(&funcs);
+    let rpc_registrations = make_rpc_registrations(&class_name, &funcs);
+    let rpc_virtual_fn = if rpc_registrations.is_some() {
+        quote! { Some(#prv::callbacks::rpc_get_virtual::<#class_name>) }
+    } else {
+        quote! { None }
+    };
 
     let mut signal_cfg_attrs: Vec<Vec<&Attribute>> = Vec::new();
     let mut signal_name_strs: Vec<String> = Vec::new();
     let mut signal_parameters_count: Vec<usize> = Vec::new();
     let mut signal_parameters: Vec<TokenStream> = Vec::new();
+    let mut signal_accessors: Vec<TokenStream> = Vec::new();
 
     for signal in signals.iter() {
         let SignalDefinition {
             signature,
             external_attributes,
+            vis_marker,
         } = signal;
         let mut param_types: Vec<TyExpr> = Vec::new();
         let mut param_names: Vec<String> = Vec::new();
@@ -128,23 +163,36 @@ fn transform_inherent_impl(mut original_impl: Impl) -> Result<TokenStream, Error
                 .into_iter()
                 .collect(),
         );
-        signal_name_strs.push(signature.name.to_string());
+        let cfg_attrs: Vec<&Attribute> = util::extract_cfg_attrs(external_attributes)
+            .into_iter()
+            .collect();
+        let signal_name = &signature.name;
+        let signal_name_str = signal_name.to_string();
+        signal_accessors.push(quote! {
+            #(#cfg_attrs)*
+            #vis_marker fn #signal_name(self) -> ::godot::obj::TypedSignal<'c, #class_name, (#(#param_types,)*)> {
+                ::godot::obj::TypedSignal::__new(self.__object, #signal_name_str, self.__base_guard)
+            }
+        });
+
+        signal_name_strs.push(signal_name_str);
         signal_parameters_count.push(param_names.len());
         signal_parameters.push(param_array_decl);
     }
 
-    let prv = quote! { ::godot::private };
-
-    let methods_registration = funcs
-        .into_iter()
-        .map(|func_def| make_method_registration(&class_name, func_def));
+    let signal_collection = make_signal_collection(&class_name, signal_accessors);
 
     let consts = process_godot_constants(&mut original_impl)?;
     let mut integer_constant_cfg_attrs = Vec::new();
     let mut integer_constant_names = Vec::new();
     let mut integer_constant_values = Vec::new();
+    let mut constant_getters = Vec::new();
 
-    for constant in consts.iter() {
+    for ConstDefinition {
+        constant,
+        has_getter,
+    } in consts.iter()
+    {
         if constant.initializer.is_none() {
             return bail!(constant, "exported const should have initializer");
         };
@@ -157,13 +205,34 @@ fn transform_inherent_impl(mut original_impl: Impl) -> Result<TokenStream, Error
             .into_iter()
             .collect::<Vec<_>>();
 
+        if *has_getter {
+            // Godot only supports integer constants; other values are exposed as static getters with the constant's name.
+            let (getter, func_def) = make_constant_getter(&class_name, constant, &cfg_attrs);
+            constant_getters.push(getter);
+            funcs.push(func_def);
+            continue;
+        }
+
         // Transport #[cfg] attrs to the FFI glue to ensure constants which were conditionally
         // removed from compilation don't cause errors.
         integer_constant_cfg_attrs.push(cfg_attrs);
         integer_constant_names.push(constant.name.to_string());
-        integer_constant_values.push(quote! { #class_name::#name });
+        let ty = &constant.ty;
+        let type_check = quote_spanned! { ty.__span() =>
+            ::godot::private::constant_must_be_integer_or_use_getter::<#ty>();
+        };
+        integer_constant_values.push(quote! {
+            {
+                #type_check
+                #class_name::#name
+            }
+        });
     }
 
+    let methods_registration = funcs
+        .into_iter()
+        .map(|func_def| make_method_registration(&class_name, func_def));
+
     let register_constants = if !integer_constant_names.is_empty() {
         quote! {
             use ::godot::builtin::meta::registration::constant::*;
@@ -189,6 +258,13 @@ fn transform_inherent_impl(mut original_impl: Impl) -> Result<TokenStream, Error
 
     let result = quote! {
         #original_impl
+        #signal_collection
+
+        impl #class_name {
+            #( #func_refs )*
+            #( #rpc_helpers )*
+            #( #constant_getters )*
+        }
 
         impl ::godot::obj::cap::ImplementsGodotApi for #class_name {
             fn __register_methods() {
@@ -224,6 +300,8 @@ fn transform_inherent_impl(mut original_impl: Impl) -> Result<TokenStream, Error
             fn __register_constants() {
                 #register_constants
             }
+
+            #rpc_registrations
         }
 
         ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
@@ -232,6 +310,7 @@ fn transform_inherent_impl(mut original_impl: Impl) -> Result<TokenStream, Error
                 register_methods_constants_fn: #prv::ErasedRegisterFn {
                     raw: #prv::callbacks::register_user_methods_constants::<#class_name>,
                 },
+                rpc_virtual_fn: #rpc_virtual_fn,
             },
             init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
         });
@@ -240,6 +319,80 @@ fn transform_inherent_impl(mut original_impl: Impl) -> Result<TokenStream, Error
     Ok(result)
 }
 
+/// For a `#[constant(getter)]`, generates a hidden static getter and its registration as a Godot static method.
+fn make_constant_getter(
+    class_name: &Ident,
+    constant: &Constant,
+    cfg_attrs: &[&Attribute],
+) -> (TokenStream, FuncDefinition) {
+    let name = &constant.name;
+    let ty = &constant.ty;
+    let getter_name = format_ident!("__godot_constant_{}", name);
+
+    let type_check = quote_spanned! { ty.__span() =>
+        ::godot::private::constant_getter_must_implement_to_godot::<#ty>();
+    };
+
+    let getter = quote! {
+        #(#cfg_attrs)*
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        fn #getter_name() -> #ty {
+            #type_check
+            #class_name::#name
+        }
+    };
+
+    let func_def = FuncDefinition {
+        func: util::parse_signature(quote! { fn #getter_name() -> #ty }),
+        external_attributes: cfg_attrs.iter().map(|&attr| attr.clone()).collect(),
+        rename: Some(name.to_string()),
+        has_gd_self: false,
+        default_params: Vec::new(),
+        is_vararg: false,
+        rpc_info: None,
+        error_fallback: None,
+    };
+
+    (getter, func_def)
+}
+
+/// Generates a struct with one type-safe accessor per `#[signal]`, returned by `signals()`.
+fn make_signal_collection(class_name: &Ident, signal_accessors: Vec<TokenStream>) -> TokenStream {
+    if signal_accessors.is_empty() {
+        return TokenStream::new();
+    }
+
+    let collection_name = format_ident!("__godot_Signals_{}", class_name);
+
+    quote! {
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        pub struct #collection_name<'c> {
+            __object: ::godot::obj::Gd<::godot::engine::Object>,
+            __base_guard: ::std::option::Option<::godot::obj::BaseMut<'c, #class_name>>,
+        }
+
+        impl<'c> #collection_name<'c> {
+            #(#signal_accessors)*
+        }
+
+        impl ::godot::obj::WithSignals for #class_name {
+            type SignalCollection<'c> = #collection_name<'c>;
+
+            fn __signals_new(
+                object: ::godot::obj::Gd<::godot::engine::Object>,
+                base_guard: ::std::option::Option<::godot::obj::BaseMut<'_, Self>>,
+            ) -> Self::SignalCollection<'_> {
+                #collection_name {
+                    __object: object,
+                    __base_guard: base_guard,
+                }
+            }
+        }
+    }
+}
+
 fn process_godot_fns(
     decl: &mut Impl,
 ) -> Result<(Vec<FuncDefinition>, Vec<SignalDefinition>), Error> {
@@ -254,9 +407,31 @@ fn process_godot_fns(
             continue;
         };
 
-        if let Some(attr) = extract_attributes(&method, &method.attributes)? {
-            // Remaining code no longer has attribute -- rest stays
-            method.attributes.remove(attr.index);
+        let rpc_attr = extract_rpc_attribute(method)?;
+        let attr = match extract_attributes(&method, &method.attributes)? {
+            Some(attr) => Some(attr),
+            // #[rpc] on its own implies #[func].
+            None => rpc_attr.as_ref().map(|(index, _)| BoundAttr {
+                attr_name: util::ident("rpc"),
+                index: *index,
+                ty: BoundAttrType::Func {
+                    rename: None,
+                    has_gd_self: false,
+                    is_vararg: false,
+                    error_fallback: None,
+                },
+            }),
+        };
+
+        if let Some(attr) = attr {
+            // Remaining code no longer has attributes -- rest stays
+            let mut attr_indexes = vec![attr.index];
+            attr_indexes.extend(rpc_attr.as_ref().map(|(index, _)| *index));
+            attr_indexes.sort_unstable();
+            attr_indexes.dedup();
+            for index in attr_indexes.into_iter().rev() {
+                method.attributes.remove(index);
+            }
 
             if method.qualifiers.tk_default.is_some()
                 || method.qualifiers.tk_const.is_some()
@@ -272,12 +447,20 @@ fn process_godot_fns(
                 return attr.bail("generic fn parameters are not supported", method);
             }
 
+            if rpc_attr.is_some() && !matches!(attr.ty, BoundAttrType::Func { .. }) {
+                return attr.bail("#[rpc] can only be combined with #[func]", method);
+            }
+
             match &attr.ty {
                 BoundAttrType::Func {
                     rename,
                     has_gd_self,
+                    is_vararg,
+                    error_fallback,
                 } => {
                     let external_attributes = method.attributes.clone();
+                    let default_params = extract_default_params(method)?;
+
                     // Signatures are the same thing without body
                     let mut sig = util::reduce_to_signature(method);
                     if *has_gd_self {
@@ -287,11 +470,50 @@ fn process_godot_fns(
                             sig.params.inner.remove(0);
                         }
                     }
+                    if *is_vararg {
+                        let mut typed_params =
+                            sig.params
+                                .inner
+                                .iter()
+                                .filter_map(|(param, _)| match param {
+                                    FnParam::Typed(param) => Some(param),
+                                    FnParam::Receiver(_) => None,
+                                });
+
+                        match (typed_params.next(), typed_params.next()) {
+                            (Some(param), None) if is_variant_slice(&param.ty) => {}
+                            (Some(param), None) => {
+                                return bail!(param, "with attribute key `vararg`, the method must have a single parameter of type &[Variant]");
+                            }
+                            _ => {
+                                return attr.bail("with attribute key `vararg`, the method must have a single parameter of type &[Variant]", method);
+                            }
+                        }
+                        if !default_params.is_empty() {
+                            return attr.bail("with attribute key `vararg`, parameters cannot have #[opt] default values", method);
+                        }
+                    }
+
+                    let rpc_info = match rpc_attr {
+                        Some((_, parser)) => {
+                            let rpc_info = RpcInfo::parse(parser, method.vis_marker.clone())?;
+                            if rpc_info.has_helpers && *is_vararg {
+                                return attr.bail("#[rpc(helpers)] is not supported for functions with attribute key `vararg`", method);
+                            }
+                            Some(rpc_info)
+                        }
+                        None => None,
+                    };
+
                     func_definitions.push(FuncDefinition {
                         func: sig,
                         external_attributes,
                         rename: rename.clone(),
                         has_gd_self: *has_gd_self,
+                        default_params,
+                        is_vararg: *is_vararg,
+                        rpc_info,
+                        error_fallback: error_fallback.clone(),
                     });
                 }
                 BoundAttrType::Signal(ref _attr_val) => {
@@ -299,15 +521,17 @@ fn process_godot_fns(
                         return attr.bail("return types are not supported", method);
                     }
                     let external_attributes = method.attributes.clone();
+                    let vis_marker = method.vis_marker.clone();
                     let sig = util::reduce_to_signature(method);
 
                     signal_definitions.push(SignalDefinition {
                         signature: sig,
                         external_attributes,
+                        vis_marker,
                     });
                     removed_indexes.push(index);
                 }
-                BoundAttrType::Const(_) => {
+                BoundAttrType::Const { .. } => {
                     return attr.bail(
                         "#[constant] can only be used on associated constant",
                         method,
@@ -326,7 +550,77 @@ fn process_godot_fns(
     Ok((func_definitions, signal_definitions))
 }
 
-fn process_godot_constants(decl: &mut Impl) -> Result<Vec<Constant>, Error> {
+/// Parses `#[opt(default = ...)]` attributes on the parameters of a `#[func]` and removes them from the function.
+///
+/// Returns the default value expressions, which must belong to the trailing parameters.
+fn extract_default_params(method: &mut Function) -> Result<Vec<TokenStream>, Error> {
+    let mut default_params = vec![];
+
+    for (param, _) in method.params.inner.iter_mut() {
+        let FnParam::Typed(param) = param else {
+            continue;
+        };
+
+        let Some(mut parser) = KvParser::parse(&param.attributes, "opt")? else {
+            if !default_params.is_empty() {
+                return bail!(
+                    param,
+                    "parameters following a #[opt] parameter must also have a default value",
+                );
+            }
+            continue;
+        };
+
+        default_params.push(parser.handle_expr_required("default")?);
+        parser.finish()?;
+
+        param
+            .attributes
+            .retain(|attr| !util::path_is_single(&attr.path, "opt"));
+    }
+
+    Ok(default_params)
+}
+
+/// Whether `ty` is `&[Variant]`, possibly with a lifetime or a path to `Variant`.
+fn is_variant_slice(ty: &TyExpr) -> bool {
+    let mut tokens = ty.tokens.iter().peekable();
+
+    if !matches!(tokens.next(), Some(TokenTree::Punct(punct)) if punct.as_char() == '&') {
+        return false;
+    }
+
+    // Skip lifetime, e.g. &'a [Variant].
+    if matches!(tokens.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == '\'') {
+        tokens.next();
+        tokens.next();
+    }
+
+    match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Group(group)), None) if group.delimiter() == Delimiter::Bracket => {
+            let element = group.stream().into_iter().collect::<Vec<_>>();
+            util::path_ends_with(&element, "Variant")
+        }
+        _ => false,
+    }
+}
+
+/// Parses the `#[rpc]` attribute of a method, if present. Returns its index among the method's attributes, and the parser for its keys.
+fn extract_rpc_attribute(method: &Function) -> Result<Option<(usize, KvParser)>, Error> {
+    let Some(parser) = KvParser::parse(&method.attributes, "rpc")? else {
+        return Ok(None);
+    };
+
+    let index = method
+        .attributes
+        .iter()
+        .position(|attr| util::path_is_single(&attr.path, "rpc"))
+        .expect("#[rpc] attribute present");
+
+    Ok(Some((index, parser)))
+}
+
+fn process_godot_constants(decl: &mut Impl) -> Result<Vec<ConstDefinition>, Error> {
     let mut constant_signatures = vec![];
 
     for item in decl.body_items.iter_mut() {
@@ -345,11 +639,14 @@ fn process_godot_constants(decl: &mut Impl) -> Result<Vec<Constant>, Error> {
                 BoundAttrType::Signal(_) => {
                     return bail!(constant, "#[signal] can only be used on functions")
                 }
-                BoundAttrType::Const(_) => {
+                BoundAttrType::Const { has_getter } => {
                     if constant.initializer.is_none() {
                         return bail!(constant, "exported constant must have initializer");
                     }
-                    constant_signatures.push(constant.clone());
+                    constant_signatures.push(ConstDefinition {
+                        constant: constant.clone(),
+                        has_getter,
+                    });
                 }
             }
         }
@@ -374,13 +671,13 @@ where
 
         let new_found = match attr_name {
             name if name == "func" => {
-                // TODO you-win (August 8, 2023): handle default values here as well?
-
                 // Safe unwrap since #[func] must be present if we got to this point
                 let mut parser = KvParser::parse(attributes, "func")?.unwrap();
 
                 let rename = parser.handle_expr("rename")?.map(|ts| ts.to_string());
                 let has_gd_self = parser.handle_alone("gd_self")?;
+                let is_vararg = parser.handle_alone("vararg")?;
+                let error_fallback = parser.handle_expr("error_fallback")?;
 
                 BoundAttr {
                     attr_name: attr_name.clone(),
@@ -388,6 +685,8 @@ where
                     ty: BoundAttrType::Func {
                         rename,
                         has_gd_self,
+                        is_vararg,
+                        error_fallback,
                     },
                 }
             }
@@ -401,11 +700,19 @@ where
                     ty: BoundAttrType::Signal(attr.value.clone()),
                 }
             }
-            name if name == "constant" => BoundAttr {
-                attr_name: attr_name.clone(),
-                index,
-                ty: BoundAttrType::Const(attr.value.clone()),
-            },
+            name if name == "constant" => {
+                // Safe unwrap since #[constant] must be present if we got to this point
+                let mut parser = KvParser::parse(attributes, "constant")?.unwrap();
+
+                let has_getter = parser.handle_alone("getter")?;
+                parser.finish()?;
+
+                BoundAttr {
+                    attr_name: attr_name.clone(),
+                    index,
+                    ty: BoundAttrType::Const { has_getter },
+                }
+            }
             // Ignore unknown attributes
             _ => continue,
         };
@@ -500,7 +807,7 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
 
                     #(#cfg_attrs)*
                     impl ::godot::obj::cap::GodotRegisterClass for #class_name {
-                        fn __godot_register_class(builder: &mut ::godot::builder::GodotBuilder<Self>) {
+                        fn __godot_register_class(builder: &mut ::godot::register::builder::ClassBuilder<Self>) {
                             <Self as #trait_name>::register_class(builder)
                         }
                     }
@@ -629,7 +936,7 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
     }
 
     // If there is no ready() method explicitly overridden, we need to add one, to ensure that __before_ready() is called to
-    // initialize the OnReady fields.
+    // initialize the OnReady fields and configure RPCs.
     if !virtual_methods
         .iter()
         .any(|(sig, _)| sig.method_name == "ready")
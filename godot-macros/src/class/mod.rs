@@ -13,6 +13,7 @@ mod data_models {
     pub mod field_var;
     pub mod func;
     pub mod property;
+    pub mod rpc;
 }
 
 pub(crate) use data_models::field::*;
@@ -20,5 +21,6 @@ pub(crate) use data_models::field_export::*;
 pub(crate) use data_models::field_var::*;
 pub(crate) use data_models::func::*;
 pub(crate) use data_models::property::*;
+pub(crate) use data_models::rpc::*;
 pub(crate) use derive_godot_class::*;
 pub(crate) use godot_api::*;
@@ -7,72 +7,67 @@
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use venial::{Declaration, StructFields};
+use venial::Declaration;
 
-use crate::util::{bail, decl_get_info, DeclInfo};
+use crate::derive::PropertyRepr;
+use crate::util::{decl_get_info, DeclInfo};
 use crate::ParseResult;
 
 pub fn derive_export(decl: Declaration) -> ParseResult<TokenStream2> {
-    let DeclInfo { name, .. } = decl_get_info(&decl);
+    let repr = PropertyRepr::parse(&decl, "Export")?;
 
-    let enum_ = match decl {
-        Declaration::Enum(e) => e,
-        Declaration::Struct(s) => {
-            return bail!(s.tk_struct, "Export can only be derived on enums for now")
-        }
-        Declaration::Union(u) => {
-            return bail!(u.tk_union, "Export can only be derived on enums for now")
-        }
-        _ => unreachable!(),
-    };
+    let DeclInfo {
+        where_,
+        generic_params,
+        name,
+        ..
+    } = decl_get_info(&decl);
 
-    let hint_string = if enum_.variants.is_empty() {
-        return bail!(
-            enum_.name,
-            "In order to derive Export, enums must have at least one variant"
-        );
-    } else {
-        let mut hint_string_segments = Vec::new();
-        for (enum_v, _) in enum_.variants.inner.iter() {
-            let v_name = enum_v.name.clone();
-            let v_disc = if let Some(c) = enum_v.value.clone() {
-                c.value
-            } else {
-                return bail!(
-                    v_name,
-                    "Property can only be derived on enums with explicit discriminants in all their variants"
-                );
-            };
-            let v_disc_trimmed = v_disc
-                .to_string()
-                .trim_matches(['(', ')'].as_slice())
-                .to_string();
+    let export_info = match repr {
+        PropertyRepr::IntEnum { int_type, variants } => {
+            // Discriminants may be implicit, so the hint string "A:0,B:1" is assembled from their values at runtime.
+            let names = variants.iter().map(|variant| variant.to_string());
 
-            hint_string_segments.push(format!("{v_name}:{v_disc_trimmed}"));
+            quote! {
+                let hint_string = [
+                    #(
+                        format!("{}:{}", #names, Self::#variants as #int_type),
+                    )*
+                ].join(",");
 
-            match &enum_v.contents {
-                StructFields::Unit => {}
-                _ => {
-                    return bail!(
-                        v_name,
-                        "Property can only be derived on enums with only unit variants for now"
-                    )
+                godot::register::property::PropertyHintInfo {
+                    hint: godot::engine::global::PropertyHint::PROPERTY_HINT_ENUM,
+                    hint_string: godot::prelude::GString::from(hint_string),
                 }
-            };
+            }
         }
-        hint_string_segments.join(",")
-    };
+        PropertyRepr::StringEnum { variants } => {
+            let hint_string = variants
+                .iter()
+                .map(|variant| variant.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
 
-    let out = quote! {
-        #[allow(unused_parens)]
-        impl godot::register::property::Export for #name {
-            fn default_export_info() -> godot::register::property::PropertyHintInfo {
+            quote! {
                 godot::register::property::PropertyHintInfo {
                     hint: godot::engine::global::PropertyHint::PROPERTY_HINT_ENUM,
                     hint_string: godot::prelude::GString::from(#hint_string),
                 }
             }
         }
+        PropertyRepr::Struct { .. } | PropertyRepr::DataEnum => quote! {
+            <godot::builtin::Dictionary as godot::register::property::Export>::default_export_info()
+        },
+    };
+
+    let gen = generic_params.as_ref().map(|x| x.as_inline_args());
+    let out = quote! {
+        #[allow(unused_parens)]
+        impl #generic_params godot::register::property::Export for #name #gen #where_ {
+            fn default_export_info() -> godot::register::property::PropertyHintInfo {
+                #export_info
+            }
+        }
     };
     Ok(out)
 }
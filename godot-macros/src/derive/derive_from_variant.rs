@@ -5,24 +5,45 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, ToTokens};
 use venial::{Declaration, NamedStructFields, StructFields, TupleField, TupleStructFields};
 
-use crate::util::{decl_get_info, has_attr, DeclInfo};
+use crate::derive::{EnumTagging, GodotAttribute, VariantAttribute};
+use crate::util::{bail, decl_get_info, DeclInfo};
 use crate::ParseResult;
 
-fn has_attr_skip(attributes: &[venial::Attribute]) -> bool {
-    has_attr(attributes, "variant", "skip")
+fn has_attr_skip(attributes: &[venial::Attribute]) -> ParseResult<bool> {
+    VariantAttribute::parse(attributes).map(|attr| attr.skip)
 }
 
 pub fn derive_from_godot(decl: Declaration) -> ParseResult<TokenStream> {
+    let decl_info = decl_get_info(&decl);
+
+    if let Some(attr) = GodotAttribute::parse(&decl)? {
+        let DeclInfo {
+            where_,
+            generic_params,
+            name,
+            ..
+        } = &decl_info;
+
+        let gen = generic_params.as_ref().map(|x| x.as_inline_args());
+        let from_godot = attr.make_from_godot(&decl_info);
+
+        return Ok(quote! {
+            impl #generic_params ::godot::builtin::meta::FromGodot for #name #gen #where_ {
+                #from_godot
+            }
+        });
+    }
+
     let DeclInfo {
         where_,
         generic_params,
         name,
         name_string,
-    } = decl_get_info(&decl);
+    } = &decl_info;
 
     let err = format!("missing expected value {name_string}");
     let mut body = quote! {
@@ -33,14 +54,17 @@ pub fn derive_from_godot(decl: Declaration) -> ParseResult<TokenStream> {
         };
     };
 
+    let mut fields_impl = TokenStream::new();
     match decl {
         Declaration::Struct(s) => match s.fields {
             StructFields::Unit => make_unit_struct(&mut body),
             StructFields::Tuple(fields) if fields.fields.len() == 1 => {
-                make_new_type_struct(&mut body, fields)
+                make_new_type_struct(&mut body, fields)?
+            }
+            StructFields::Tuple(fields) => make_tuple_struct(fields, &mut body, name)?,
+            StructFields::Named(fields) => {
+                fields_impl = make_named_struct(fields, &mut body, &decl_info)?
             }
-            StructFields::Tuple(fields) => make_tuple_struct(fields, &mut body, &name),
-            StructFields::Named(fields) => make_named_struct(fields, &mut body, &name),
         },
         Declaration::Enum(enum_) => {
             if enum_.variants.is_empty() {
@@ -49,48 +73,39 @@ pub fn derive_from_godot(decl: Declaration) -> ParseResult<TokenStream> {
                     panic!("cannot convert Variant into uninhabited enum {}", #name_string);
                 }
             } else {
+                let tagging = EnumTagging::parse(&enum_.attributes)?;
+
                 let mut matches = quote! {};
                 for (enum_v, _) in &enum_.variants.inner {
+                    let variant_attr = VariantAttribute::parse(&enum_v.attributes)?;
                     let variant_name = enum_v.name.clone();
-                    let variant_name_string = enum_v.name.to_string();
-                    let if_let_content = match &enum_v.contents {
-                        _ if has_attr_skip(&enum_v.attributes) => {
-                            quote! {
-                                if root == Variant::nil() {
-                                    return Ok(Self::default());
-                                }
-                            }
-                        }
-                        StructFields::Unit if !has_attr_skip(&enum_v.attributes) => {
-                            quote! {
-                                let child = root.try_to::<String>();
-                                if let Ok(child) = child {
-                                    if child == #variant_name_string {
-                                        return Ok(Self::#variant_name);
-                                    }
-                                }
+                    let variant_name_string = variant_attr.godot_name(&enum_v.name);
+
+                    let if_let_content = if variant_attr.skip {
+                        quote! {
+                            if root == Variant::nil() {
+                                return Ok(Self::default());
                             }
                         }
-                        StructFields::Unit => quote! {},
-                        StructFields::Tuple(fields) if fields.fields.len() == 1 => {
-                            let (field, _) = fields.fields.first().unwrap();
-                            if has_attr_skip(&field.attributes) {
-                                make_enum_new_type_skipped(
-                                    field,
-                                    &variant_name,
-                                    &variant_name_string,
-                                )
-                            } else {
-                                make_enum_new_type(field, &variant_name, &variant_name_string)
+                    } else {
+                        match &tagging {
+                            EnumTagging::External => make_enum_external(
+                                &enum_v.contents,
+                                &variant_name,
+                                &variant_name_string,
+                            )?,
+                            EnumTagging::Internal { tag } => make_enum_internal(
+                                &enum_v.contents,
+                                &variant_name,
+                                &variant_name_string,
+                                tag,
+                            )?,
+                            EnumTagging::Untagged => {
+                                make_enum_untagged(&enum_v.contents, &variant_name)?
                             }
                         }
-                        StructFields::Tuple(fields) => {
-                            make_enum_tuple(fields, &variant_name, &variant_name_string)
-                        }
-                        StructFields::Named(fields) => {
-                            make_enum_named(fields, &variant_name, &variant_name_string)
-                        }
                     };
+
                     matches = quote! {
                         #matches
                         #if_let_content
@@ -119,69 +134,162 @@ pub fn derive_from_godot(decl: Declaration) -> ParseResult<TokenStream> {
                 #body
             }
         }
+
+        #fields_impl
     })
 }
 
+/// Generates statements reading named fields from the `Dictionary` bound to `dict`, honoring `skip`, `rename`, `default`
+/// and `flatten`.
+///
+/// Returns the statements and the field initializers for the constructor. `skip_default` maps a field to the expression used
+/// for skipped fields. `ignored_keys` are removed from the dictionary passed to flattened fields, in addition to the keys of
+/// all other fields.
+fn make_named_fields(
+    fields: &NamedStructFields,
+    dict: &Ident,
+    skip_default: impl Fn(&venial::NamedField) -> TokenStream,
+    ignored_keys: &[&str],
+) -> ParseResult<(Vec<TokenStream>, Vec<TokenStream>)> {
+    let mut reads = Vec::new();
+    let mut inits = Vec::new();
+    let mut consumed_keys: Vec<String> = ignored_keys.iter().map(|key| key.to_string()).collect();
+    let mut flattened = Vec::new();
+
+    for (field, _) in fields.fields.iter() {
+        let attr = VariantAttribute::parse(&field.attributes)?;
+        let ident = &field.name;
+        let field_type = &field.ty;
+
+        if attr.skip {
+            let default = skip_default(field);
+            inits.push(quote! { #ident: #default });
+            continue;
+        }
+
+        if attr.flatten {
+            flattened.push((ident, field_type));
+            inits.push(ident.to_token_stream());
+            continue;
+        }
+
+        let key = attr.godot_name(ident);
+        let missing = match attr.default {
+            Some(default) => quote! { #default },
+            None => {
+                let err = format!("missing expected value {key}");
+                quote! { return Err(ConvertError::with_cause_value(#err, #dict)) }
+            }
+        };
+
+        reads.push(quote! {
+            let #ident = match #dict.get(#key) {
+                Some(value) => value.try_to::<#field_type>()?,
+                None => #missing,
+            };
+        });
+        inits.push(ident.to_token_stream());
+        consumed_keys.push(key);
+    }
+
+    if !flattened.is_empty() {
+        reads.push(quote! {
+            let rest = {
+                let mut rest = #dict.duplicate_shallow();
+                #(
+                    rest.remove(#consumed_keys);
+                )*
+                rest
+            };
+        });
+
+        for (ident, field_type) in flattened {
+            reads.push(quote! {
+                let #ident = {
+                    use ::godot::private::{FlattenFromDerived as _, FlattenFromDictionary as _};
+                    (&&::godot::private::Flatten::<#field_type>(::std::marker::PhantomData))
+                        .try_from_flattened(rest.duplicate_shallow())?
+                };
+            });
+        }
+    }
+
+    Ok((reads, inits))
+}
+
+/// Also returns an implementation of `DerivedFromFields`, through which `#[variant(flatten)]` can read the fields from another struct.
 fn make_named_struct(
     fields: venial::NamedStructFields,
     body: &mut TokenStream,
-    name: &impl ToTokens,
-) {
-    let fields = fields.fields.iter().map(|(field, _)| {
-        let ident = &field.name;
-        let string_ident = &field.name.to_string();
+    decl_info: &DeclInfo,
+) -> ParseResult<TokenStream> {
+    let DeclInfo {
+        where_,
+        generic_params,
+        name,
+        ..
+    } = decl_info;
+
+    let root = format_ident!("root");
+    let (reads, inits) = make_named_fields(
+        &fields,
+        &root,
+        |field| {
+            let ident = &field.name;
+            quote! { #name::default().#ident }
+        },
+        &[],
+    )?;
 
-        if has_attr_skip(&field.attributes) {
-            (quote! {}, quote! { #ident: #name::default().#ident })
-        } else {
-            let err = format!("missing expected value {string_ident}");
-            (
-                quote! {
-                    let #ident = match root.get(#string_ident) {
-                        Some(value) => value,
-                        None => return Err(ConvertError::with_cause_value(#err, root)),
-                    };
-                },
-                quote! { #ident: #ident.try_to()? },
-            )
-        }
-    });
-    let (set_idents, set_self): (Vec<_>, Vec<_>) = fields.unzip();
     *body = quote! {
         #body
         let root = root.try_to::<::godot::builtin::Dictionary>()?;
-        #(
-            #set_idents
-        )*
-        Ok(Self { #(#set_self,)* })
-    }
+        ::godot::private::DerivedFromFields::__from_fields(root)
+    };
+
+    let gen = generic_params.as_ref().map(|x| x.as_inline_args());
+    Ok(quote! {
+        impl #generic_params ::godot::private::DerivedFromFields for #name #gen #where_ {
+            fn __from_fields(
+                root: ::godot::builtin::Dictionary
+            ) -> Result<Self, ::godot::builtin::meta::ConvertError> {
+                use ::godot::builtin::meta::ConvertError;
+                #(
+                    #reads
+                )*
+                Ok(Self { #(#inits,)* })
+            }
+        }
+    })
 }
 
 fn make_tuple_struct(
     fields: venial::TupleStructFields,
     body: &mut TokenStream,
     name: &impl ToTokens,
-) {
-    let ident_and_set = fields.fields.iter().enumerate().map(|(k, (f, _))| {
+) -> ParseResult<()> {
+    let mut idents = Vec::new();
+    let mut ident_set = Vec::new();
+    for (k, (f, _)) in fields.fields.iter().enumerate() {
         let ident = format_ident!("__{}", k);
+        let index = proc_macro2::Literal::usize_unsuffixed(k);
         let field_type = f.ty.to_token_stream();
-        (
-            ident.clone(),
-            if has_attr_skip(&f.attributes) {
-                quote! {
-                    let #ident = <#name as Default>::default().#ident;
-                }
-            } else {
-                quote! {
-                    let #ident = match root.pop_front() {
-                        Some(value) => value.try_to::<#field_type>()?,
-                        None => return Err(ConvertError::with_cause_value("missing expected value", root)),
-                    };
-                }
-            },
-        )
-    });
-    let (idents, ident_set): (Vec<_>, Vec<_>) = ident_and_set.unzip();
+
+        ident_set.push(if has_attr_skip(&f.attributes)? {
+            quote! {
+                let #ident = <#name as Default>::default().#index;
+            }
+        } else {
+            quote! {
+                let #ident = match root.pop_front() {
+                    Some(value) => value.try_to::<#field_type>()?,
+                    None => return Err(ConvertError::with_cause_value("missing expected value", root)),
+                };
+            }
+        });
+        idents.push(ident);
+    }
+
     *body = quote! {
         #body
         let mut root = root.try_to::<::godot::builtin::VariantArray>()?;
@@ -192,10 +300,15 @@ fn make_tuple_struct(
             #(#idents,)*
         ))
     };
+
+    Ok(())
 }
 
-fn make_new_type_struct(body: &mut TokenStream, fields: venial::TupleStructFields) {
-    *body = if has_attr_skip(&fields.fields.first().unwrap().0.attributes) {
+fn make_new_type_struct(
+    body: &mut TokenStream,
+    fields: venial::TupleStructFields,
+) -> ParseResult<()> {
+    *body = if has_attr_skip(&fields.fields.first().unwrap().0.attributes)? {
         quote! { Ok(Self::default()) }
     } else {
         quote! {
@@ -203,7 +316,9 @@ fn make_new_type_struct(body: &mut TokenStream, fields: venial::TupleStructField
             let root = root.try_to()?;
             Ok(Self(root))
         }
-    }
+    };
+
+    Ok(())
 }
 
 fn make_unit_struct(body: &mut TokenStream) {
@@ -213,6 +328,177 @@ fn make_unit_struct(body: &mut TokenStream) {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Enum variants
+
+/// Generates the check for a variant of an externally tagged enum: `"Variant"` or `{ "Variant": payload }`.
+fn make_enum_external(
+    contents: &StructFields,
+    variant_name: &Ident,
+    variant_name_string: &str,
+) -> ParseResult<TokenStream> {
+    let check = match contents {
+        StructFields::Unit => quote! {
+            let child = root.try_to::<String>();
+            if let Ok(child) = child {
+                if child == #variant_name_string {
+                    return Ok(Self::#variant_name);
+                }
+            }
+        },
+        StructFields::Tuple(fields) if fields.fields.len() == 1 => {
+            let (field, _) = fields.fields.first().unwrap();
+            if has_attr_skip(&field.attributes)? {
+                make_enum_new_type_skipped(field, variant_name, variant_name_string)
+            } else {
+                make_enum_new_type(field, variant_name, variant_name_string)
+            }
+        }
+        StructFields::Tuple(fields) => {
+            let (reads, idents) = make_enum_tuple_fields(fields)?;
+            quote! {
+                let child = root.try_to::<::godot::builtin::Dictionary>();
+                if let Ok(child) = child {
+                    if let Some(variant) = child.get(#variant_name_string) {
+                        let mut variant = variant.try_to::<::godot::builtin::VariantArray>()?;
+                        #(#reads)*
+                        return Ok(Self::#variant_name(#(#idents ,)*));
+                    }
+                }
+            }
+        }
+        StructFields::Named(fields) => {
+            let (reads, inits) = make_enum_named_fields(fields, &[])?;
+            quote! {
+                if let Ok(root) = root.try_to::<::godot::builtin::Dictionary>() {
+                    if let Some(variant) = root.get(#variant_name_string) {
+                        let variant = variant.try_to::<::godot::builtin::Dictionary>()?;
+                        #(
+                            #reads
+                        )*
+                        return Ok(Self::#variant_name {
+                            #( #inits, )*
+                        });
+                    }
+                }
+            }
+        }
+    };
+
+    Ok(check)
+}
+
+/// Generates the check for a variant of an internally tagged enum: `{ tag: "Variant", fields... }`.
+fn make_enum_internal(
+    contents: &StructFields,
+    variant_name: &Ident,
+    variant_name_string: &str,
+    tag: &str,
+) -> ParseResult<TokenStream> {
+    let construct = match contents {
+        StructFields::Unit => quote! {
+            return Ok(Self::#variant_name);
+        },
+        StructFields::Named(fields) => {
+            let (reads, inits) = make_enum_named_fields(fields, &[tag])?;
+            quote! {
+                #(
+                    #reads
+                )*
+                return Ok(Self::#variant_name {
+                    #( #inits, )*
+                });
+            }
+        }
+        StructFields::Tuple(fields) => {
+            return bail!(
+                fields,
+                "#[variant(tag = ...)] only supports unit variants and variants with named fields"
+            );
+        }
+    };
+
+    Ok(quote! {
+        if let Ok(variant) = root.try_to::<::godot::builtin::Dictionary>() {
+            let tag = variant.get(#tag).and_then(|tag| tag.try_to::<String>().ok());
+            if tag.as_deref() == Some(#variant_name_string) {
+                #construct
+            }
+        }
+    })
+}
+
+/// Generates the attempt to convert the payload of an untagged enum into a variant; failed attempts fall through to the next one.
+fn make_enum_untagged(contents: &StructFields, variant_name: &Ident) -> ParseResult<TokenStream> {
+    let check = match contents {
+        StructFields::Unit => quote! {
+            if root.is_nil() {
+                return Ok(Self::#variant_name);
+            }
+        },
+        StructFields::Tuple(fields) if fields.fields.len() == 1 => {
+            let (field, _) = fields.fields.first().unwrap();
+            let field_type = &field.ty;
+
+            if has_attr_skip(&field.attributes)? {
+                quote! {
+                    if root.is_nil() {
+                        return Ok(Self::#variant_name(<#field_type as Default>::default()));
+                    }
+                }
+            } else {
+                quote! {
+                    if let Ok(value) = root.try_to::<#field_type>() {
+                        return Ok(Self::#variant_name(value));
+                    }
+                }
+            }
+        }
+        StructFields::Tuple(fields) => {
+            let (reads, idents) = make_enum_tuple_fields(fields)?;
+            let mut len = 0usize;
+            for (field, _) in fields.fields.iter() {
+                if !has_attr_skip(&field.attributes)? {
+                    len += 1;
+                }
+            }
+
+            quote! {
+                let attempt = || -> Result<Self, ConvertError> {
+                    let mut variant = root.try_to::<::godot::builtin::VariantArray>()?;
+                    if variant.len() != #len {
+                        return Err(ConvertError::with_value(variant));
+                    }
+                    #(#reads)*
+                    Ok(Self::#variant_name(#(#idents ,)*))
+                };
+                if let Ok(value) = attempt() {
+                    return Ok(value);
+                }
+            }
+        }
+        StructFields::Named(fields) => {
+            let (reads, inits) = make_enum_named_fields(fields, &[])?;
+            quote! {
+                let attempt = || -> Result<Self, ConvertError> {
+                    let variant = root.try_to::<::godot::builtin::Dictionary>()?;
+                    #(
+                        #reads
+                    )*
+                    Ok(Self::#variant_name {
+                        #( #inits, )*
+                    })
+                };
+                if let Ok(value) = attempt() {
+                    return Ok(value);
+                }
+            }
+        }
+    };
+
+    Ok(check)
+}
+
 fn make_enum_new_type(
     field: &TupleField,
     variant_name: &impl ToTokens,
@@ -247,76 +533,44 @@ fn make_enum_new_type_skipped(
     }
 }
 
-fn make_enum_tuple(
+/// Generates statements popping the fields of a tuple variant from the `VariantArray` bound to `variant`.
+fn make_enum_tuple_fields(
     fields: &TupleStructFields,
-    variant_name: &impl ToTokens,
-    variant_name_string: &impl ToTokens,
-) -> TokenStream {
-    let fields = fields.fields.iter().enumerate().map(|(k, (field, _))| {
+) -> ParseResult<(Vec<TokenStream>, Vec<Ident>)> {
+    let mut idents = Vec::new();
+    let mut reads = Vec::new();
+    for (k, (field, _)) in fields.fields.iter().enumerate() {
         let ident = format_ident!("__{k}");
         let field_type = &field.ty;
-        let set_ident = if has_attr_skip(&field.attributes) {
+        reads.push(if has_attr_skip(&field.attributes)? {
             quote! {
                 let #ident = <#field_type as Default>::default();
             }
         } else {
             quote! {
                 let #ident = variant.pop_front()
-                    .ok_or(ConvertError::with_cause_value("missing expected value", &variant))?
+                    .ok_or_else(|| ConvertError::with_cause_value("missing expected value", &variant))?
                     .try_to::<#field_type>()?;
             }
-        };
-        (ident.to_token_stream(), set_ident)
-    });
-    let (idents, set_idents): (Vec<_>, Vec<_>) = fields.unzip();
-
-    quote! {
-        let child = root.try_to::<::godot::builtin::Dictionary>();
-        if let Ok(child) = child {
-            if let Some(variant) = child.get(#variant_name_string) {
-                let mut variant = variant.try_to::<::godot::builtin::VariantArray>()?;
-                #(#set_idents)*
-                return Ok(Self::#variant_name(#(#idents ,)*));
-            }
-        }
+        });
+        idents.push(ident);
     }
+
+    Ok((reads, idents))
 }
-fn make_enum_named(
-    fields: &NamedStructFields,
-    variant_name: &impl ToTokens,
-    variant_name_string: &impl ToTokens,
-) -> TokenStream {
-    let fields = fields.fields.iter().map(|(field, _)| {
-        let field_name = &field.name;
-        let field_name_string = &field.name.to_string();
-        let field_type = &field.ty;
-        let set_field = if has_attr(&field.attributes, "variant", "skip") {
-            quote! {
-                let #field_name = <#field_type as Default>::default();
-            }
-        } else {
-            let err = format!("missing expected value {field_name_string}");
-            quote! {
-                let #field_name = variant.get(#field_name_string)
-                    .ok_or(ConvertError::with_cause_value(#err, &variant))?
-                    .try_to::<#field_type>()?;
-            }
-        };
-        (field_name.to_token_stream(), set_field)
-    });
 
-    let (fields, set_fields): (Vec<_>, Vec<_>) = fields.unzip();
-    quote! {
-        if let Ok(root) = root.try_to::<::godot::builtin::Dictionary>() {
-            if let Some(variant) = root.get(#variant_name_string) {
-                let variant = variant.try_to::<::godot::builtin::Dictionary>()?;
-                #(
-                    #set_fields
-                )*
-                return Ok(Self::#variant_name {
-                    #( #fields, )*
-                });
-            }
-        }
-    }
+/// Generates statements reading the fields of a named variant from the `Dictionary` bound to `variant`.
+fn make_enum_named_fields(
+    fields: &NamedStructFields,
+    ignored_keys: &[&str],
+) -> ParseResult<(Vec<TokenStream>, Vec<TokenStream>)> {
+    make_named_fields(
+        fields,
+        &format_ident!("variant"),
+        |field| {
+            let field_type = &field.ty;
+            quote! { <#field_type as Default>::default() }
+        },
+        ignored_keys,
+    )
 }
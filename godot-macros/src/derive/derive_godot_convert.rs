@@ -9,22 +9,39 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use venial::Declaration;
 
+use crate::derive::GodotAttribute;
 use crate::util::{decl_get_info, DeclInfo};
 use crate::ParseResult;
 
 pub fn derive_godot_convert(decl: Declaration) -> ParseResult<TokenStream> {
+    let decl_info = decl_get_info(&decl);
     let DeclInfo {
         where_,
         generic_params,
         name,
         ..
-    } = decl_get_info(&decl);
+    } = &decl_info;
 
     let gen = generic_params.as_ref().map(|x| x.as_inline_args());
 
+    let (body, checks) = match GodotAttribute::parse(&decl)? {
+        Some(attr) => (
+            attr.make_godot_convert(),
+            attr.make_discriminant_checks(&decl_info),
+        ),
+        None => (
+            quote! {
+                type Via = ::godot::builtin::Variant;
+            },
+            TokenStream::new(),
+        ),
+    };
+
     Ok(quote! {
         impl #generic_params ::godot::builtin::meta::GodotConvert for #name #gen #where_ {
-            type Via = ::godot::builtin::Variant;
+            #body
         }
+
+        #checks
     })
 }
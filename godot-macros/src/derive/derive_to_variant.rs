@@ -9,7 +9,8 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use venial::{Declaration, StructFields};
 
-use crate::util::{decl_get_info, has_attr, DeclInfo};
+use crate::derive::{EnumTagging, GodotAttribute, VariantAttribute};
+use crate::util::{bail, decl_get_info, DeclInfo};
 use crate::ParseResult;
 
 pub fn derive_to_godot(decl: Declaration) -> ParseResult<TokenStream> {
@@ -17,47 +18,65 @@ pub fn derive_to_godot(decl: Declaration) -> ParseResult<TokenStream> {
         let mut root = ::godot::builtin::Dictionary::new();
     };
 
+    let decl_info = decl_get_info(&decl);
     let DeclInfo {
         where_,
         generic_params,
         name,
         name_string,
-    } = decl_get_info(&decl);
+    } = &decl_info;
 
+    if let Some(attr) = GodotAttribute::parse(&decl)? {
+        let gen = generic_params.as_ref().map(|x| x.as_inline_args());
+        let to_godot = attr.make_to_godot();
+
+        return Ok(quote! {
+            impl #generic_params ::godot::builtin::meta::ToGodot for #name #gen #where_ {
+                #to_godot
+            }
+        });
+    }
+
+    let mut fields_impl = TokenStream::new();
     match &decl {
         Declaration::Struct(struct_) => match &struct_.fields {
-            StructFields::Unit => make_struct_unit(&mut body, name_string),
-            StructFields::Tuple(fields) => make_struct_tuple(&mut body, fields, name_string),
+            StructFields::Unit => make_struct_unit(&mut body, name_string.clone()),
+            StructFields::Tuple(fields) => {
+                make_struct_tuple(&mut body, fields, name_string.clone())?
+            }
             StructFields::Named(named_struct) => {
-                make_struct_named(&mut body, named_struct, name_string);
+                fields_impl = make_struct_named(&mut body, named_struct, &decl_info)?;
             }
         },
         Declaration::Enum(enum_) => {
-            let arms = enum_.variants.iter().map(|(enum_v, _)| {
+            let tagging = EnumTagging::parse(&enum_.attributes)?;
+
+            let mut arms = Vec::new();
+            for (enum_v, _) in enum_.variants.iter() {
+                let variant_attr = VariantAttribute::parse(&enum_v.attributes)?;
                 let variant_name = enum_v.name.clone();
-                let variant_name_string = enum_v.name.to_string();
+                let variant_name_string = variant_attr.godot_name(&enum_v.name);
                 let fields = match &enum_v.contents {
                     StructFields::Unit => quote! {},
-                    StructFields::Tuple(s) => make_tuple_enum_field(s),
-                    StructFields::Named(named) => make_named_enum_field(named),
+                    StructFields::Tuple(s) => make_tuple_enum_field(s)?,
+                    StructFields::Named(named) => make_named_enum_field(named)?,
                 };
-                let arm_content = match &enum_v.contents {
-                    _ if has_attr(&enum_v.attributes, "variant", "skip") => quote! {
+                let arm_content = if variant_attr.skip {
+                    quote! {
                         return ::godot::builtin::dict! {
                             #name_string: ::godot::builtin::Variant::nil()
                         }.to_variant();
-                    },
-                    StructFields::Unit => quote! { #variant_name_string.to_variant() },
-
-                    StructFields::Tuple(fields) => make_enum_tuple_arm(fields, variant_name_string),
-                    StructFields::Named(fields) => make_enum_named_arm(fields, variant_name_string),
+                    }
+                } else {
+                    make_enum_arm(&enum_v.contents, &variant_name_string, &tagging)?
                 };
-                quote! {
+
+                arms.push(quote! {
                     Self::#variant_name #fields => {
                         #arm_content
                     }
-                }
-            });
+                });
+            }
 
             body = quote! {
                 #body
@@ -98,163 +117,239 @@ pub fn derive_to_godot(decl: Declaration) -> ParseResult<TokenStream> {
                 #body
             }
         }
+
+        #fields_impl
     })
 }
 
-fn make_named_enum_field(named: &venial::NamedStructFields) -> TokenStream {
-    let fields = named.fields.iter().map(|(field, _)| {
+fn make_named_enum_field(named: &venial::NamedStructFields) -> ParseResult<TokenStream> {
+    let mut fields = Vec::new();
+    for (field, _) in named.fields.iter() {
         let field_name = field.name.to_token_stream();
-        if has_attr(&field.attributes, "variant", "skip") {
-            quote! { #field_name : _ }
+        if VariantAttribute::parse(&field.attributes)?.skip {
+            fields.push(quote! { #field_name : _ });
         } else {
-            field_name
+            fields.push(field_name);
         }
-    });
-    quote! { { #(#fields ,)* } }
+    }
+
+    Ok(quote! { { #(#fields ,)* } })
 }
 
-fn make_tuple_enum_field(s: &venial::TupleStructFields) -> TokenStream {
-    let fields = s.fields.iter().enumerate().map(|(k, (f, _))| {
-        if has_attr(&f.attributes, "variant", "skip") {
-            quote! { _ }
+fn make_tuple_enum_field(s: &venial::TupleStructFields) -> ParseResult<TokenStream> {
+    let mut fields = Vec::new();
+    for (k, (f, _)) in s.fields.iter().enumerate() {
+        if VariantAttribute::parse(&f.attributes)?.skip {
+            fields.push(quote! { _ });
         } else {
-            format_ident!("__{}", k).to_token_stream()
+            fields.push(format_ident!("__{}", k).to_token_stream());
         }
-    });
+    }
 
-    quote! {
+    Ok(quote! {
         (#(#fields ,)*)
-    }
+    })
 }
 
-fn make_enum_named_arm(
-    fields: &venial::NamedStructFields,
-    variant_name_string: String,
-) -> TokenStream {
-    let fields = fields
-        .fields
-        .iter()
-        .filter(|(f, _)| !has_attr(&f.attributes, "variant", "skip"))
-        .map(|(field, _)| (field.name.clone(), field.name.to_string()))
-        .map(|(ident, ident_string)| {
+/// Generates the content of an enum variant, according to the enum's tagging.
+fn make_enum_arm(
+    contents: &StructFields,
+    variant_name_string: &str,
+    tagging: &EnumTagging,
+) -> ParseResult<TokenStream> {
+    let arm = match (tagging, contents) {
+        (EnumTagging::External, StructFields::Unit) => {
+            quote! { #variant_name_string.to_variant() }
+        }
+        (EnumTagging::External, StructFields::Tuple(fields)) => {
+            let payload = make_enum_tuple_payload(fields)?;
+            quote! { ::godot::builtin::dict! { #variant_name_string: #payload }.to_variant() }
+        }
+        (EnumTagging::External, StructFields::Named(fields)) => {
+            let payload = make_named_fields_dict(fields, |ident| ident.to_token_stream())?;
+            quote! { ::godot::builtin::dict! { #variant_name_string: #payload }.to_variant() }
+        }
+
+        (EnumTagging::Internal { tag }, StructFields::Unit) => {
+            quote! { ::godot::builtin::dict! { #tag: #variant_name_string }.to_variant() }
+        }
+        (EnumTagging::Internal { tag }, StructFields::Named(fields)) => {
+            let payload = make_named_fields_dict(fields, |ident| ident.to_token_stream())?;
             quote! {
-                root.insert(#ident_string,#ident.to_variant());
+                let mut tagged = ::godot::builtin::dict! { #tag: #variant_name_string };
+                tagged.extend_dictionary(#payload, false);
+                tagged.to_variant()
             }
-        });
+        }
+        (EnumTagging::Internal { .. }, StructFields::Tuple(fields)) => {
+            return bail!(
+                fields,
+                "#[variant(tag = ...)] only supports unit variants and variants with named fields"
+            );
+        }
 
-    quote! {
-        let mut root = ::godot::builtin::Dictionary::new();
-        #(
-            #fields
-        )*
-        ::godot::builtin::dict! { #variant_name_string: root }.to_variant()
-    }
+        (EnumTagging::Untagged, StructFields::Unit) => {
+            quote! { ::godot::builtin::Variant::nil() }
+        }
+        (EnumTagging::Untagged, StructFields::Tuple(fields)) => {
+            let payload = make_enum_tuple_payload(fields)?;
+            quote! { ::godot::builtin::meta::ToGodot::to_variant(&#payload) }
+        }
+        (EnumTagging::Untagged, StructFields::Named(fields)) => {
+            let payload = make_named_fields_dict(fields, |ident| ident.to_token_stream())?;
+            quote! { ::godot::builtin::meta::ToGodot::to_variant(&#payload) }
+        }
+    };
+
+    Ok(arm)
 }
 
-fn make_enum_tuple_arm(
-    fields: &venial::TupleStructFields,
-    variant_name_string: String,
-) -> TokenStream {
+/// Generates the payload of a tuple variant: the single value for newtypes, otherwise an array of the non-skipped fields.
+fn make_enum_tuple_payload(fields: &venial::TupleStructFields) -> ParseResult<TokenStream> {
     if fields.fields.len() == 1 {
-        let res = if has_attr(
-            &fields.fields.first().unwrap().0.attributes,
-            "variant",
-            "skip",
-        ) {
-            quote! { godot::builtin::Variant::nil() }
+        let payload = if VariantAttribute::parse(&fields.fields.first().unwrap().0.attributes)?.skip
+        {
+            quote! { ::godot::builtin::Variant::nil() }
         } else {
-            quote! { __0 }
+            quote! { ::godot::builtin::meta::ToGodot::to_variant(&__0) }
         };
-        return quote! { godot::builtin::dict! { #variant_name_string : #res }.to_variant() };
+        return Ok(payload);
     }
-    let fields = fields
-        .fields
-        .iter()
-        .enumerate()
-        .filter(|(_, (f, _))| !has_attr(&f.attributes, "variant", "skip"))
-        .map(|(k, _)| format_ident!("__{}", k))
-        .map(|ident| {
+
+    let mut pushes = Vec::new();
+    for (k, (f, _)) in fields.fields.iter().enumerate() {
+        if !VariantAttribute::parse(&f.attributes)?.skip {
+            let ident = format_ident!("__{}", k);
+            pushes.push(quote! {
+                root.push(::godot::builtin::meta::ToGodot::to_variant(&#ident));
+            });
+        }
+    }
+
+    Ok(quote! {
+        {
+            let mut root = ::godot::builtin::VariantArray::new();
+            #(
+                #pushes
+            )*
+            root
+        }
+    })
+}
+
+/// Generates an expression building a `Dictionary` from named fields, honoring `skip`, `rename` and `flatten`.
+///
+/// `access` maps a field name to the expression accessing the field's value.
+fn make_named_fields_dict(
+    fields: &venial::NamedStructFields,
+    access: impl Fn(&proc_macro2::Ident) -> TokenStream,
+) -> ParseResult<TokenStream> {
+    let mut inserts = Vec::new();
+    for (field, _) in fields.fields.iter() {
+        let attr = VariantAttribute::parse(&field.attributes)?;
+        if attr.skip {
+            continue;
+        }
+
+        let value = access(&field.name);
+        let insert = if attr.flatten {
+            let field_type = &field.ty;
             quote! {
-                root.push(#ident.to_variant());
+                let flattened = {
+                    use ::godot::private::{FlattenToDerived as _, FlattenToDictionary as _};
+                    (&&::godot::private::Flatten::<#field_type>(::std::marker::PhantomData))
+                        .to_flattened(&#value)
+                };
+                fields.extend_dictionary(flattened, false);
             }
-        });
-    quote! {
-        let mut root = godot::builtin::VariantArray::new();
-        #(
-            #fields
+        } else {
+            let key = attr.godot_name(&field.name);
+            quote! {
+                fields.insert(#key, ::godot::builtin::meta::ToGodot::to_variant(&#value));
+            }
+        };
 
-        )*
-        godot::builtin::dict!{ #variant_name_string: root }.to_variant()
+        inserts.push(insert);
     }
+
+    Ok(quote! {
+        {
+            let mut fields = ::godot::builtin::Dictionary::new();
+            #(
+                #inserts
+            )*
+            fields
+        }
+    })
 }
 
+/// Also returns an implementation of `DerivedToFields`, through which `#[variant(flatten)]` can merge the fields into another struct.
 fn make_struct_named(
     body: &mut TokenStream,
     fields: &venial::NamedStructFields,
-    string_ident: String,
-) {
-    let fields = fields
-        .fields
-        .items()
-        .filter(|f| !has_attr(&f.attributes, "variant", "skip"))
-        .map(|nf| {
-            let field_name = nf.name.clone();
-            let field_name_string = nf.name.to_string();
+    decl_info: &DeclInfo,
+) -> ParseResult<TokenStream> {
+    let DeclInfo {
+        where_,
+        generic_params,
+        name,
+        name_string,
+    } = decl_info;
 
-            quote! {
-                fields.insert(#field_name_string, self.#field_name.to_variant());
-            }
-        });
+    let gen = generic_params.as_ref().map(|x| x.as_inline_args());
+    let fields = make_named_fields_dict(fields, |ident| quote! { self.#ident })?;
 
     *body = quote! {
         #body
-        let mut fields = godot::builtin::Dictionary::new();
-        #(
-            #fields
-        )*
-        root.insert(#string_ident, fields.to_variant());
+        let fields = ::godot::private::DerivedToFields::__to_fields(self);
+        root.insert(#name_string, fields.to_variant());
     };
+
+    Ok(quote! {
+        impl #generic_params ::godot::private::DerivedToFields for #name #gen #where_ {
+            fn __to_fields(&self) -> ::godot::builtin::Dictionary {
+                #fields
+            }
+        }
+    })
 }
 
 fn make_struct_tuple(
     body: &mut TokenStream,
     fields: &venial::TupleStructFields,
     string_ident: String,
-) {
+) -> ParseResult<()> {
     if fields.fields.len() == 1
-        && !has_attr(
-            &fields.fields.first().unwrap().0.attributes,
-            "variant",
-            "skip",
-        )
+        && !VariantAttribute::parse(&fields.fields.first().unwrap().0.attributes)?.skip
     {
         *body = quote! {
             #body
             root.insert(#string_ident, self.0.to_variant());
         };
 
-        return;
+        return Ok(());
+    }
+
+    let mut pushes = Vec::new();
+    for (k, (f, _)) in fields.fields.iter().enumerate() {
+        if !VariantAttribute::parse(&f.attributes)?.skip {
+            let index = proc_macro2::Literal::usize_unsuffixed(k);
+            pushes.push(quote! {
+                fields.push(self.#index.to_variant());
+            });
+        }
     }
-    let fields = fields
-        .fields
-        .iter()
-        .filter(|(f, _)| !has_attr(&f.attributes, "variant", "skip"))
-        .enumerate()
-        .map(|(k, _)| proc_macro2::Literal::usize_unsuffixed(k))
-        .map(|ident| {
-            quote! {
-                fields.push(self.#ident.to_variant());
-            }
-        });
 
     *body = quote! {
         #body
         let mut fields = godot::builtin::VariantArray::new();
         #(
-            #fields
+            #pushes
         )*
         root.insert(#string_ident, fields.to_variant());
     };
+
+    Ok(())
 }
 
 fn make_struct_unit(body: &mut TokenStream, string_ident: String) {
@@ -5,108 +5,220 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
 use quote::{quote, ToTokens};
 use venial::{Declaration, StructFields};
 
+use crate::derive::{GodotAttribute, ViaType};
 use crate::util::{bail, decl_get_info, ident, DeclInfo};
 use crate::ParseResult;
 
+/// How a type deriving `Var`/`Export` is represented as a property.
+pub enum PropertyRepr {
+    /// Fieldless enum, stored as the integer value of its discriminant.
+    IntEnum {
+        int_type: TokenStream2,
+        variants: Vec<Ident>,
+    },
+
+    /// Fieldless enum with `#[godot(via = GString)]`, stored as the name of its variant.
+    StringEnum { variants: Vec<Ident> },
+
+    /// Struct with named fields, stored as a dictionary mapping field names to the field's property values.
+    Struct { fields: Vec<Ident> },
+
+    /// Enum with fields, stored as the dictionary produced by its `ToGodot` implementation.
+    DataEnum,
+}
+
+impl PropertyRepr {
+    pub fn parse(decl: &Declaration, derive_name: &str) -> ParseResult<Self> {
+        let repr = match decl {
+            Declaration::Enum(enum_) => {
+                if enum_.variants.is_empty() {
+                    return bail!(
+                        enum_.name,
+                        "In order to derive {derive_name}, enums must have at least one variant"
+                    );
+                }
+
+                let variants: Vec<Ident> = enum_
+                    .variants
+                    .iter()
+                    .map(|(variant, _)| variant.name.clone())
+                    .collect();
+
+                let fieldless = enum_
+                    .variants
+                    .iter()
+                    .all(|(variant, _)| matches!(variant.contents, StructFields::Unit));
+
+                match GodotAttribute::parse(decl)? {
+                    Some(GodotAttribute::Via {
+                        via_type: ViaType::GString,
+                        ..
+                    }) => Self::StringEnum { variants },
+                    Some(GodotAttribute::Via {
+                        via_type: ViaType::Int { int_ident },
+                        ..
+                    }) => Self::IntEnum {
+                        int_type: int_ident.to_token_stream(),
+                        variants,
+                    },
+                    _ if fieldless => {
+                        let int_type = match enum_
+                            .attributes
+                            .iter()
+                            .find(|attr| attr.get_single_path_segment() == Some(&ident("repr")))
+                        {
+                            Some(attr) => attr.value.to_token_stream(),
+                            None => quote! { i64 },
+                        };
+
+                        Self::IntEnum { int_type, variants }
+                    }
+                    _ => Self::DataEnum,
+                }
+            }
+            Declaration::Struct(struct_) => match &struct_.fields {
+                StructFields::Named(named) => Self::Struct {
+                    fields: named
+                        .fields
+                        .iter()
+                        .map(|(field, _)| field.name.clone())
+                        .collect(),
+                },
+                _ => {
+                    return bail!(
+                        struct_.name,
+                        "{derive_name} can only be derived on structs with named fields"
+                    )
+                }
+            },
+            Declaration::Union(u) => {
+                return bail!(u.tk_union, "{derive_name} cannot be derived on unions")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(repr)
+    }
+}
+
 pub fn derive_var(decl: Declaration) -> ParseResult<TokenStream2> {
+    let repr = PropertyRepr::parse(&decl, "Var")?;
+
     let DeclInfo {
-        name, name_string, ..
+        where_,
+        generic_params,
+        name,
+        name_string,
     } = decl_get_info(&decl);
 
+    let intermediate;
     let body_get;
     let body_set;
-    let intermediate;
 
-    let enum_ = match decl {
-        Declaration::Enum(e) => e,
-        Declaration::Struct(s) => {
-            return bail!(s.tk_struct, "Property can only be derived on enums for now")
-        }
-        Declaration::Union(u) => {
-            return bail!(u.tk_union, "Property can only be derived on enums for now")
+    match repr {
+        PropertyRepr::IntEnum { int_type, variants } => {
+            intermediate = int_type;
+            body_get = quote! {
+                match &self {
+                    #(
+                        Self::#variants => Self::#variants as #intermediate,
+                    )*
+                }
+            };
+            body_set = quote! {
+                #(
+                    if value == Self::#variants as #intermediate {
+                        *self = Self::#variants;
+                        return;
+                    }
+                )*
+                panic!("Incorrect conversion from {} to {}", stringify!(#intermediate), #name_string);
+            };
         }
-        _ => unreachable!(),
-    };
+        PropertyRepr::StringEnum { variants } => {
+            let names = variants.iter().map(|variant| variant.to_string());
 
-    if enum_.variants.is_empty() {
-        return bail!(
-            enum_.name,
-            "In order to derive Property, enums must have at least one variant"
-        );
-    } else {
-        let mut matches_get = quote! {};
-        let mut matches_set = quote! {};
-        intermediate = if let Some(attr) = enum_
-            .attributes
-            .iter()
-            .find(|attr| attr.get_single_path_segment() == Some(&ident("repr")))
-        {
-            attr.value.to_token_stream()
-        } else {
-            return bail!(
-                name,
-                "Property can only be derived on enums with an explicit `#[repr(i*/u*)]` type"
-            );
-        };
+            intermediate = quote! { ::godot::builtin::GString };
+            body_get = quote! {
+                match &self {
+                    #(
+                        Self::#variants => ::godot::builtin::GString::from(#names),
+                    )*
+                }
+            };
 
-        for (enum_v, _) in enum_.variants.inner.iter() {
-            let v_name = enum_v.name.clone();
-            let v_disc = if let Some(c) = enum_v.value.clone() {
-                c.value
-            } else {
-                return bail!(
-                    v_name,
-                    "Property can only be derived on enums with explicit discriminants in all their variants"
-                );
+            let names = variants.iter().map(|variant| variant.to_string());
+            body_set = quote! {
+                *self = match value.to_string().as_str() {
+                    #(
+                        #names => Self::#variants,
+                    )*
+                    other => panic!("Incorrect conversion from \"{}\" to {}", other, #name_string),
+                };
             };
+        }
+        PropertyRepr::Struct { fields } => {
+            let keys = fields.iter().map(|field| field.to_string());
+            let keys_set = keys.clone();
 
-            let match_content_get;
-            let match_content_set;
-            match &enum_v.contents {
-                StructFields::Unit => {
-                    match_content_get = quote! {
-                        Self::#v_name => #v_disc,
-                    };
-                    match_content_set = quote! {
-                        #v_disc => Self::#v_name,
-                    };
-                }
-                _ => {
-                    return bail!(
-                        v_name,
-                        "Property can only be derived on enums with only unit variants for now"
-                    )
-                }
+            intermediate = quote! { ::godot::builtin::Dictionary };
+            body_get = quote! {
+                let mut dict = ::godot::builtin::Dictionary::new();
+                #(
+                    dict.set(
+                        #keys,
+                        ::godot::builtin::meta::ToGodot::to_variant(
+                            &::godot::register::property::Var::get_property(&self.#fields),
+                        ),
+                    );
+                )*
+                dict
+            };
+
+            // Keys that are missing in the dictionary, or whose values cannot be converted, leave the corresponding fields unchanged.
+            body_set = quote! {
+                #(
+                    if let Some(field_value) = value.get(#keys_set) {
+                        match ::godot::builtin::meta::FromGodot::try_from_variant(&field_value) {
+                            Ok(field_value) => ::godot::register::property::Var::set_property(
+                                &mut self.#fields,
+                                field_value,
+                            ),
+                            Err(err) => ::godot::log::godot_error!(
+                                "{}.{}: invalid property value: {}", #name_string, #keys_set, err
+                            ),
+                        }
+                    }
+                )*
             };
-            matches_get = quote! {
-                #matches_get
-                #match_content_get
+        }
+        PropertyRepr::DataEnum => {
+            intermediate = quote! { ::godot::builtin::Dictionary };
+            body_get = quote! {
+                ::godot::builtin::meta::ToGodot::to_variant(self).to::<::godot::builtin::Dictionary>()
             };
-            matches_set = quote! {
-                #matches_set
-                #match_content_set
+            // Values that don't match any variant leave the property unchanged.
+            body_set = quote! {
+                match ::godot::builtin::meta::FromGodot::try_from_variant(
+                    &::godot::builtin::meta::ToGodot::to_variant(&value),
+                ) {
+                    Ok(new_value) => *self = new_value,
+                    Err(err) => ::godot::log::godot_error!(
+                        "{}: invalid property value: {}", #name_string, err
+                    ),
+                }
             };
         }
-        body_get = quote! {
-            match &self {
-                #matches_get
-            }
-        };
-        body_set = quote! {
-            *self = match value {
-                #matches_set
-                _ => panic!("Incorrect conversion from {} to {}", stringify!(#intermediate), #name_string),
-            }
-        };
     }
 
+    let gen = generic_params.as_ref().map(|x| x.as_inline_args());
     let out = quote! {
         #[allow(unused_parens)]
-        impl godot::register::property::Var for #name {
+        impl #generic_params godot::register::property::Var for #name #gen #where_ {
             type Intermediate = #intermediate;
 
             fn get_property(&self) -> #intermediate {
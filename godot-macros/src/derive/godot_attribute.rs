@@ -0,0 +1,329 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Parsing and code generation for the `#[godot(...)]` attribute, which selects how a type is represented in Godot.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use venial::{Declaration, StructFields};
+
+use crate::util::{bail, DeclInfo, KvParser};
+use crate::ParseResult;
+
+/// Integer types allowed in `#[godot(via = ...)]`.
+const VIA_INT_TYPES: &[&str] = &["i8", "i16", "i32", "i64", "u8", "u16", "u32"];
+
+/// Representation of a type in Godot, selected with `#[godot(...)]`.
+///
+/// Types without this attribute are converted through a `Variant` holding a dictionary.
+pub enum GodotAttribute {
+    /// `#[godot(transparent)]`: newtype represented by its single field.
+    Transparent { field: NewtypeField },
+
+    /// `#[godot(via = ...)]`: fieldless enum represented by its discriminant or its variant name.
+    Via {
+        via_type: ViaType,
+        variants: Vec<Ident>,
+    },
+}
+
+/// The single field of a newtype struct.
+pub struct NewtypeField {
+    ty: venial::TyExpr,
+    /// `0` for tuple structs, the field name for structs with named fields.
+    member: TokenStream,
+}
+
+pub enum ViaType {
+    /// Enum converted to/from the integer value of its discriminant.
+    Int { int_ident: Ident },
+    /// Enum converted to/from the name of its variant.
+    GString,
+}
+
+impl GodotAttribute {
+    /// Parses the `#[godot(...)]` attribute of a type, returning `None` if there is no such attribute.
+    pub fn parse(decl: &Declaration) -> ParseResult<Option<Self>> {
+        let attributes = match decl {
+            Declaration::Struct(struct_) => &struct_.attributes,
+            Declaration::Enum(enum_) => &enum_.attributes,
+            _ => return Ok(None),
+        };
+
+        let Some(mut parser) = KvParser::parse(attributes, "godot")? else {
+            return Ok(None);
+        };
+
+        let result = if let Some(span) = parser.handle_alone_ident("transparent")? {
+            let field = NewtypeField::parse(decl, &span)?;
+            Self::Transparent { field }
+        } else if let Some(via_ident) = parser.handle_ident("via")? {
+            let via_type = ViaType::parse(via_ident)?;
+            let variants = parse_fieldless_variants(decl)?;
+            Self::Via { via_type, variants }
+        } else {
+            return bail!(
+                parser.span(),
+                "#[godot] requires either `transparent` or `via = ...`"
+            );
+        };
+
+        parser.finish()?;
+        Ok(Some(result))
+    }
+
+    /// Generates the body of the `GodotConvert` impl.
+    pub fn make_godot_convert(&self) -> TokenStream {
+        let via = self.via_type_tokens();
+
+        quote! {
+            type Via = #via;
+        }
+    }
+
+    /// Generates compile-time checks that every discriminant fits into the `via` integer type, which `as` would silently truncate.
+    pub fn make_discriminant_checks(&self, decl_info: &DeclInfo) -> TokenStream {
+        let Self::Via {
+            via_type: ViaType::Int { int_ident },
+            variants,
+        } = self
+        else {
+            return TokenStream::new();
+        };
+
+        let name = &decl_info.name;
+        let checks = variants.iter().map(|variant| {
+            let err = format!(
+                "discriminant of `{}::{variant}` does not fit into `{int_ident}`, as required by #[godot(via = {int_ident})]",
+                decl_info.name_string
+            );
+
+            quote_spanned! { variant.span() =>
+                assert!(
+                    #name::#variant as i128 >= #int_ident::MIN as i128
+                        && #name::#variant as i128 <= #int_ident::MAX as i128,
+                    #err
+                );
+            }
+        });
+
+        quote! {
+            const _: () = {
+                #( #checks )*
+            };
+        }
+    }
+
+    /// Generates the body of the `ToGodot` impl.
+    pub fn make_to_godot(&self) -> TokenStream {
+        let via = self.via_type_tokens();
+
+        match self {
+            Self::Transparent { field } => {
+                let member = &field.member;
+
+                quote! {
+                    fn to_godot(&self) -> #via {
+                        ::godot::builtin::meta::ToGodot::to_godot(&self.#member)
+                    }
+
+                    fn into_godot(self) -> #via {
+                        ::godot::builtin::meta::ToGodot::into_godot(self.#member)
+                    }
+                }
+            }
+            Self::Via {
+                via_type: ViaType::Int { .. },
+                variants,
+            } => quote! {
+                #[allow(unreachable_code)]
+                fn to_godot(&self) -> #via {
+                    match self {
+                        #(
+                            Self::#variants => Self::#variants as #via,
+                        )*
+                    }
+                }
+            },
+            Self::Via {
+                via_type: ViaType::GString,
+                variants,
+            } => {
+                let names = variants.iter().map(|variant| variant.to_string());
+
+                quote! {
+                    #[allow(unreachable_code)]
+                    fn to_godot(&self) -> #via {
+                        match self {
+                            #(
+                                Self::#variants => ::godot::builtin::GString::from(#names),
+                            )*
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generates the body of the `FromGodot` impl.
+    pub fn make_from_godot(&self, decl_info: &DeclInfo) -> TokenStream {
+        let via = self.via_type_tokens();
+
+        match self {
+            Self::Transparent { field } => {
+                let member = &field.member;
+                let ty = &field.ty;
+
+                quote! {
+                    fn try_from_godot(via: #via) -> Result<Self, ::godot::builtin::meta::ConvertError> {
+                        let inner = <#ty as ::godot::builtin::meta::FromGodot>::try_from_godot(via)?;
+                        Ok(Self { #member: inner })
+                    }
+                }
+            }
+            Self::Via {
+                via_type: ViaType::Int { .. },
+                variants,
+            } => {
+                let err = format!(
+                    "integer does not match any discriminant of enum {}",
+                    decl_info.name_string
+                );
+
+                quote! {
+                    fn try_from_godot(via: #via) -> Result<Self, ::godot::builtin::meta::ConvertError> {
+                        #(
+                            if via == Self::#variants as #via {
+                                return Ok(Self::#variants);
+                            }
+                        )*
+
+                        Err(::godot::builtin::meta::ConvertError::with_cause_value(#err, via))
+                    }
+                }
+            }
+            Self::Via {
+                via_type: ViaType::GString,
+                variants,
+            } => {
+                let names = variants.iter().map(|variant| variant.to_string());
+                let err = format!(
+                    "string does not match any variant name of enum {}",
+                    decl_info.name_string
+                );
+
+                quote! {
+                    fn try_from_godot(via: #via) -> Result<Self, ::godot::builtin::meta::ConvertError> {
+                        match via.to_string().as_str() {
+                            #(
+                                #names => Ok(Self::#variants),
+                            )*
+                            _ => Err(::godot::builtin::meta::ConvertError::with_cause_value(#err, via)),
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn via_type_tokens(&self) -> TokenStream {
+        match self {
+            Self::Transparent { field } => {
+                let ty = &field.ty;
+                quote! { <#ty as ::godot::builtin::meta::GodotConvert>::Via }
+            }
+            Self::Via {
+                via_type: ViaType::Int { int_ident },
+                ..
+            } => int_ident.to_token_stream(),
+            Self::Via {
+                via_type: ViaType::GString,
+                ..
+            } => quote! { ::godot::builtin::GString },
+        }
+    }
+}
+
+impl NewtypeField {
+    fn parse(decl: &Declaration, span: &Ident) -> ParseResult<Self> {
+        let fields = match decl {
+            Declaration::Struct(struct_) => &struct_.fields,
+            _ => return bail!(span, "#[godot(transparent)] can only be used on structs"),
+        };
+
+        let field = match fields {
+            StructFields::Tuple(tuple) if tuple.fields.len() == 1 => {
+                let (field, _) = tuple.fields.first().unwrap();
+                Self {
+                    ty: field.ty.clone(),
+                    member: quote! { 0 },
+                }
+            }
+            StructFields::Named(named) if named.fields.len() == 1 => {
+                let (field, _) = named.fields.first().unwrap();
+                Self {
+                    ty: field.ty.clone(),
+                    member: field.name.to_token_stream(),
+                }
+            }
+            _ => {
+                return bail!(
+                    span,
+                    "#[godot(transparent)] requires a struct with exactly one field"
+                )
+            }
+        };
+
+        Ok(field)
+    }
+}
+
+impl ViaType {
+    fn parse(ident: Ident) -> ParseResult<Self> {
+        let name = ident.to_string();
+
+        if name == "GString" {
+            Ok(Self::GString)
+        } else if VIA_INT_TYPES.contains(&name.as_str()) {
+            Ok(Self::Int { int_ident: ident })
+        } else {
+            bail!(
+                ident,
+                "#[godot(via = ...)] expects `GString` or one of the integer types {}",
+                VIA_INT_TYPES.join(", ")
+            )
+        }
+    }
+}
+
+/// Returns the variant names of an enum, ensuring that none of them has fields.
+fn parse_fieldless_variants(decl: &Declaration) -> ParseResult<Vec<Ident>> {
+    let enum_ = match decl {
+        Declaration::Enum(enum_) => enum_,
+        Declaration::Struct(struct_) => {
+            return bail!(
+                &struct_.name,
+                "#[godot(via = ...)] requires an enum; use #[godot(transparent)] for newtypes"
+            )
+        }
+        _ => unreachable!(),
+    };
+
+    let mut variants = Vec::new();
+    for (variant, _) in enum_.variants.iter() {
+        if !matches!(variant.contents, StructFields::Unit) {
+            return bail!(
+                &variant.name,
+                "#[godot(via = ...)] requires an enum whose variants have no fields"
+            );
+        }
+
+        variants.push(variant.name.clone());
+    }
+
+    Ok(variants)
+}
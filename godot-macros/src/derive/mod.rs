@@ -12,9 +12,13 @@ mod derive_from_variant;
 mod derive_godot_convert;
 mod derive_to_variant;
 mod derive_var;
+mod godot_attribute;
+mod variant_attribute;
 
 pub(crate) use derive_export::*;
 pub(crate) use derive_from_variant::*;
 pub(crate) use derive_godot_convert::*;
 pub(crate) use derive_to_variant::*;
 pub(crate) use derive_var::*;
+pub(crate) use godot_attribute::*;
+pub(crate) use variant_attribute::*;
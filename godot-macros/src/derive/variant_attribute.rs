@@ -0,0 +1,100 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Parsing of the `#[variant(...)]` attribute, which customizes the dictionary layout of the `ToGodot`/`FromGodot` derives.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+/// Options of a field or an enum variant.
+#[derive(Default)]
+pub struct VariantAttribute {
+    /// `#[variant(skip)]`: not converted, replaced by `Default::default()` when converting from Godot.
+    pub skip: bool,
+
+    /// `#[variant(rename = "key")]`: key (for fields) or name (for enum variants) used in Godot.
+    pub rename: Option<String>,
+
+    /// `#[variant(default)]` or `#[variant(default = expr)]`: value used for a field whose key is missing.
+    pub default: Option<TokenStream>,
+
+    /// `#[variant(flatten)]`: the field's dictionary entries are merged into the surrounding dictionary.
+    pub flatten: bool,
+}
+
+impl VariantAttribute {
+    pub fn parse(attributes: &[venial::Attribute]) -> ParseResult<Self> {
+        let Some(mut parser) = KvParser::parse(attributes, "variant")? else {
+            return Ok(Self::default());
+        };
+
+        let skip = parser.handle_alone("skip")?;
+        let rename = parser.handle_string("rename")?;
+
+        let default = match parser.handle_any("default") {
+            None => None,
+            Some(None) => Some(quote! { ::std::default::Default::default() }),
+            Some(Some(value)) => Some(value.expr()?),
+        };
+
+        let flatten = parser.handle_alone("flatten")?;
+        parser.finish()?;
+
+        Ok(Self {
+            skip,
+            rename,
+            default,
+            flatten,
+        })
+    }
+
+    /// Name under which a field or variant appears in Godot.
+    pub fn godot_name(&self, rust_name: &impl ToString) -> String {
+        self.rename.clone().unwrap_or_else(|| rust_name.to_string())
+    }
+}
+
+/// How enum variants are distinguished in the Godot representation, declared on the enum itself.
+pub enum EnumTagging {
+    /// Default: `{ "Variant": payload }`, or just `"Variant"` for unit variants.
+    External,
+
+    /// `#[variant(tag = "key")]`: the variant name is stored under `key`, next to the fields of the variant.
+    Internal { tag: String },
+
+    /// `#[variant(untagged)]`: only the payload is stored; the first variant that can be converted from it is chosen.
+    Untagged,
+}
+
+impl EnumTagging {
+    pub fn parse(attributes: &[venial::Attribute]) -> ParseResult<Self> {
+        let Some(mut parser) = KvParser::parse(attributes, "variant")? else {
+            return Ok(Self::External);
+        };
+
+        let tag = parser.handle_string("tag")?;
+        let untagged = parser.handle_alone_ident("untagged")?;
+
+        let tagging = match (tag, untagged) {
+            (Some(_), Some(untagged)) => {
+                return bail!(
+                    untagged,
+                    "#[variant]: keys `tag` and `untagged` are mutually exclusive"
+                )
+            }
+            (Some(tag), None) => Self::Internal { tag },
+            (None, Some(_)) => Self::Untagged,
+            (None, None) => Self::External,
+        };
+
+        parser.finish()?;
+        Ok(tagging)
+    }
+}
@@ -6,6 +6,7 @@
  */
 
 mod bench;
+mod callable;
 mod class;
 mod derive;
 mod gdextension;
@@ -71,6 +72,25 @@ use crate::util::ident;
 /// # }
 /// ```
 ///
+/// Fields of type `OnReady<Gd<T>>` can instead be assigned a node from the scene tree with `#[init(node = "path")]`.
+/// The path is resolved relative to the base node, right before `ready()` is called. This requires a `#[base]` field.
+/// If there is no node at that path, or it is not of type `T`, a panic message names the class, field and path.
+///
+/// ```
+/// use godot::prelude::*;
+/// use godot::engine::Sprite2D;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base = Node2D)]
+/// struct Player {
+///     #[init(node = "Body/Sprite")]
+///     sprite: OnReady<Gd<Sprite2D>>,
+///
+///     #[base]
+///     base: Base<Node2D>,
+/// }
+/// ```
+///
 /// # Inheritance
 ///
 /// Unlike C++, Rust doesn't really have inheritance, but the GDExtension API lets us "inherit"
@@ -287,19 +307,35 @@ use crate::util::ident;
 ///
 /// # Signals
 ///
-/// The `#[signal]` attribute is quite limited at the moment and can only be used for parameter-less signals.
-/// It will be fundamentally reworked.
+/// Signals are declared as bodyless functions annotated with `#[signal]` inside the `#[godot_api]` block. Their parameters
+/// must implement `ToGodot` and `FromGodot`.
+///
+/// For every signal, `#[godot_api]` generates a type-safe accessor, reachable through `signals()`. This is available on `Gd<T>`
+/// and, if the class has a `#[base]` field, on `self`. The accessor returns a [`TypedSignal`](../obj/struct.TypedSignal.html),
+/// which checks argument types at compile time when emitting or connecting. It has the same visibility as the `#[signal]` declaration.
+///
+/// On `self`, `signals()` requires `&mut self` and holds a `base_mut()` guard until the returned signal is dropped. Connected
+/// receivers can therefore call back into the emitting object, e.g. through its `#[func]` methods.
 ///
 /// ```no_run
 /// use godot::prelude::*;
 ///
 /// #[derive(GodotClass)]
-/// struct MyClass {}
+/// #[class(init, base = Node)]
+/// struct MyClass {
+///     #[base]
+///     base: Base<Node>,
+/// }
 ///
 /// #[godot_api]
 /// impl MyClass {
 ///     #[signal]
-///     fn some_signal();
+///     fn hit(damage: i32);
+///
+///     #[func]
+///     fn take_damage(&mut self, damage: i32) {
+///         self.signals().hit().emit(damage);
+///     }
 /// }
 /// ```
 ///
@@ -426,6 +462,141 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// Note that `init` can be either provided by overriding it, or generated with a `#[class(init)]` attribute on the struct.
 /// Classes without `init` cannot be instantiated from GDScript.
 ///
+/// ## Default parameters
+///
+/// Trailing parameters of a `#[func]` can declare default values with `#[opt(default = ...)]`. Callers such as GDScript may then
+/// omit the corresponding arguments. The expression must evaluate to the parameter's type, and cannot refer to `Self`.
+///
+/// ```no_run
+///# use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Spawner;
+///
+/// #[godot_api]
+/// impl Spawner {
+///     #[func]
+///     fn spawn(&mut self, position: Vector2, #[opt(default = 1)] count: i32) {
+///         // GDScript: spawner.spawn(Vector2(1, 2))
+///     }
+/// }
+/// ```
+///
+/// ## Variadic functions
+///
+/// A `#[func(vararg)]` accepts any number of arguments of any type, which are passed as a single `&[Variant]` parameter.
+///
+/// ```no_run
+///# use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Logger;
+///
+/// #[godot_api]
+/// impl Logger {
+///     #[func(vararg)]
+///     fn log(&self, args: &[Variant]) {
+///         // GDScript: logger.log("hit", 3, Vector2.ZERO)
+///         godot_print!("{args:?}");
+///     }
+/// }
+/// ```
+///
+/// ## Returning `Result`
+///
+/// A `#[func]` may return `Result<T, E>` if `E` implements `Display`. `Ok` values are passed to Godot as usual. On `Err`, a script
+/// error with the error's message is printed, and Godot receives a fallback value: `null` for objects, `Error.FAILED` for
+/// `Result<(), E>` (`Error.OK` on success), and the default value for other types.
+/// See `godot::builtin::meta::FuncResultOk` for details.
+///
+/// The fallback can be changed per method with `#[func(error_fallback = expr)]`, where `expr` has the type seen by Godot. For
+/// example, `#[func(error_fallback = global::Error::ERR_FILE_NOT_FOUND)]` on a method returning `Result<(), E>`, or
+/// `#[func(error_fallback = -1)]` for `Result<i64, E>`. The key is only allowed on methods returning `Result`.
+///
+/// ```no_run
+///# use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Inventory;
+///
+/// #[godot_api]
+/// impl Inventory {
+///     #[func]
+///     fn parse_count(&self, text: GString) -> Result<i64, std::num::ParseIntError> {
+///         // GDScript receives 0 and an error is logged, if the text is not a number.
+///         text.to_string().parse()
+///     }
+/// }
+/// ```
+///
+/// ## Constants
+///
+/// Associated constants annotated with `#[constant]` are registered with Godot. Godot only supports integer constants, so the
+/// type must be convertible to `i64`; GDScript then reads them as `Class.MAX_SPEED`.
+///
+/// Constants of other types, such as floats or strings, need `#[constant(getter)]`. This registers a static method of the
+/// same name, which GDScript calls as `Class.GRAVITY()` rather than accessing a constant. The type must implement `ToGodot`.
+///
+/// ```no_run
+///# use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct World;
+///
+/// #[godot_api]
+/// impl World {
+///     // GDScript: World.MAX_SPEED
+///     #[constant]
+///     const MAX_SPEED: i32 = 400;
+///
+///     // GDScript: World.GRAVITY()
+///     #[constant(getter)]
+///     const GRAVITY: f32 = 9.8;
+/// }
+/// ```
+///
+/// ## Remote procedure calls
+///
+/// Methods of classes inheriting `Node` can be declared as RPCs with `#[rpc]`, which implies `#[func]` and can also be combined
+/// with it. The configuration is applied to every instance before `ready()`, so there is no need to call `Node::rpc_config()`. The
+/// class needs a `#[base]` field.
+///
+/// Keys correspond to GDScript's `@rpc` annotation; omitted ones take the same defaults:
+/// * `any_peer` or `authority`
+/// * `reliable`, `unreliable` or `unreliable_ordered`
+/// * `call_local` or `call_remote`
+/// * `channel = expr`
+///
+/// With the additional key `helpers`, typed methods `rpc_<method>(args...)` and `rpc_id_<method>(peer_id, args...)` are generated,
+/// which forward to `Node::rpc()` and `Node::rpc_id()`.
+///
+/// ```no_run
+///# use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[rpc(any_peer, reliable, call_local, channel = 1, helpers)]
+///     fn jump(&mut self, height: f32) {}
+///
+///     #[func]
+///     fn on_jump_pressed(&mut self) {
+///         // Calls jump(2.5) on all peers.
+///         self.rpc_jump(2.5);
+///     }
+/// }
+/// ```
+///
 /// ## `Node` as a base, generated `init`
 ///
 /// ```no_run
@@ -450,8 +621,93 @@ pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
     translate(input, class::attribute_godot_api)
 }
 
+/// Compile-time checked reference to a `#[func]` method.
+///
+/// * `callable!(MyClass::method)` evaluates to a [`FuncRef`](../obj/struct.FuncRef.html), which knows the method's Godot name
+///   (taking `#[func(rename = ...)]` into account) and its parameter types.
+/// * `callable!(object, MyClass::method)` evaluates to a `Callable` invoking the method on `object`, a `Gd<T>` pointer to an instance
+///   of `MyClass` or a derived class.
+///
+/// Compilation fails if `method` doesn't exist or isn't a `#[func]` of `MyClass`. Methods declared with `#[func(vararg)]` are not
+/// supported, as their parameters are not known statically.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Enemy {
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Enemy {
+///     #[signal]
+///     fn hit(damage: i64);
+///
+///     #[func(rename = handle_hit)]
+///     fn on_hit(&mut self, damage: i64) {}
+///
+///     #[func]
+///     fn wire_up(&mut self) {
+///         let gd = self.to_gd();
+///         let callable = callable!(gd, Enemy::on_hit);
+///         assert_eq!(callable.method_name(), Some("handle_hit".into()));
+///
+///         // Only compiles if the parameters of `on_hit` match the signal's.
+///         self.signals().hit().connect_func(&gd, callable!(Enemy::on_hit));
+///     }
+/// }
+/// ```
+#[proc_macro]
+pub fn callable(input: TokenStream) -> TokenStream {
+    let result2 =
+        callable::callable(TokenStream2::from(input)).unwrap_or_else(|e| e.to_compile_error());
+
+    TokenStream::from(result2)
+}
+
 /// Derive macro for [`GodotConvert`](../builtin/meta/trait.GodotConvert.html) on structs (required by [`ToGodot`] and [`FromGodot`]).
-#[proc_macro_derive(GodotConvert)]
+///
+/// By default, the type is represented in Godot as a `Variant` holding a dictionary. The `#[godot]` attribute selects a more
+/// direct representation, which is also respected by the `ToGodot` and `FromGodot` derives:
+///
+/// - `#[godot(transparent)]` on a struct with exactly one field represents the struct as that field, i.e. `Via` is the
+///   field's own `Via` type.
+/// - `#[godot(via = i64)]` on an enum without fields represents each variant by its discriminant. Any of the integer types
+///   `i8`, `i16`, `i32`, `i64`, `u8`, `u16` and `u32` can be used. Compilation fails if a discriminant is out of its range.
+/// - `#[godot(via = GString)]` on an enum without fields represents each variant by its name.
+///
+/// Such types can be passed to and from Godot without going through a `Variant`, e.g. in `#[func]` signatures.
+///
+/// # Example
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotConvert, ToGodot, FromGodot, PartialEq, Debug)]
+/// #[godot(transparent)]
+/// struct Health(i32);
+///
+/// #[derive(GodotConvert, ToGodot, FromGodot, PartialEq, Debug)]
+/// #[godot(via = i64)]
+/// enum Faction {
+///     Player = 1,
+///     Enemy = 2,
+/// }
+///
+/// #[derive(GodotConvert, ToGodot, FromGodot, PartialEq, Debug)]
+/// #[godot(via = GString)]
+/// enum Weather {
+///     Sunny,
+///     Rainy,
+/// }
+///
+/// assert_eq!(Health(100).to_godot(), 100);
+/// assert_eq!(Faction::Enemy.to_godot(), 2);
+/// assert_eq!(Weather::Rainy.to_godot(), GString::from("Rainy"));
+/// assert_eq!(Faction::from_godot(1), Faction::Player);
+/// ```
+#[proc_macro_derive(GodotConvert, attributes(godot))]
 pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_godot_convert)
 }
@@ -484,7 +740,40 @@ pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
 /// ```
 ///
 /// You can use the `#[skip]` attribute to ignore a field from being converted to `ToGodot`.
-#[proc_macro_derive(ToGodot, attributes(variant))]
+///
+/// # Customizing the layout
+///
+/// The `#[variant(...)]` attribute accepts the following keys:
+///
+/// - On fields:
+///   - `rename = "key"`: use `key` instead of the field name as the dictionary key.
+///   - `flatten`: merge the entries of the field into the surrounding dictionary. The field must either convert to a `Dictionary`
+///     (e.g. `HashMap` or `TypedDictionary`), or be a struct with named fields that derives `ToGodot` itself; the latter contributes
+///     its fields without the `{ "Name": ... }` wrapper. Other types are rejected at compile time.
+///   - `default` or `default = expr`: only relevant for [`FromGodot`].
+/// - On enum variants:
+///   - `rename = "Name"`: use `Name` instead of the variant name.
+/// - On enums:
+///   - `tag = "key"`: store the variant name under `key`, next to the fields of the variant (internal tagging).
+///     Only unit variants and variants with named fields are supported.
+///   - `untagged`: store only the payload of the variant; unit variants become `nil`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(FromGodot, ToGodot, GodotConvert)]
+/// #[variant(tag = "type")]
+/// enum Shape {
+///     Circle { radius: f32 },
+///     #[variant(rename = "rect")]
+///     Rectangle { width: f32, height: f32 },
+/// }
+///
+/// // Converts to { "Shape": { "type": "Circle", "radius": 2.0 } }.
+/// let variant = Shape::Circle { radius: 2.0 }.to_variant();
+/// ```
+///
+/// If the type has a `#[godot(...)]` attribute, the conversion follows the representation selected there, see [`GodotConvert`].
+#[proc_macro_derive(ToGodot, attributes(variant, godot))]
 pub fn derive_to_godot(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_to_godot)
 }
@@ -518,19 +807,31 @@ pub fn derive_to_godot(input: TokenStream) -> TokenStream {
 ///
 /// You can use the skip attribute to ignore a field from the provided variant and use `Default::default()`
 /// to get it instead.
-#[proc_macro_derive(FromGodot, attributes(variant))]
+///
+/// The `#[variant(...)]` keys described in [`ToGodot`] are honored in the reverse direction. In addition, a field annotated with
+/// `#[variant(default)]` or `#[variant(default = expr)]` is set to `Default::default()` or `expr` if its key is missing.
+/// A `#[variant(flatten)]` field receives all entries that are not consumed by other fields. Nested structs with named fields must
+/// derive `FromGodot` themselves, and read their fields directly from these entries.
+///
+/// Untagged enums try each variant in declaration order and pick the first one that converts successfully.
+///
+/// If the type has a `#[godot(...)]` attribute, the conversion follows the representation selected there, see [`GodotConvert`].
+#[proc_macro_derive(FromGodot, attributes(variant, godot))]
 pub fn derive_from_godot(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_from_godot)
 }
 
-/// Derive macro for [`Var`](../bind/property/trait.Var.html) on enums.
-///
-/// Currently has some tight requirements which are expected to be softened as implementation expands:
-/// - Only works for enums, structs aren't supported by this derive macro at the moment.
-/// - The enum must have an explicit `#[repr(u*/i*)]` type.
-///     - This will likely stay this way, since `isize`, the default repr type, is not a concept in Godot.
-/// - The enum variants must not have any fields - currently only unit variants are supported.
-/// - The enum variants must have explicit discriminants, that is, e.g. `A = 2`, not just `A`
+/// Derive macro for [`Var`](../bind/property/trait.Var.html) on enums and structs.
+///
+/// The property representation depends on the type:
+/// - Enums without fields are stored as the integer value of their discriminant. The integer type is taken from
+///   `#[godot(via = ...)]` or `#[repr(...)]`, and defaults to `i64`. Discriminants may be implicit.
+/// - Enums without fields and with `#[godot(via = GString)]` are stored as the name of their variant.
+/// - Structs with named fields are stored as a `Dictionary` mapping each field name to the property value of that field.
+///   Every field must implement `Var`. Keys missing from an assigned dictionary leave the corresponding field unchanged, as do
+///   values of the wrong type, which are additionally reported as Godot errors.
+/// - Enums with fields are stored as the `Dictionary` produced by their [`ToGodot`] implementation, so they must also
+///   derive [`ToGodot`] and [`FromGodot`]. Assigning a dictionary that doesn't convert reports an error and keeps the old value.
 ///
 /// # Example
 ///
@@ -558,15 +859,18 @@ pub fn derive_from_godot(input: TokenStream) -> TokenStream {
 ///     assert_eq!(class.foo, MyEnum::A);
 /// }
 /// ```
-#[proc_macro_derive(Var)]
+#[proc_macro_derive(Var, attributes(godot))]
 pub fn derive_property(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_var)
 }
 
-/// Derive macro for [`Export`](../bind/property/trait.Export.html) on enums.
+/// Derive macro for [`Export`](../bind/property/trait.Export.html) on enums and structs.
 ///
-/// Currently has some tight requirements which are expected to be softened as implementation expands, see requirements for [`Var`].
-#[proc_macro_derive(Export)]
+/// Supports the same types as [`Var`]:
+/// - Enums without fields are exported with `PROPERTY_HINT_ENUM`, so the inspector shows a drop-down of the variant names.
+///   Integer-backed enums use the hint string `"A:0,B:1"`, string-backed ones `"A,B"`.
+/// - Structs and enums with fields are exported as a `Dictionary`.
+#[proc_macro_derive(Export, attributes(godot))]
 pub fn derive_export(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_export)
 }
@@ -6,7 +6,7 @@
  */
 
 use crate::ParseResult;
-use proc_macro2::{Delimiter, Ident, Spacing, Span, TokenStream, TokenTree};
+use proc_macro2::{Delimiter, Ident, Literal, Spacing, Span, TokenStream, TokenTree};
 use quote::ToTokens;
 use std::collections::HashMap;
 use venial::Attribute;
@@ -162,6 +162,24 @@ impl KvParser {
         Ok(Some(int))
     }
 
+    /// Handles an optional key that can only occur with a string literal as the value. Escape sequences are resolved, and raw
+    /// strings are supported.
+    pub fn handle_string(&mut self, key: &str) -> ParseResult<Option<String>> {
+        let Some(expr) = self.handle_expr(key)? else {
+            return Ok(None);
+        };
+
+        let mut tokens = expr.into_iter();
+        match (tokens.next(), tokens.next()) {
+            (Some(TokenTree::Literal(lit)), None) => match string_literal_value(&lit) {
+                Some(value) => Ok(Some(value)),
+                None => bail!(lit, "value for `{key}` must be a string literal"),
+            },
+            (Some(tt), _) => bail!(tt, "value for `{key}` must be a string literal"),
+            (None, _) => bail!(key, "missing value for `{key}` (must be string literal)"),
+        }
+    }
+
     /// Handles a key that must be provided and must have an identifier as the value.
     pub fn handle_ident_required(&mut self, key: &str) -> ParseResult<Ident> {
         self.handle_ident(key)?
@@ -388,6 +406,74 @@ impl<'a> ParserState<'a> {
     }
 }
 
+/// Returns the value of a (possibly raw) string literal, or `None` if `lit` is any other literal.
+fn string_literal_value(lit: &Literal) -> Option<String> {
+    let repr = lit.to_string();
+
+    if let Some(raw) = repr.strip_prefix('r') {
+        let hashes = &raw[..raw.len() - raw.trim_start_matches('#').len()];
+        let inner = raw[hashes.len()..]
+            .strip_prefix('"')?
+            .strip_suffix(hashes)?
+            .strip_suffix('"')?;
+
+        return Some(inner.to_string());
+    }
+
+    // Rejects byte and C strings as well as literals with suffix.
+    let inner = repr.strip_prefix('"')?.strip_suffix('"')?;
+    unescape(inner)
+}
+
+/// Resolves the escape sequences of a Rust string literal's content.
+fn unescape(escaped: &str) -> Option<String> {
+    let mut result = String::with_capacity(escaped.len());
+    let mut chars = escaped.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        let unescaped = match chars.next()? {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '0' => '\0',
+            '\\' => '\\',
+            '\'' => '\'',
+            '"' => '"',
+            'x' => {
+                let hex: String = chars.by_ref().take(2).collect();
+                let byte = u8::from_str_radix(&hex, 16).ok().filter(u8::is_ascii)?;
+                char::from(byte)
+            }
+            'u' => {
+                if chars.next()? != '{' {
+                    return None;
+                }
+                let hex: String = chars
+                    .by_ref()
+                    .take_while(|&c| c != '}')
+                    .filter(|&c| c != '_')
+                    .collect();
+                char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?
+            }
+            // Line continuation: skips the line break and leading whitespace of the next line.
+            '\n' | '\r' => {
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+                continue;
+            }
+            _ => return None,
+        };
+
+        result.push(unescaped);
+    }
+
+    Some(result)
+}
+
 pub(crate) fn has_attr(attributes: &[Attribute], expected: &str, key: &str) -> bool {
     match KvParser::parse(attributes, expected) {
         Ok(Some(mut kvp)) => kvp.handle_alone(key).unwrap(),
@@ -560,4 +646,36 @@ mod tests {
             ),
         );
     }
+
+    fn string_value(lit: TokenStream) -> Option<String> {
+        match lit.into_iter().next() {
+            Some(TokenTree::Literal(lit)) => string_literal_value(&lit),
+            _ => panic!("expected literal"),
+        }
+    }
+
+    #[test]
+    fn test_string_literal_value() {
+        assert_eq!(string_value(quote! { "plain" }), Some("plain".to_string()));
+        assert_eq!(
+            string_value(quote! { "quote\" tab\t \\ \x41 \u{1F600}" }),
+            Some("quote\" tab\t \\ A \u{1F600}".to_string())
+        );
+        assert_eq!(
+            string_value(quote! { r"raw \n" }),
+            Some("raw \\n".to_string())
+        );
+        assert_eq!(
+            string_value(quote! { r#"raw "quoted""# }),
+            Some("raw \"quoted\"".to_string())
+        );
+    }
+
+    #[test]
+    fn test_string_literal_value_rejects_non_strings() {
+        assert_eq!(string_value(quote! { 42 }), None);
+        assert_eq!(string_value(quote! { 'c' }), None);
+        assert_eq!(string_value(quote! { b"bytes" }), None);
+        assert_eq!(string_value(quote! { br"bytes" }), None);
+    }
 }
@@ -202,8 +202,11 @@ pub mod init {
 
 /// Register/export Rust symbols to Godot: classes, methods, enums...
 pub mod register {
+    pub use godot_core::builder;
     pub use godot_core::property;
-    pub use godot_macros::{godot_api, Export, FromGodot, GodotClass, GodotConvert, ToGodot, Var};
+    pub use godot_macros::{
+        callable, godot_api, Export, FromGodot, GodotClass, GodotConvert, ToGodot, Var,
+    };
 }
 
 /// Renamed to [`register`] module.
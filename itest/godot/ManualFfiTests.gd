@@ -345,3 +345,21 @@ func test_callable_refcount():
 	var method := Callable(self, "sample_func")
 	assert(method.is_valid())
 	test_obj.free()
+
+func test_func_default_params_varcall():
+	var obj = FuncDefaultParams.new()
+	assert_eq(obj.spawn(5), "5:1:orc")
+	assert_eq(obj.spawn(5, 3), "5:3:orc")
+	assert_eq(obj.spawn(5, 3, "elf"), "5:3:elf")
+
+func test_func_default_params_ptrcall():
+	var obj: FuncDefaultParams = FuncDefaultParams.new()
+	assert_eq(obj.spawn(5), "5:1:orc")
+	assert_eq(obj.spawn(5, 3), "5:3:orc")
+	assert_eq(obj.spawn(5, 3, "elf"), "5:3:elf")
+
+func test_func_vararg():
+	var obj := FuncVarargs.new()
+	assert_eq(obj.sum(), 0)
+	assert_eq(obj.sum(1, 2, 3), 6)
+	assert_eq(FuncVarargs.count_args("a", Vector2.ZERO), 2)
@@ -6,6 +6,7 @@
  */
 
 use crate::framework::itest;
+use godot::builtin::math::assert_eq_approx;
 use godot::builtin::{Color, ColorChannelOrder};
 
 #[itest]
@@ -76,6 +77,50 @@ fn color_from_string() {
     assert_eq!(Color::from_string("octarine"), None); // Sorry, Rincewind.
 }
 
+#[itest]
+fn color_from_name_matches_godot() {
+    let names = [
+        "ALICE_BLUE",
+        "cornflower_blue",
+        "Light Goldenrod",
+        "gray",
+        "web-gray",
+        "maroon",
+        "web_maroon",
+        "purple",
+        "web_purple",
+        "rebecca_purple",
+        "transparent",
+        "yellow_green",
+    ];
+
+    for name in names {
+        assert_eq!(
+            Color::from_name(name),
+            Color::from_string(name),
+            "named color {name:?}"
+        );
+    }
+
+    assert_eq!(Color::from_name("octarine"), None);
+}
+
+#[itest]
+fn color_hsv_roundtrip() {
+    let colors = [
+        Color::CORNFLOWER_BLUE,
+        Color::DARK_ORANGE,
+        Color::MEDIUM_VIOLET_RED,
+        Color::WEB_GRAY,
+        Color::BLACK,
+    ];
+
+    for color in colors {
+        let (h, s, v) = color.to_hsv();
+        assert_eq_approx!(Color::from_hsv(h, s, v), color);
+    }
+}
+
 #[itest]
 fn color_get_set_u8() {
     let mut c = Color::default();
@@ -8,7 +8,7 @@
 use std::collections::{HashMap, HashSet};
 
 use godot::builtin::meta::{FromGodot, ToGodot};
-use godot::builtin::{dict, varray, Dictionary, Variant};
+use godot::builtin::{dict, varray, Dictionary, GString, TypedDictionary, Variant};
 use godot::sys::GdextBuild;
 
 use crate::framework::{expect_panic, itest};
@@ -628,3 +628,60 @@ fn dictionary_should_format_with_display() {
     };
     assert_eq!(format!("{d}"), "{ one: 1, two: true, three: <null> }")
 }
+
+#[itest]
+fn typed_dictionary_access() {
+    let mut dict = TypedDictionary::<GString, i64>::new();
+    assert!(dict.is_empty());
+
+    assert_eq!(dict.insert("a".into(), 1), None);
+    assert_eq!(dict.insert("a".into(), 2), Some(1));
+    dict.set("b".into(), 3);
+
+    assert_eq!(dict.len(), 2);
+    assert_eq!(dict.get("a".into()), Some(2));
+    assert_eq!(dict.get("c".into()), None);
+    assert!(dict.contains_key("b".into()));
+
+    assert_eq!(dict.remove("b".into()), Some(3));
+    assert_eq!(dict.remove("b".into()), None);
+
+    let entries: Vec<(GString, i64)> = dict.iter_shared().collect();
+    assert_eq!(entries, vec![("a".into(), 2)]);
+}
+
+#[itest]
+fn typed_dictionary_from_untyped() {
+    let untyped = dict! { "a": 1, "b": 2 };
+    let typed = TypedDictionary::<GString, i64>::try_from_untyped(untyped.clone())
+        .expect("all entries have matching types");
+
+    // Data is shared with the untyped dictionary.
+    let mut untyped = untyped;
+    untyped.set("c", 3);
+    assert_eq!(typed.get("c".into()), Some(3));
+    assert_eq!(typed.as_untyped(), &untyped);
+
+    untyped.set("d", "not an int");
+    let err = TypedDictionary::<GString, i64>::try_from_untyped(untyped.clone());
+    assert!(err.is_err());
+
+    expect_panic("typed access to value of wrong type", || {
+        typed.get("d".into());
+    });
+}
+
+#[itest]
+fn typed_dictionary_variant_conversion() {
+    let typed: TypedDictionary<i64, bool> = [(1, true), (2, false)].into_iter().collect();
+
+    let variant = typed.to_variant();
+    assert_eq!(variant, dict! { 1: true, 2: false }.to_variant());
+
+    let back = TypedDictionary::<i64, bool>::try_from_variant(&variant)
+        .expect("entries have matching types");
+    assert_eq!(back, typed);
+
+    let mismatched = TypedDictionary::<GString, bool>::try_from_variant(&variant);
+    assert!(mismatched.is_err());
+}
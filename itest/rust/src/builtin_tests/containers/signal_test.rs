@@ -7,10 +7,11 @@
 
 use std::cell::Cell;
 
-use godot::builtin::{GString, Variant};
-use godot::register::{godot_api, GodotClass};
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{GString, Signal, StringName, Variant, VariantType};
+use godot::register::{callable, godot_api, GodotClass};
 
-use godot::engine::Object;
+use godot::engine::{global, Object};
 use godot::obj::{Base, Gd, NewAlloc, WithBaseField};
 use godot::sys;
 
@@ -58,6 +59,14 @@ impl Receiver {
     }
 }
 
+impl Receiver {
+    // Not a #[func]; connected through typed signals only.
+    fn receive_1_arg_typed(&mut self, arg1: i64) {
+        self.used[1].set(true);
+        assert_eq!(arg1, 987);
+    }
+}
+
 const SIGNAL_ARG_STRING: &str = "Signal string arg";
 
 #[itest]
@@ -88,3 +97,181 @@ fn signals() {
     receiver.free();
     emitter.free();
 }
+
+#[itest]
+fn signals_typed_emit() {
+    let emitter = Emitter::new_alloc();
+    let receiver = Receiver::new_alloc();
+
+    let mut signal = emitter.signals().signal_2_arg();
+    assert_eq!(signal.name(), "signal_2_arg");
+
+    signal.connect_callable(receiver.callable("receive_2_arg"));
+    signal.emit(receiver.clone().upcast(), GString::from(SIGNAL_ARG_STRING));
+
+    assert!(receiver.bind().used[2].get());
+
+    receiver.free();
+    emitter.free();
+}
+
+#[itest]
+fn signals_typed_connect_func() {
+    let emitter = Emitter::new_alloc();
+    let receiver = Receiver::new_alloc();
+
+    let mut signal = emitter.signals().signal_1_arg();
+    signal.connect_func(&receiver, callable!(Receiver::receive_1_arg));
+    signal.emit(987);
+
+    assert!(receiver.bind().used[1].get());
+
+    receiver.free();
+    emitter.free();
+}
+
+#[itest]
+#[cfg(since_api = "4.2")]
+fn signals_typed_connect() {
+    let emitter = Emitter::new_alloc();
+    let receiver = Receiver::new_alloc();
+
+    let mut signal = emitter.signals().signal_1_arg();
+    signal.connect(&receiver, Receiver::receive_1_arg_typed);
+    signal.emit(987);
+
+    assert!(receiver.bind().used[1].get());
+
+    receiver.free();
+    emitter.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct ReentrantEmitter {
+    value: i64,
+    observed: Vec<i64>,
+    #[base]
+    base: Base<Object>,
+}
+
+#[godot_api]
+impl ReentrantEmitter {
+    #[signal]
+    fn value_changed(value: i64);
+
+    #[func]
+    fn set_value(&mut self, value: i64) {
+        self.value = value;
+        self.signals().value_changed().emit(value);
+    }
+
+    // Invoked through Godot while set_value() is still running on the same object.
+    #[func]
+    fn observe_func(&mut self, value: i64) {
+        assert_eq!(self.value, value);
+        self.observed.push(value);
+    }
+
+    // Not a #[func]; connected through typed signals only.
+    fn observe_typed(&mut self, value: i64) {
+        self.observed.push(-value);
+    }
+}
+
+#[itest]
+fn signals_typed_reentrant_func() {
+    let mut emitter = ReentrantEmitter::new_alloc();
+
+    emitter
+        .signals()
+        .value_changed()
+        .connect_func(&emitter, callable!(ReentrantEmitter::observe_func));
+    emitter.bind_mut().set_value(12);
+
+    assert_eq!(emitter.bind().observed, vec![12]);
+
+    // Also through Godot, not only from Rust.
+    emitter.call("set_value".into(), &[Variant::from(34)]);
+    assert_eq!(emitter.bind().observed, vec![12, 34]);
+
+    emitter.free();
+}
+
+#[itest]
+#[cfg(since_api = "4.2")]
+fn signals_typed_reentrant_connect() {
+    let mut emitter = ReentrantEmitter::new_alloc();
+
+    emitter
+        .signals()
+        .value_changed()
+        .connect(&emitter, ReentrantEmitter::observe_typed);
+    emitter.bind_mut().set_value(5);
+
+    assert_eq!(emitter.bind().observed, vec![-5]);
+
+    emitter.free();
+}
+
+#[itest]
+fn signal_builtin_accessors() {
+    let emitter = Emitter::new_alloc();
+    let signal = Signal::from_object_signal(&emitter, "signal_1_arg");
+
+    assert!(!signal.is_null());
+    assert_eq!(signal.name(), StringName::from("signal_1_arg"));
+    assert_eq!(signal.object(), Some(emitter.clone().upcast::<Object>()));
+    assert_eq!(signal.object_id(), Some(emitter.instance_id()));
+
+    assert_eq!(signal, Signal::from_object_signal(&emitter, "signal_1_arg"));
+    assert_ne!(signal, Signal::from_object_signal(&emitter, "signal_0_arg"));
+
+    let invalid = Signal::invalid();
+    assert!(invalid.is_null());
+    assert_eq!(invalid.object(), None);
+    assert_eq!(invalid.object_id(), None);
+
+    emitter.free();
+}
+
+#[itest]
+fn signal_builtin_connect_emit() {
+    let emitter = Emitter::new_alloc();
+    let receiver = Receiver::new_alloc();
+
+    let signal = Signal::from_object_signal(&emitter, "signal_1_arg");
+    let callable = receiver.callable("receive_1_arg");
+
+    assert!(!signal.is_connected(callable.clone()));
+    assert_eq!(signal.connect(callable.clone(), 0), global::Error::OK);
+    assert!(signal.is_connected(callable.clone()));
+
+    let connections = signal.connections();
+    assert_eq!(connections.len(), 1);
+    assert_eq!(connections[0].signal, signal);
+    assert_eq!(connections[0].callable, callable);
+    assert_eq!(connections[0].flags, 0);
+
+    signal.emit(&[Variant::from(987)]);
+    assert!(receiver.bind().used[1].get());
+
+    signal.disconnect(callable.clone());
+    assert!(!signal.is_connected(callable));
+    assert!(signal.connections().is_empty());
+
+    receiver.free();
+    emitter.free();
+}
+
+#[itest]
+fn signal_builtin_variant_roundtrip() {
+    let emitter = Emitter::new_alloc();
+    let signal = Signal::from_object_signal(&emitter, "signal_0_arg");
+
+    let variant = signal.to_variant();
+    assert_eq!(variant.get_type(), VariantType::Signal);
+    assert_eq!(variant.to::<Signal>(), signal);
+
+    emitter.free();
+}
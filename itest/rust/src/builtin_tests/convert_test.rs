@@ -5,9 +5,13 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::{BTreeMap, HashMap, HashSet};
+
 use godot::builtin::meta::{ConvertError, FromGodot, GodotConvert, ToGodot};
+
 use godot::builtin::{
-    dict, Array, Dictionary, GString, Variant, VariantArray, Vector2, Vector2Axis,
+    array, dict, varray, Array, Dictionary, GString, PackedByteArray, PackedInt32Array, Variant,
+    VariantArray, VariantType, Vector2, Vector2Axis,
 };
 use godot::engine::{Node, Resource};
 use godot::obj::{Gd, NewAlloc};
@@ -229,3 +233,98 @@ fn custom_convert_error_from_variant() {
     assert!(err.cause().is_none());
     assert_eq!(err.value_str().unwrap(), format!("{:?}", i64::MAX));
 }
+
+#[itest]
+fn vec_convert_packed_array() {
+    let bytes: Vec<u8> = vec![1, 2, 255];
+    let packed = bytes.to_godot();
+    assert_eq!(packed, PackedByteArray::from(&[1, 2, 255]));
+    assert_eq!(Vec::<u8>::from_godot(packed), bytes);
+
+    let names = vec![GString::from("a"), GString::from("b")];
+    let variant = names.to_variant();
+    assert_eq!(variant.get_type(), VariantType::PackedStringArray);
+    assert_eq!(variant.to::<Vec<GString>>(), names);
+}
+
+#[itest]
+fn vec_convert_typed_array() {
+    let values: Vec<i8> = vec![-3, 0, 7];
+    let array = values.to_godot();
+    assert_eq!(array, array![-3, 0, 7]);
+    assert_eq!(Vec::<i8>::from_godot(array), values);
+
+    let nested = vec![vec![1u8, 2], vec![]];
+    let variant = nested.to_variant();
+    assert_eq!(variant.to::<Vec<Vec<u8>>>(), nested);
+
+    let err = array![1, 300]
+        .to_variant()
+        .try_to::<Vec<i8>>()
+        .expect_err("300 does not fit in i8");
+    assert_eq!(err.value_str().unwrap(), "300");
+}
+
+#[itest]
+fn fixed_array_convert() {
+    let values = [1, 2, 3];
+    let packed = values.to_godot();
+    assert_eq!(packed, PackedInt32Array::from(&[1, 2, 3]));
+    assert_eq!(<[i32; 3]>::from_godot(packed.clone()), values);
+
+    let err = packed
+        .to_variant()
+        .try_to::<[i32; 2]>()
+        .expect_err("array has 3 elements");
+    assert!(err.to_string().contains("expected array of length 2"));
+}
+
+#[itest]
+fn map_convert_roundtrip() {
+    let map: HashMap<GString, i64> = [("a".into(), 1), ("b".into(), 2)].into_iter().collect();
+    let dictionary = map.to_godot();
+    assert_eq!(dictionary, dict! { "a": 1, "b": 2 });
+    assert_eq!(HashMap::<GString, i64>::from_godot(dictionary), map);
+
+    let map: BTreeMap<i32, Vec<u8>> = [(1, vec![1]), (2, vec![])].into_iter().collect();
+    assert_eq!(map.to_variant().to::<BTreeMap<i32, Vec<u8>>>(), map);
+
+    dict! { "a": "not a number" }
+        .to_variant()
+        .try_to::<HashMap<GString, i64>>()
+        .expect_err("value has wrong type");
+}
+
+#[itest]
+fn set_convert_roundtrip() {
+    let set: HashSet<i64> = [4, 8, 15].into_iter().collect();
+    let variant = set.to_variant();
+    assert_eq!(variant.to::<Vec<i64>>().len(), 3);
+    assert_eq!(variant.to::<HashSet<i64>>(), set);
+}
+
+#[itest]
+fn tuple_convert_roundtrip() {
+    let tuple = (1, GString::from("two"), Vector2::new(3.0, 4.0));
+    let array = tuple.to_godot();
+    assert_eq!(
+        array,
+        varray![1, GString::from("two"), Vector2::new(3.0, 4.0)]
+    );
+    assert_eq!(<(i32, GString, Vector2)>::from_godot(array), tuple);
+
+    let err = varray![1, 2]
+        .to_variant()
+        .try_to::<(i32, i32, i32)>()
+        .expect_err("array has 2 elements");
+    assert!(err.to_string().contains("expected array of length 3"));
+
+    let err = varray![1, "two"]
+        .to_variant()
+        .try_to::<(i32, i32)>()
+        .expect_err("second element has wrong type");
+    assert_eq!(
+        err.value_str().unwrap(),
+        format!("{:?}", "two".to_variant())
+    );
+}
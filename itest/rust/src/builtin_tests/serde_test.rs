@@ -6,7 +6,14 @@
  */
 
 use crate::framework::itest;
-use godot::builtin::{array, Array, GString, NodePath, StringName, Vector2i};
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{
+    array, dict, varray, Array, Color, Dictionary, GString, NodePath, PackedByteArray,
+    PackedStringArray, PackedVector2Array, Rid, StringName, TypedDictionary, Variant, Vector2,
+    Vector2i,
+};
+use godot::engine::RefCounted;
+use godot::obj::NewGd;
 use serde::{Deserialize, Serialize};
 
 fn serde_roundtrip<T>(value: &T, expected_json: &str)
@@ -78,3 +85,90 @@ fn serde_array_godot_type() {
 
     serde_roundtrip(&value, expected_json)
 }
+
+#[itest]
+fn serde_packed_arrays() {
+    serde_roundtrip(&PackedByteArray::from(&[1, 2, 255]), "[1,2,255]");
+    serde_roundtrip(
+        &PackedStringArray::from(&["a".into(), "b".into()]),
+        r#"["a","b"]"#,
+    );
+    serde_roundtrip(
+        &PackedVector2Array::from(&[Vector2::new(1.0, 2.0)]),
+        r#"[{"x":1.0,"y":2.0}]"#,
+    );
+    serde_roundtrip(&PackedByteArray::new(), "[]");
+}
+
+#[itest]
+fn serde_rid() {
+    serde_roundtrip(&Rid::new(42), "42");
+    serde_roundtrip(&Rid::Invalid, "0");
+}
+
+#[itest]
+fn serde_variant() {
+    serde_roundtrip(&Variant::nil(), r#""Nil""#);
+    serde_roundtrip(&true.to_variant(), r#"{"Bool":true}"#);
+    serde_roundtrip(&7.to_variant(), r#"{"Int":7}"#);
+    serde_roundtrip(&2.5.to_variant(), r#"{"Float":2.5}"#);
+    serde_roundtrip(&"text".to_variant(), r#"{"String":"text"}"#);
+    serde_roundtrip(
+        &StringName::from("name").to_variant(),
+        r#"{"StringName":"name"}"#,
+    );
+    serde_roundtrip(
+        &Vector2i::new(1, 2).to_variant(),
+        r#"{"Vector2i":{"x":1,"y":2}}"#,
+    );
+    serde_roundtrip(
+        &Color::from_rgba(1.0, 0.5, 0.0, 1.0).to_variant(),
+        r#"{"Color":{"r":1.0,"g":0.5,"b":0.0,"a":1.0}}"#,
+    );
+    serde_roundtrip(
+        &varray![1, "two"].to_variant(),
+        r#"{"Array":[{"Int":1},{"String":"two"}]}"#,
+    );
+    serde_roundtrip(
+        &PackedByteArray::from(&[3, 4]).to_variant(),
+        r#"{"PackedByteArray":[3,4]}"#,
+    );
+}
+
+#[itest]
+fn serde_variant_typed_array() {
+    let typed: Array<i64> = array![1, 2];
+    let json = serde_json::to_string(&typed.to_variant()).unwrap();
+    assert_eq!(json, r#"{"Array":[{"Int":1},{"Int":2}]}"#);
+
+    let back: Variant = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, varray![1, 2].to_variant());
+}
+
+#[itest]
+fn serde_variant_unsupported() {
+    let object = RefCounted::new_gd();
+    let err =
+        serde_json::to_string(&object.to_variant()).expect_err("objects are not serializable");
+    assert!(err.to_string().contains("Object"), "{err}");
+}
+
+#[itest]
+fn serde_dictionary() {
+    let value = dict! { "level": 3, 5: varray![true] };
+    let expected_json = r#"[[{"String":"level"},{"Int":3}],[{"Int":5},{"Array":[{"Bool":true}]}]]"#;
+
+    serde_roundtrip(&value, expected_json);
+    serde_roundtrip(&Dictionary::new(), "[]");
+}
+
+#[itest]
+fn serde_typed_dictionary() {
+    let value: TypedDictionary<GString, i64> =
+        [(GString::from("alice"), 12), (GString::from("bob"), 7)]
+            .into_iter()
+            .collect();
+    let expected_json = r#"{"alice":12,"bob":7}"#;
+
+    serde_roundtrip(&value, expected_json);
+}
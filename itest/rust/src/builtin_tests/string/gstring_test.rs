@@ -8,7 +8,7 @@
 use std::collections::HashSet;
 
 use crate::framework::itest;
-use godot::builtin::GString;
+use godot::builtin::{dict, varray, GString};
 
 // TODO use tests from godot-rust/gdnative
 
@@ -96,3 +96,83 @@ fn string_hash() {
     .collect();
     assert_eq!(set.len(), 5);
 }
+
+#[itest]
+fn string_find() {
+    let string = GString::from("abcabc");
+
+    assert_eq!(string.find("bc"), Some(1));
+    assert_eq!(string.find_from("bc", 2), Some(4));
+    assert_eq!(string.rfind("a"), Some(3));
+    assert_eq!(string.findn("BC"), Some(1));
+    assert_eq!(string.find("x"), None);
+    assert!(string.contains("ca"));
+    assert!(string.starts_with("abc"));
+    assert!(string.ends_with("bc"));
+    assert!(!string.ends_with("a"));
+}
+
+#[itest]
+fn string_split() {
+    let string = GString::from("a,b,,c");
+    let parts: Vec<GString> = string.split(",").collect();
+
+    assert_eq!(parts, ["a", "b", "", "c"].map(GString::from));
+    assert_eq!(GString::new().split(",").len(), 1);
+}
+
+#[itest]
+fn string_substring() {
+    let string = GString::from("änderung");
+
+    assert_eq!(string.substring(0..3), "änd");
+    assert_eq!(string.substring(3..), "erung");
+    assert_eq!(string.substring(..=1), "än");
+    assert_eq!(string.substring(5..100), "ung");
+    assert_eq!(string.substring(20..), "");
+}
+
+#[itest]
+fn string_case_and_replace() {
+    let string = GString::from("  Hello World ");
+
+    assert_eq!(string.trim(), "Hello World");
+    assert_eq!(string.trim().to_upper(), "HELLO WORLD");
+    assert_eq!(string.trim().to_lower(), "hello world");
+    assert_eq!(string.trim().to_snake_case(), "hello_world");
+    assert_eq!(string.trim().to_camel_case(), "helloWorld");
+    assert_eq!(GString::from("move_local_x").capitalize(), "Move Local X");
+    assert_eq!(string.replace("World", "Godot"), "  Hello Godot ");
+}
+
+#[itest]
+fn string_format() {
+    let template = GString::from("{name} has {hp} HP");
+    let formatted = template.format(dict! { "name": "Godette", "hp": 42 });
+    assert_eq!(formatted, "Godette has 42 HP");
+
+    let template = GString::from("{0} and {1}");
+    assert_eq!(template.format(varray!["left", "right"]), "left and right");
+}
+
+#[itest]
+fn string_parse_numbers() {
+    assert_eq!(GString::from("-42").parse_int().ok(), Some(-42));
+    assert_eq!(GString::from("1.5e3").parse_float().ok(), Some(1500.0));
+    assert_eq!(GString::from("12").parse_float().ok(), Some(12.0));
+
+    let err = GString::from("twelve").parse_int().expect_err("not an int");
+    assert_eq!(err.value_str(), Some("\"twelve\""));
+    GString::from("1.2.3")
+        .parse_float()
+        .expect_err("not a float");
+}
+
+#[itest]
+fn string_eq_str() {
+    let string = GString::from("hello");
+
+    assert_eq!(string, "hello");
+    assert_ne!(string, "hell");
+    assert!("hello" == string);
+}
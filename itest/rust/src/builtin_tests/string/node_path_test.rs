@@ -8,7 +8,7 @@
 use std::collections::HashSet;
 
 use crate::framework::itest;
-use godot::builtin::{GString, NodePath};
+use godot::builtin::{GString, NodePath, StringName};
 
 #[itest]
 fn node_path_default() {
@@ -64,3 +64,74 @@ fn node_path_hash() {
     .collect();
     assert_eq!(set.len(), 5);
 }
+
+#[itest]
+fn node_path_names_subnames() {
+    let path = NodePath::from("/root/Path2D/PathFollow2D:position:x");
+
+    assert!(path.is_absolute());
+    assert!(!path.is_empty());
+    assert_eq!(path.name_count(), 3);
+    assert_eq!(path.subname_count(), 2);
+
+    let names: Vec<StringName> = path.names().collect();
+    assert_eq!(
+        names,
+        ["root", "Path2D", "PathFollow2D"].map(StringName::from)
+    );
+
+    let subnames: Vec<StringName> = path.subnames().collect();
+    assert_eq!(subnames, ["position", "x"].map(StringName::from));
+
+    assert!(!NodePath::from("Child").is_absolute());
+    assert!(NodePath::default().is_empty());
+}
+
+#[itest]
+fn node_path_join() {
+    let base = NodePath::from("Level/Player");
+
+    assert_eq!(
+        base.join(&"Sprite:modulate".into()),
+        "Level/Player/Sprite:modulate".into()
+    );
+    assert_eq!(
+        base.join(&":position".into()),
+        "Level/Player:position".into()
+    );
+    assert_eq!(base.join(&"/root/Main".into()), "/root/Main".into());
+    assert_eq!(NodePath::default().join(&base), base);
+
+    let absolute = NodePath::from("/root:transform");
+    assert_eq!(absolute.join(&"Camera".into()), "/root/Camera".into());
+}
+
+#[itest]
+fn node_path_parent() {
+    let path = NodePath::from("/root/Level/Player:position");
+
+    assert_eq!(path.parent(), Some("/root/Level".into()));
+    assert_eq!(NodePath::from("a/b").parent(), Some("a".into()));
+    assert_eq!(NodePath::from("a").parent(), None);
+    assert_eq!(NodePath::default().parent(), None);
+}
+
+#[itest]
+fn node_path_property_path() {
+    let path = NodePath::from("Sprite2D:texture:load_path");
+
+    assert_eq!(
+        path.as_property_path(),
+        ":Sprite2D:texture:load_path".into()
+    );
+}
+
+#[itest]
+fn node_path_from_names() {
+    let path: NodePath = ["Level", "Player"].into_iter().collect();
+    assert_eq!(path, "Level/Player".into());
+
+    let parsed: NodePath = "/root/Level:position".parse().unwrap();
+    assert_eq!(parsed.to_string(), "/root/Level:position");
+    assert_eq!(parsed.to_string().parse::<NodePath>().unwrap(), parsed);
+}
@@ -8,7 +8,7 @@
 use std::collections::HashSet;
 
 use crate::framework::itest;
-use godot::builtin::{GString, NodePath, StringName};
+use godot::builtin::{string_name, GString, NodePath, StringName};
 
 #[itest]
 fn string_name_default() {
@@ -58,17 +58,15 @@ fn string_name_equality() {
     assert_ne!(string, different);
 }
 
-// TODO: add back in when ordering StringNames is fixed
-#[itest(skip)]
+#[itest]
 fn string_name_ordering() {
-    let _low = StringName::from("Alpha");
-    let _high = StringName::from("Beta");
-    /*
+    let low = StringName::from("Alpha");
+    let high = StringName::from("Beta");
+
     assert!(low < high);
     assert!(low <= high);
     assert!(high > low);
     assert!(high >= low);
-     */
 }
 
 #[itest]
@@ -137,3 +135,43 @@ fn string_name_from_latin1_with_nul() {
         assert_eq!(a, b);
     }
 }
+
+#[itest]
+fn string_name_text_api() {
+    let name = StringName::from("move_local_x");
+
+    assert_eq!(name.find("local"), Some(5));
+    assert!(name.starts_with("move"));
+    assert_eq!(name.to_pascal_case(), "MoveLocalX");
+    assert_eq!(name.substring(5..10), "local");
+    assert_eq!(name, "move_local_x");
+    assert_ne!(name, "move");
+    assert_eq!("move_local_x", name);
+}
+
+#[itest]
+fn string_name_macro() {
+    fn cached() -> StringName {
+        string_name!("health_changed")
+    }
+
+    let first = cached();
+    let second = cached();
+
+    assert_eq!(first, StringName::from("health_changed"));
+    assert_eq!(first, second);
+    assert_eq!(string_name!("health_changed"), first);
+    assert_eq!(string_name!(""), StringName::default());
+}
+
+#[itest]
+fn string_name_interned() {
+    let dynamic = format!("interned_{}", 42);
+
+    let a = StringName::interned(&dynamic);
+    let b = StringName::interned("interned_42");
+
+    assert_eq!(a, b);
+    assert_eq!(a, StringName::from("interned_42"));
+    assert_ne!(a, StringName::interned("interned_43"));
+}
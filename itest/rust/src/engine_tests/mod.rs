@@ -8,5 +8,6 @@
 mod gfile_test;
 mod native_structures_test;
 mod node_test;
+mod panic_policy_test;
 mod save_load_test;
 mod utilities_test;
@@ -7,8 +7,8 @@
 
 use std::str::FromStr;
 
-use godot::builtin::{NodePath, Variant};
-use godot::engine::{global, Node, Node3D, NodeExt, PackedScene, SceneTree};
+use godot::builtin::{NodePath, StringName, Variant, Vector2};
+use godot::engine::{global, Node, Node2D, Node3D, NodeExt, PackedScene, SceneTree};
 use godot::obj::{NewAlloc, NewGd};
 
 use crate::framework::{itest, TestContext};
@@ -91,3 +91,40 @@ fn node_call_group(ctx: &TestContext) {
     node.add_to_group("group".into());
     tree.call_group("group".into(), "set_name".into(), &[Variant::from("name")]);
 }
+
+#[itest]
+fn node_engine_signals() {
+    // Declared by Node itself, and inherited from Object through Deref.
+    assert_eq!(Node::signals().ready().name(), "ready");
+    assert_eq!(Node2D::signals().tree_entered().name(), "tree_entered");
+    assert_eq!(Node2D::signals().script_changed().name(), "script_changed");
+
+    let name: StringName = Node::signals().renamed().into();
+    assert_eq!(name, StringName::from("renamed"));
+
+    let node = Node::new_alloc();
+    let mut signal = Node::signals().renamed().on(&node);
+    signal.connect_callable(node.callable("queue_free"));
+    assert!(node.is_connected("renamed".into(), node.callable("queue_free")));
+
+    node.free();
+}
+
+#[itest]
+fn node_engine_properties() {
+    let position = Node2D::properties().position();
+    assert_eq!(position.name(), "position");
+    assert_eq!(position.godot_type(), "Vector2");
+    assert_eq!(position.getter(), Some("get_position"));
+    assert_eq!(position.setter(), Some("set_position"));
+
+    let mut node = Node2D::new_alloc();
+    position.set(&mut node, Variant::from(Vector2::new(1.0, 2.0)));
+    assert_eq!(node.get_position(), Vector2::new(1.0, 2.0));
+    assert_eq!(position.get(&node), Variant::from(Vector2::new(1.0, 2.0)));
+
+    // Inherited from Node.
+    assert_eq!(Node2D::properties().name().name(), "name");
+
+    node.free();
+}
@@ -0,0 +1,90 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::Mutex;
+
+use godot::init::{PanicPolicy, PanicReport};
+use godot::private::handle_panic_with_policy;
+
+use crate::framework::itest;
+
+#[derive(Debug)]
+struct RecordedReport {
+    message: Option<String>,
+    context: String,
+    file: Option<String>,
+    line: Option<u32>,
+}
+
+static RECORDED: Mutex<Vec<RecordedReport>> = Mutex::new(Vec::new());
+
+fn record_report(report: &PanicReport) {
+    RECORDED.lock().unwrap().push(RecordedReport {
+        message: report.message().map(str::to_string),
+        context: report.context().to_string(),
+        file: report.file().map(str::to_string),
+        line: report.line(),
+    });
+}
+
+fn take_recorded() -> Vec<RecordedReport> {
+    std::mem::take(&mut *RECORDED.lock().unwrap())
+}
+
+#[itest]
+fn panic_policy_custom_receives_report() {
+    take_recorded();
+
+    let line_before = line!();
+    let result: Option<i32> = handle_panic_with_policy(
+        PanicPolicy::Custom(record_report),
+        || "MyClass::my_method",
+        || panic!("custom policy {}", 42),
+    );
+    let line_after = line!();
+    assert_eq!(result, None);
+
+    let reports = take_recorded();
+    assert_eq!(reports.len(), 1, "handler must be called exactly once");
+
+    let report = &reports[0];
+    assert_eq!(report.message.as_deref(), Some("custom policy 42"));
+    assert_eq!(report.context, "MyClass::my_method");
+    assert_eq!(report.file.as_deref(), Some(file!()));
+    let line = report.line.expect("panic line");
+    assert!(line_before < line && line < line_after, "line {line}");
+}
+
+#[itest]
+fn panic_policy_custom_not_called_without_panic() {
+    take_recorded();
+
+    let result = handle_panic_with_policy(PanicPolicy::Custom(record_report), || "no panic", || 7);
+    assert_eq!(result, Some(7));
+    assert!(take_recorded().is_empty());
+}
+
+#[itest]
+fn panic_policy_custom_handler_panic_is_contained() {
+    fn panicking_handler(report: &PanicReport) {
+        record_report(report);
+        panic!("handler panicked");
+    }
+
+    take_recorded();
+
+    let result: Option<()> = handle_panic_with_policy(
+        PanicPolicy::Custom(panicking_handler),
+        || "contained",
+        || panic!("original panic"),
+    );
+    assert_eq!(result, None);
+
+    let reports = take_recorded();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].message.as_deref(), Some("original panic"));
+}
@@ -15,3 +15,4 @@ mod property_test;
 mod reentrant_test;
 mod singleton_test;
 mod virtual_methods_test;
+mod weak_gd_test;
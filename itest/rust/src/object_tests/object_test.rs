@@ -15,7 +15,7 @@ use godot::engine::{
     RefCounted,
 };
 use godot::obj;
-use godot::obj::{Base, Gd, Inherits, InstanceId, NewAlloc, NewGd, RawGd};
+use godot::obj::{Base, BorrowError, Gd, Inherits, InstanceId, NewAlloc, NewGd, RawGd};
 use godot::prelude::meta::GodotType;
 use godot::register::{godot_api, GodotClass};
 use godot::sys::{self, interface_fn, GodotFfi};
@@ -238,6 +238,46 @@ fn object_user_free_during_bind() {
     obj.free(); // now succeeds
 }
 
+#[itest]
+fn object_user_try_bind() {
+    let mut obj = RefcPayload::new_gd();
+    let mut copy = obj.clone();
+
+    {
+        let guard = obj.bind();
+        assert_eq!(copy.try_bind().map(|g| g.value), Ok(guard.value));
+        assert_eq!(
+            copy.try_bind_mut().err(),
+            Some(BorrowError::SharedBound),
+            "try_bind_mut() while shared-bound"
+        );
+    }
+
+    {
+        let mut guard = obj.bind_mut();
+        guard.value = 33;
+
+        assert_eq!(copy.try_bind().err(), Some(BorrowError::MutablyBound));
+        assert_eq!(copy.try_bind_mut().err(), Some(BorrowError::MutablyBound));
+    }
+
+    // Unbound again.
+    copy.try_bind_mut()
+        .expect("try_bind_mut() after guards dropped")
+        .value += 1;
+    assert_eq!(obj.try_bind().map(|g| g.value), Ok(34));
+}
+
+#[itest]
+fn object_user_try_bind_after_free() {
+    let obj = Gd::from_object(ObjPayload {});
+    let mut copy = obj.clone();
+    obj.free();
+
+    assert_eq!(copy.try_bind().err(), Some(BorrowError::DeadInstance));
+    assert_eq!(copy.try_bind_mut().err(), Some(BorrowError::DeadInstance));
+}
+
 #[itest]
 fn object_engine_freed_argument_passing(ctx: &TestContext) {
     let node: Gd<Node> = Node::new_alloc();
@@ -7,10 +7,10 @@
 
 use crate::framework::{expect_panic, itest};
 use godot::engine::notify::NodeNotification;
-use godot::engine::INode;
+use godot::engine::{INode, Node, Node2D};
 use godot::register::{godot_api, GodotClass};
 
-use godot::obj::{Gd, OnReady};
+use godot::obj::{Base, Gd, NewAlloc, OnReady, UserClass};
 use godot::prelude::ToGodot;
 
 #[itest]
@@ -139,6 +139,51 @@ fn onready_property_access() {
     obj.free();
 }
 
+#[itest]
+fn onready_node_path() {
+    let mut obj = OnReadyWithNode::new_alloc();
+
+    let mut child = Node2D::new_alloc();
+    child.set_name("Child".into());
+    obj.add_child(child.clone().upcast());
+
+    obj.notify(NodeNotification::Ready);
+
+    {
+        let obj = obj.bind();
+        assert_eq!(obj.child.instance_id(), child.instance_id());
+        assert_eq!(*obj.auto, 88);
+    }
+
+    obj.free();
+}
+
+#[itest]
+fn onready_node_path_missing() {
+    let mut obj = OnReadyWithNode::new_alloc();
+
+    expect_panic("missing node for #[init(node)]", || {
+        obj.bind_mut().__before_ready();
+    });
+
+    obj.free();
+}
+
+#[itest]
+fn onready_node_path_wrong_type() {
+    let mut obj = OnReadyWithNode::new_alloc();
+
+    let mut child = Node::new_alloc();
+    child.set_name("Child".into());
+    obj.add_child(child);
+
+    expect_panic("node of wrong type for #[init(node)]", || {
+        obj.bind_mut().__before_ready();
+    });
+
+    obj.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[derive(GodotClass)]
@@ -224,3 +269,19 @@ impl INode for OnReadyWithoutImpl {
     // Declare another function to ensure virtual getter must be provided.
     fn process(&mut self, _delta: f64) {}
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+// Class that looks up a child node declaratively.
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct OnReadyWithNode {
+    #[init(node = "Child")]
+    child: OnReady<Gd<Node2D>>,
+
+    #[init(default = OnReady::new(|| 88))]
+    auto: OnReady<i32>,
+
+    #[base]
+    base: Base<Node>,
+}
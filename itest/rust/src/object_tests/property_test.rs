@@ -361,6 +361,126 @@ fn derive_export() {
     );
 }
 
+#[derive(Var, Export, Default, Eq, PartialEq, Debug)]
+pub enum ImplicitEnum {
+    #[default]
+    Low,
+    Medium,
+    High = 10,
+    Higher,
+}
+
+#[derive(GodotConvert, Var, Export, Default, Eq, PartialEq, Debug)]
+#[godot(via = GString)]
+pub enum StringEnum {
+    #[default]
+    North,
+    South,
+}
+
+#[derive(Var, Export, Default, Eq, PartialEq, Debug)]
+pub struct Stats {
+    strength: i32,
+    name: GString,
+}
+
+#[derive(GodotConvert, ToGodot, FromGodot, Var, Export, Clone, Eq, PartialEq, Debug)]
+pub enum Weapon {
+    Fists,
+    Sword { damage: i64 },
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct DeriveExportCompound {
+    #[export]
+    implicit: ImplicitEnum,
+
+    #[export]
+    direction: StringEnum,
+
+    #[export]
+    stats: Stats,
+
+    #[export]
+    #[init(default = Weapon::Fists)]
+    weapon: Weapon,
+}
+
+#[itest]
+fn derive_export_compound() {
+    let mut class = DeriveExportCompound::new_alloc();
+    let properties = class.get_property_list();
+    let find = |name: &str| {
+        properties
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap()
+    };
+
+    let implicit = find("implicit");
+    check_property(&implicit, "type", VariantType::Int as i32);
+    check_property(&implicit, "hint", PropertyHint::PROPERTY_HINT_ENUM.ord());
+    check_property(&implicit, "hint_string", "Low:0,Medium:1,High:10,Higher:11");
+
+    let direction = find("direction");
+    check_property(&direction, "type", VariantType::String as i32);
+    check_property(&direction, "hint", PropertyHint::PROPERTY_HINT_ENUM.ord());
+    check_property(&direction, "hint_string", "North,South");
+
+    for name in ["stats", "weapon"] {
+        let property = find(name);
+        check_property(&property, "type", VariantType::Dictionary as i32);
+        check_property(&property, "hint", PropertyHint::PROPERTY_HINT_NONE.ord());
+    }
+
+    class.set("implicit".into(), 11.to_variant());
+    class.set("direction".into(), "South".to_variant());
+    class.set(
+        "stats".into(),
+        dict! { "strength": 7, "name": "hero" }.to_variant(),
+    );
+    class.set("weapon".into(), Weapon::Sword { damage: 3 }.to_variant());
+
+    {
+        let bound = class.bind();
+        assert_eq!(bound.implicit, ImplicitEnum::Higher);
+        assert_eq!(bound.direction, StringEnum::South);
+        assert_eq!(
+            bound.stats,
+            Stats {
+                strength: 7,
+                name: "hero".into()
+            }
+        );
+        assert_eq!(bound.weapon, Weapon::Sword { damage: 3 });
+    }
+
+    // Keys missing in the dictionary leave the fields unchanged.
+    class.set("stats".into(), dict! { "strength": 9 }.to_variant());
+    assert_eq!(
+        class.get("stats".into()),
+        dict! { "strength": 9, "name": "hero" }.to_variant()
+    );
+    assert_eq!(class.get("direction".into()), "South".to_variant());
+
+    // Values of the wrong type are skipped with an error, while other keys are still applied.
+    class.set(
+        "stats".into(),
+        dict! { "strength": "strong", "name": "orc" }.to_variant(),
+    );
+    assert_eq!(
+        class.get("stats".into()),
+        dict! { "strength": 9, "name": "orc" }.to_variant()
+    );
+
+    // Dictionaries that don't convert to any variant keep the previous value.
+    class.set("weapon".into(), dict! { "Weapon": "Spear" }.to_variant());
+    assert_eq!(class.bind().weapon, Weapon::Sword { damage: 3 });
+
+    class.free();
+}
+
 #[derive(GodotClass)]
 #[class(init, base=Resource)]
 pub struct CustomResource {}
@@ -429,3 +549,85 @@ fn export_resource() {
 fn check_property(property: &Dictionary, key: &str, expected: impl ToGodot) {
     assert_eq!(property.get_or_nil(key), expected.to_variant());
 }
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportTypedDictionary {
+    #[export]
+    scores: TypedDictionary<GString, i64>,
+}
+
+#[itest]
+fn export_typed_dictionary() {
+    let mut class = ExportTypedDictionary::new_alloc();
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "scores".to_variant())
+        .unwrap();
+    check_property(&property, "type", VariantType::Dictionary as i32);
+    check_property(&property, "hint", PropertyHint::PROPERTY_HINT_NONE.ord());
+
+    class.set("scores".into(), dict! { "alice": 12 }.to_variant());
+    assert_eq!(class.bind().scores.get("alice".into()), Some(12));
+    assert_eq!(
+        class.get("scores".into()),
+        dict! { "alice": 12 }.to_variant()
+    );
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportRustCollections {
+    #[export]
+    bytes: Vec<u8>,
+
+    #[export]
+    names: Vec<StringName>,
+
+    #[var]
+    lookup: std::collections::HashMap<GString, i64>,
+}
+
+#[itest]
+fn export_rust_collections() {
+    let mut class = ExportRustCollections::new_alloc();
+
+    let property_type = |name: &str| {
+        let property = class
+            .get_property_list()
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap();
+        property.get_or_nil("type")
+    };
+    assert_eq!(
+        property_type("bytes"),
+        (VariantType::PackedByteArray as i32).to_variant()
+    );
+    assert_eq!(
+        property_type("names"),
+        (VariantType::Array as i32).to_variant()
+    );
+    assert_eq!(
+        property_type("lookup"),
+        (VariantType::Dictionary as i32).to_variant()
+    );
+
+    class.set("bytes".into(), PackedByteArray::from(&[4, 2]).to_variant());
+    assert_eq!(class.bind().bytes, vec![4, 2]);
+
+    class.bind_mut().names = vec![StringName::from("a")];
+    assert_eq!(
+        class.get("names".into()),
+        array![StringName::from("a")].to_variant()
+    );
+
+    class.set("lookup".into(), dict! { "x": 1 }.to_variant());
+    assert_eq!(class.bind().lookup.get(&GString::from("x")), Some(&1));
+
+    class.free();
+}
@@ -0,0 +1,75 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::meta::ToGodot;
+use godot::builtin::Variant;
+use godot::engine::{Node, RefCounted, WeakRef};
+use godot::obj::{Gd, NewAlloc, NewGd, WeakGd};
+
+use crate::framework::itest;
+
+#[itest]
+fn weak_gd_refcounted_does_not_keep_alive() {
+    let strong = RefCounted::new_gd();
+    let weak = strong.downgrade();
+    assert_eq!(
+        strong.get_reference_count(),
+        1,
+        "downgrade() must not add a reference"
+    );
+
+    let upgraded = weak.upgrade().expect("object is alive");
+    assert_eq!(upgraded, strong);
+    assert_eq!(strong.get_reference_count(), 2);
+    drop(upgraded);
+
+    drop(strong);
+    assert!(!weak.is_instance_valid());
+    assert_eq!(weak.upgrade(), None);
+}
+
+#[itest]
+fn weak_gd_manual_free() {
+    let node = Node::new_alloc();
+    let weak = node.downgrade();
+    assert!(weak.is_instance_valid());
+    assert_eq!(weak.instance_id(), node.instance_id());
+
+    node.free();
+    assert!(!weak.is_instance_valid());
+    assert_eq!(weak.upgrade(), None);
+}
+
+#[itest]
+fn weak_gd_copy_eq() {
+    let node = Node::new_alloc();
+    let weak = node.downgrade();
+    let copy: WeakGd<Node> = weak;
+
+    assert_eq!(weak, copy);
+    assert_eq!(weak, node.downgrade());
+
+    node.free();
+}
+
+#[itest]
+fn weak_gd_to_godot() {
+    let strong = RefCounted::new_gd();
+    let weak = strong.downgrade();
+
+    let weak_ref: Gd<WeakRef> = weak.to_godot();
+    assert_eq!(weak_ref.get_ref(), strong.to_variant());
+    assert_eq!(
+        strong.get_reference_count(),
+        1,
+        "WeakRef must not add a reference"
+    );
+
+    drop(strong);
+    assert_eq!(weak_ref.get_ref(), Variant::nil());
+    assert_eq!(weak.to_godot().get_ref(), Variant::nil());
+}
@@ -0,0 +1,94 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::engine::ClassDb;
+use godot::prelude::*;
+use godot::register::builder::ClassBuilder;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct BuiltAtRuntime {
+    level: i64,
+}
+
+#[godot_api]
+impl BuiltAtRuntime {
+    #[func]
+    fn get_level(&self) -> i64 {
+        self.level
+    }
+
+    #[func]
+    fn set_level(&mut self, level: i64) {
+        self.level = level;
+    }
+}
+
+#[godot_api]
+impl IRefCounted for BuiltAtRuntime {
+    fn register_class(builder: &mut ClassBuilder<Self>) {
+        builder
+            .method("add_levels", |mut this: Gd<Self>, args: &[&Variant]| {
+                let levels = args[0].try_to::<i64>().map_err(|_| ())?;
+
+                let mut guard = this.bind_mut();
+                guard.level += levels;
+                Ok(guard.level.to_variant())
+            })
+            .param::<i64>("levels")
+            .returns::<i64>()
+            .done();
+
+        builder
+            .property::<i64>("level")
+            .getter("get_level")
+            .setter("set_level")
+            .done();
+
+        builder.signal("leveled_up").param::<i64>("level").done();
+        builder.constant("MAX_LEVEL", 99);
+    }
+}
+
+#[itest]
+fn builder_method() {
+    let obj = BuiltAtRuntime::new_gd();
+    let mut object = obj.clone().upcast::<Object>();
+
+    let result = object.call("add_levels".into(), &[3.to_variant()]);
+    assert_eq!(result, 3.to_variant());
+
+    let result = object.call("add_levels".into(), &[4.to_variant()]);
+    assert_eq!(result, 7.to_variant());
+    assert_eq!(obj.bind().level, 7);
+}
+
+#[itest]
+fn builder_property() {
+    let obj = BuiltAtRuntime::new_gd();
+    let mut object = obj.clone().upcast::<Object>();
+
+    object.set("level".into(), 12.to_variant());
+    assert_eq!(obj.bind().level, 12);
+    assert_eq!(object.get("level".into()), 12.to_variant());
+}
+
+#[itest]
+fn builder_signal() {
+    let object = BuiltAtRuntime::new_gd().upcast::<Object>();
+
+    assert!(object.has_signal("leveled_up".into()));
+}
+
+#[itest]
+fn builder_constant() {
+    let class_name = BuiltAtRuntime::class_name().to_string_name();
+    let value = ClassDb::singleton().class_get_integer_constant(class_name, "MAX_LEVEL".into());
+
+    assert_eq!(value, 99);
+}
@@ -13,6 +13,9 @@ use godot::engine::ClassDb;
 use godot::prelude::*;
 use godot::sys::static_assert;
 
+// Integer types are not recognized by their name, so aliases and paths are registered as integer constants, too.
+type ConstantId = i64;
+
 #[derive(GodotClass)]
 struct HasConstants {}
 
@@ -30,6 +33,12 @@ impl HasConstants {
     #[constant]
     const D: usize = 20 + 33 * 45;
 
+    #[constant]
+    const E: core::primitive::i32 = -7;
+
+    #[constant]
+    const F: ConstantId = 99;
+
     #[constant]
     #[rustfmt::skip]
     const DONT_PANIC_WITH_SEGMENTED_PATH_ATTRIBUTE: bool = true;
@@ -71,11 +80,13 @@ fn class_has_integer_constant<T: GodotClass>(name: &str) -> bool {
 
 #[itest]
 fn constants_correct_value() {
-    const CONSTANTS: [(&str, i64); 5] = [
+    const CONSTANTS: [(&str, i64); 7] = [
         ("A", HasConstants::A),
         ("B", HasConstants::B as i64),
         ("C", HasConstants::C as i64),
         ("D", HasConstants::D as i64),
+        ("E", HasConstants::E as i64),
+        ("F", HasConstants::F),
         (
             "CFG_REMOVES_DUPLICATE_CONSTANT_DEF",
             HasConstants::CFG_REMOVES_DUPLICATE_CONSTANT_DEF,
@@ -113,6 +124,48 @@ fn cfg_removes_or_keeps_constants() {
     ));
 }
 
+#[derive(GodotClass)]
+#[class(init)]
+struct HasNonIntegerConstants {}
+
+#[godot_api]
+impl HasNonIntegerConstants {
+    #[constant(getter)]
+    const GRAVITY: f32 = 9.81;
+
+    #[constant(getter)]
+    const PRECISE: f64 = 0.125;
+
+    #[constant(getter)]
+    const ORIGIN: Vector2 = Vector2::new(1.0, -2.0);
+
+    #[cfg(any())]
+    #[constant(getter)]
+    const REMOVED: f32 = compile_error!("Removed by #[cfg]");
+}
+
+#[itest]
+fn constants_non_integer() {
+    let class_name = HasNonIntegerConstants::class_name().to_string_name();
+
+    // Not registered as integer constants, but as static methods.
+    assert!(!class_has_integer_constant::<HasNonIntegerConstants>(
+        "GRAVITY"
+    ));
+    assert!(ClassDb::singleton().class_has_method(class_name.clone(), "GRAVITY".into()));
+    assert!(ClassDb::singleton().class_has_method(class_name.clone(), "PRECISE".into()));
+    assert!(ClassDb::singleton().class_has_method(class_name.clone(), "ORIGIN".into()));
+    assert!(!ClassDb::singleton().class_has_method(class_name, "REMOVED".into()));
+
+    let mut obj = HasNonIntegerConstants::new_gd().upcast::<Object>();
+    assert_eq!(obj.call("GRAVITY".into(), &[]), 9.81f32.to_variant());
+    assert_eq!(obj.call("PRECISE".into(), &[]), 0.125f64.to_variant());
+    assert_eq!(
+        obj.call("ORIGIN".into(), &[]),
+        Vector2::new(1.0, -2.0).to_variant()
+    );
+}
+
 #[derive(GodotClass)]
 struct HasOtherConstants {}
 
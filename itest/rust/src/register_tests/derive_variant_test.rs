@@ -5,10 +5,11 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::HashMap;
 use std::fmt::Debug;
 
 use godot::builtin::meta::{FromGodot, ToGodot};
-use godot::builtin::{dict, varray, Variant};
+use godot::builtin::{dict, varray, GString, Variant};
 use godot::register::{FromGodot, GodotConvert, ToGodot};
 
 use crate::common::roundtrip;
@@ -265,3 +266,242 @@ fn struct_with_skip_from_variant() {
         )
     );
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Layout customized with #[variant]
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+struct StructRenamedDefault {
+    #[variant(rename = "display_name")]
+    name: GString,
+    #[variant(default)]
+    level: i64,
+    #[variant(default = 100)]
+    health: i64,
+}
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+struct StructRenameLiterals {
+    #[variant(rename = "tab\tkey")]
+    escaped: i64,
+    #[variant(rename = r#"raw "key""#)]
+    raw: i64,
+}
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+struct StructFlatten {
+    id: i64,
+    #[variant(flatten)]
+    extra: HashMap<GString, i64>,
+}
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+struct Stats {
+    strength: i64,
+    #[variant(rename = "agi")]
+    agility: i64,
+}
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+struct StructFlattenNested {
+    name: GString,
+    #[variant(flatten)]
+    stats: Stats,
+}
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+#[variant(tag = "type")]
+enum EnumInternal {
+    Empty,
+    #[variant(rename = "circle")]
+    Circle {
+        radius: i64,
+    },
+}
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+#[variant(untagged)]
+enum EnumUntagged {
+    Nothing,
+    Number(i64),
+    Pair(GString, i64),
+    Named { text: GString },
+}
+
+#[itest]
+fn struct_rename_default() {
+    let value = StructRenamedDefault {
+        name: "hero".into(),
+        level: 3,
+        health: 40,
+    };
+    assert_eq!(
+        value.to_variant(),
+        dict! { "StructRenamedDefault": dict! { "display_name": "hero", "level": 3, "health": 40 } }
+            .to_variant()
+    );
+    roundtrip(value);
+
+    let partial =
+        dict! { "StructRenamedDefault": dict! { "display_name": "villain" } }.to_variant();
+    assert_eq!(
+        StructRenamedDefault::from_variant(&partial),
+        StructRenamedDefault {
+            name: "villain".into(),
+            level: 0,
+            health: 100,
+        }
+    );
+
+    let missing = dict! { "StructRenamedDefault": dict! { "name": "villain" } }.to_variant();
+    StructRenamedDefault::try_from_variant(&missing).expect_err("renamed key is required");
+}
+
+#[itest]
+fn struct_rename_literals() {
+    let value = StructRenameLiterals { escaped: 1, raw: 2 };
+    assert_eq!(
+        value.to_variant(),
+        dict! { "StructRenameLiterals": dict! { "tab\tkey": 1, r#"raw "key""#: 2 } }.to_variant()
+    );
+    roundtrip(value);
+}
+
+#[itest]
+fn struct_flatten() {
+    let value = StructFlatten {
+        id: 7,
+        extra: HashMap::from([(GString::from("a"), 1), (GString::from("b"), 2)]),
+    };
+    assert_eq!(
+        value.to_variant(),
+        dict! { "StructFlatten": dict! { "id": 7, "a": 1, "b": 2 } }.to_variant()
+    );
+    roundtrip(value);
+}
+
+#[itest]
+fn struct_flatten_nested() {
+    let stats = Stats {
+        strength: 3,
+        agility: 2,
+    };
+
+    // Unflattened, the nested struct keeps its own wrapper.
+    assert_eq!(
+        stats.to_variant(),
+        dict! { "Stats": dict! { "strength": 3, "agi": 2 } }.to_variant()
+    );
+
+    let value = StructFlattenNested {
+        name: "orc".into(),
+        stats,
+    };
+    assert_eq!(
+        value.to_variant(),
+        dict! { "StructFlattenNested": dict! { "name": "orc", "strength": 3, "agi": 2 } }
+            .to_variant()
+    );
+    roundtrip(value);
+
+    let missing =
+        dict! { "StructFlattenNested": dict! { "name": "orc", "strength": 3 } }.to_variant();
+    StructFlattenNested::try_from_variant(&missing).expect_err("agi is missing");
+}
+
+#[itest]
+fn enum_internally_tagged() {
+    assert_eq!(
+        EnumInternal::Circle { radius: 2 }.to_variant(),
+        dict! { "EnumInternal": dict! { "type": "circle", "radius": 2 } }.to_variant()
+    );
+    assert_eq!(
+        EnumInternal::Empty.to_variant(),
+        dict! { "EnumInternal": dict! { "type": "Empty" } }.to_variant()
+    );
+    roundtrip(EnumInternal::Empty);
+    roundtrip(EnumInternal::Circle { radius: 5 });
+
+    let unknown = dict! { "EnumInternal": dict! { "type": "Square" } }.to_variant();
+    EnumInternal::try_from_variant(&unknown).expect_err("Square is not a variant");
+}
+
+#[itest]
+fn enum_untagged() {
+    assert_eq!(
+        EnumUntagged::Number(4).to_variant(),
+        dict! { "EnumUntagged": 4 }.to_variant()
+    );
+    assert_eq!(
+        EnumUntagged::Pair("x".into(), 1).to_variant(),
+        dict! { "EnumUntagged": varray!["x", 1] }.to_variant()
+    );
+    roundtrip(EnumUntagged::Nothing);
+    roundtrip(EnumUntagged::Number(-9));
+    roundtrip(EnumUntagged::Pair("y".into(), 2));
+    roundtrip(EnumUntagged::Named { text: "z".into() });
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Representation selected with #[godot]
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+#[godot(transparent)]
+struct TransparentTuple(i64);
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+#[godot(transparent)]
+struct TransparentNamed {
+    name: GString,
+}
+
+#[derive(FromGodot, ToGodot, GodotConvert, Clone, Copy, PartialEq, Debug)]
+#[godot(via = u8)]
+enum ViaInt {
+    A = 1,
+    B = 5,
+    C,
+}
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+#[godot(via = GString)]
+enum ViaGString {
+    Left,
+    Right,
+}
+
+#[itest]
+fn transparent_newtype() {
+    assert_eq!(TransparentTuple(7).to_godot(), 7);
+    assert_eq!(TransparentTuple(7).to_variant(), 7.to_variant());
+    roundtrip(TransparentTuple(-3));
+
+    let named = TransparentNamed {
+        name: "gdext".into(),
+    };
+    assert_eq!(named.to_godot(), GString::from("gdext"));
+    roundtrip(named);
+}
+
+#[itest]
+fn enum_via_int() {
+    assert_eq!(ViaInt::A.to_godot(), 1);
+    assert_eq!(ViaInt::C.to_variant(), 6.to_variant());
+    assert_eq!(ViaInt::from_godot(5), ViaInt::B);
+    roundtrip(ViaInt::C);
+
+    let err = ViaInt::try_from_godot(2).expect_err("2 is not a discriminant");
+    assert_eq!(err.value_str(), Some("2"));
+}
+
+#[itest]
+fn enum_via_gstring() {
+    assert_eq!(ViaGString::Right.to_godot(), GString::from("Right"));
+    assert_eq!(
+        ViaGString::from_variant(&"Left".to_variant()),
+        ViaGString::Left
+    );
+    roundtrip(ViaGString::Left);
+
+    ViaGString::try_from_godot(GString::from("Up")).expect_err("Up is not a variant");
+}
@@ -9,7 +9,10 @@
 #![allow(clippy::non_minimal_cfg)]
 
 use crate::framework::itest;
+use godot::engine::global;
+use godot::engine::global::MethodFlags;
 use godot::engine::ClassDb;
+use godot::obj::EngineBitfield;
 use godot::prelude::*;
 
 #[derive(GodotClass)]
@@ -54,6 +57,117 @@ impl FuncRename {
     }
 }
 
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncDefaultParams;
+
+#[godot_api]
+impl FuncDefaultParams {
+    #[func]
+    fn spawn(
+        &self,
+        position: i64,
+        #[opt(default = 1)] count: i32,
+        #[opt(default = GString::from("orc"))] kind: GString,
+    ) -> GString {
+        GString::from(format!("{position}:{count}:{kind}"))
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncVarargs;
+
+#[godot_api]
+impl FuncVarargs {
+    #[func(vararg)]
+    fn sum(&self, args: &[Variant]) -> i64 {
+        args.iter().map(|arg| arg.to::<i64>()).sum()
+    }
+
+    #[func(vararg)]
+    fn count_args(args: &[Variant]) -> i64 {
+        args.len() as i64
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncRustCollections;
+
+#[godot_api]
+impl FuncRustCollections {
+    #[func]
+    fn byte_sum(bytes: Vec<u8>) -> i64 {
+        bytes.into_iter().map(i64::from).sum()
+    }
+
+    #[func]
+    fn split_pair(pair: (GString, i32)) -> [i32; 2] {
+        [pair.0.len() as i32, pair.1]
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncResults;
+
+#[godot_api]
+impl FuncResults {
+    #[func]
+    fn parse_count(text: GString) -> Result<i64, std::num::ParseIntError> {
+        text.to_string().parse()
+    }
+
+    #[func]
+    fn make_object(&self, succeed: bool) -> Result<Gd<RefCounted>, String> {
+        if succeed {
+            Ok(RefCounted::new_gd())
+        } else {
+            Err("object creation refused".to_string())
+        }
+    }
+
+    #[func]
+    fn validate(&mut self, valid: bool) -> Result<(), &'static str> {
+        if valid {
+            Ok(())
+        } else {
+            Err("invalid")
+        }
+    }
+
+    #[func]
+    fn split_words(text: GString) -> Result<Vec<GString>, String> {
+        if text.is_empty() {
+            return Err("no words".to_string());
+        }
+        Ok(text.to_string().split(' ').map(GString::from).collect())
+    }
+
+    #[func]
+    fn make_plane(valid: bool) -> Result<Plane, String> {
+        if valid {
+            Ok(Plane::new(Vector3::UP, 2.0))
+        } else {
+            Err("no plane".to_string())
+        }
+    }
+    #[func(error_fallback = global::Error::ERR_FILE_NOT_FOUND)]
+    fn open_file(exists: bool) -> Result<(), String> {
+        if exists {
+            Ok(())
+        } else {
+            Err("file not found".to_string())
+        }
+    }
+
+    #[func(rename = parse_index, error_fallback = -1)]
+    fn parse_index_or_minus_one(text: GString) -> Result<i64, std::num::ParseIntError> {
+        text.to_string().parse()
+    }
+}
+
 impl FuncRename {
     /// Unused but present to demonstrate how `rename = ...` can be used to avoid name clashes.
     #[allow(dead_code)]
@@ -304,3 +418,160 @@ fn cfg_removes_or_keeps_signals() {
     ));
     assert!(!class_has_signal::<GdSelfReference>("cfg_removes_signal"));
 }
+
+#[itest]
+fn func_default_params() {
+    let mut object = FuncDefaultParams::new_gd().upcast::<RefCounted>();
+
+    let all = object.call(
+        "spawn".into(),
+        &[5.to_variant(), 3.to_variant(), "elf".to_variant()],
+    );
+    assert_eq!(all, "5:3:elf".to_variant());
+
+    let one_default = object.call("spawn".into(), &[5.to_variant(), 3.to_variant()]);
+    assert_eq!(one_default, "5:3:orc".to_variant());
+
+    let all_defaults = object.call("spawn".into(), &[5.to_variant()]);
+    assert_eq!(all_defaults, "5:1:orc".to_variant());
+}
+
+#[itest]
+fn func_default_params_registered() {
+    let class_name = FuncDefaultParams::class_name().to_string_name();
+    let methods = ClassDb::singleton().class_get_method_list(class_name);
+
+    let spawn = methods
+        .iter_shared()
+        .find(|method| method.get("name") == Some("spawn".to_variant()))
+        .expect("method `spawn` is registered");
+
+    let default_args = spawn
+        .get("default_args")
+        .expect("method info has default arguments")
+        .to::<VariantArray>();
+    assert_eq!(default_args, varray![1, "orc"]);
+}
+
+#[itest]
+fn func_vararg() {
+    let mut object = FuncVarargs::new_gd().upcast::<RefCounted>();
+
+    assert_eq!(object.call("sum".into(), &[]), 0.to_variant());
+    assert_eq!(
+        object.call(
+            "sum".into(),
+            &[1.to_variant(), 2.to_variant(), 3.to_variant()]
+        ),
+        6.to_variant()
+    );
+}
+
+#[itest]
+fn func_vararg_registered() {
+    let class_name = FuncVarargs::class_name().to_string_name();
+    let methods = ClassDb::singleton().class_get_method_list(class_name);
+
+    for name in ["sum", "count_args"] {
+        let method = methods
+            .iter_shared()
+            .find(|method| method.get("name") == Some(name.to_variant()))
+            .unwrap_or_else(|| panic!("method `{name}` is registered"));
+
+        let flags = method
+            .get("flags")
+            .expect("method info has flags")
+            .to::<u64>();
+        assert_ne!(flags & MethodFlags::METHOD_FLAG_VARARG.ord(), 0);
+    }
+}
+
+#[itest]
+fn func_callable_macro() {
+    let object = FuncRename::new_gd();
+
+    let func = callable!(FuncRename::long_function_name_for_is_true);
+    assert_eq!(func.name(), "is_true");
+    assert_eq!(func.arity(), 0);
+
+    let callable = callable!(object, FuncRename::long_function_name_for_is_true);
+    assert_eq!(callable.method_name(), Some("is_true".into()));
+    assert_eq!(callable.callv(VariantArray::new()), true.to_variant());
+
+    let func = callable!(FuncDefaultParams::spawn);
+    assert_eq!(func.name(), "spawn");
+    assert_eq!(func.arity(), 3);
+}
+
+#[itest]
+fn func_rust_collections() {
+    let mut object = FuncRustCollections::new_gd().upcast::<RefCounted>();
+
+    let bytes = PackedByteArray::from(&[1, 2, 3]);
+    let sum = object.call("byte_sum".into(), &[bytes.to_variant()]);
+    assert_eq!(sum, 6.to_variant());
+
+    let split = object.call("split_pair".into(), &[varray!["four", 4].to_variant()]);
+    assert_eq!(split, PackedInt32Array::from(&[4, 4]).to_variant());
+}
+
+#[itest]
+fn func_result_ok() {
+    let mut object = FuncResults::new_gd().upcast::<RefCounted>();
+
+    let count = object.call("parse_count".into(), &["42".to_variant()]);
+    assert_eq!(count, 42.to_variant());
+
+    let created = object.call("make_object".into(), &[true.to_variant()]);
+    assert!(created.try_to::<Gd<RefCounted>>().is_ok());
+
+    let validated = object.call("validate".into(), &[true.to_variant()]);
+    assert_eq!(validated, global::Error::OK.to_variant());
+
+    let words = object.call("split_words".into(), &["a b".to_variant()]);
+    assert_eq!(
+        words.to::<Vec<GString>>(),
+        vec![GString::from("a"), GString::from("b")]
+    );
+
+    let plane = object.call("make_plane".into(), &[true.to_variant()]);
+    assert_eq!(plane, Plane::new(Vector3::UP, 2.0).to_variant());
+}
+
+#[itest]
+fn func_result_err_fallback() {
+    let mut object = FuncResults::new_gd().upcast::<RefCounted>();
+
+    // Errors are reported through godot_error!, and the fallback is returned.
+    let count = object.call("parse_count".into(), &["many".to_variant()]);
+    assert_eq!(count, 0.to_variant());
+
+    let created = object.call("make_object".into(), &[false.to_variant()]);
+    assert!(created.is_nil());
+
+    let validated = object.call("validate".into(), &[false.to_variant()]);
+    assert_eq!(validated, global::Error::FAILED.to_variant());
+
+    let words = object.call("split_words".into(), &["".to_variant()]);
+    assert!(words.to::<PackedStringArray>().is_empty());
+
+    let plane = object.call("make_plane".into(), &[false.to_variant()]);
+    assert_eq!(plane, Plane::invalid().to_variant());
+}
+
+#[itest]
+fn func_result_custom_fallback() {
+    let mut object = FuncResults::new_gd().upcast::<RefCounted>();
+
+    let opened = object.call("open_file".into(), &[true.to_variant()]);
+    assert_eq!(opened, global::Error::OK.to_variant());
+
+    let opened = object.call("open_file".into(), &[false.to_variant()]);
+    assert_eq!(opened, global::Error::ERR_FILE_NOT_FOUND.to_variant());
+
+    let index = object.call("parse_index".into(), &["7".to_variant()]);
+    assert_eq!(index, 7.to_variant());
+
+    let index = object.call("parse_index".into(), &["seven".to_variant()]);
+    assert_eq!(index, (-1).to_variant());
+}
@@ -5,11 +5,13 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod builder_test;
 mod constant_test;
 mod derive_variant_test;
 mod func_test;
 mod gdscript_ffi_test;
 mod option_ffi_test;
+mod rpc_test;
 mod var_test;
 
 pub use gdscript_ffi_test::gen_ffi;
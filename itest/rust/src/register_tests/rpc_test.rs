@@ -0,0 +1,155 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::{itest, TestContext};
+use godot::engine::global::Error;
+use godot::engine::multiplayer_api::RPCMode;
+use godot::engine::multiplayer_peer::TransferMode;
+use godot::prelude::*;
+use godot::register::builder::RpcConfig;
+
+// Without a multiplayer peer, the default OfflineMultiplayerPeer acts as server with unique ID 1.
+const LOCAL_PEER_ID: i64 = 1;
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct RpcReceiver {
+    value: i64,
+
+    #[base]
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl RpcReceiver {
+    #[rpc(any_peer, reliable, call_local, channel = 1, helpers)]
+    fn set_value(&mut self, value: i64) {
+        self.value = value;
+    }
+
+    #[func(rename = clear)]
+    #[rpc(call_local)]
+    fn reset(&mut self) {
+        self.value = 0;
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct RpcReadyReceiver {
+    #[init(default = OnReady::new(|| 42))]
+    auto: OnReady<i64>,
+    ready_called: bool,
+
+    #[base]
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl RpcReadyReceiver {
+    #[rpc(call_local)]
+    fn ping(&mut self) {}
+}
+
+#[godot_api]
+impl INode for RpcReadyReceiver {
+    fn ready(&mut self) {
+        self.ready_called = true;
+    }
+}
+
+fn add_receiver(ctx: &TestContext) -> Gd<RpcReceiver> {
+    let receiver = RpcReceiver::new_alloc();
+    ctx.scene_tree.clone().add_child(receiver.clone().upcast());
+
+    receiver
+}
+
+fn free_receiver(ctx: &TestContext, receiver: Gd<RpcReceiver>) {
+    ctx.scene_tree
+        .clone()
+        .remove_child(receiver.clone().upcast());
+    receiver.free();
+}
+
+#[itest]
+fn rpc_configured_before_ready(ctx: &TestContext) {
+    let receiver = add_receiver(ctx);
+    let mut node = receiver.clone().upcast::<Node>();
+
+    let err = node.rpc_id(LOCAL_PEER_ID, "set_value".into(), &[7.to_variant()]);
+    assert_eq!(err, Error::OK);
+    assert_eq!(receiver.bind().value, 7);
+
+    let err = node.rpc_id(LOCAL_PEER_ID, "clear".into(), &[]);
+    assert_eq!(err, Error::OK);
+    assert_eq!(receiver.bind().value, 0);
+
+    free_receiver(ctx, receiver);
+}
+
+#[itest]
+fn rpc_configured_with_user_ready(ctx: &TestContext) {
+    let receiver = RpcReadyReceiver::new_alloc();
+    ctx.scene_tree.clone().add_child(receiver.clone().upcast());
+
+    // RPC configuration must not replace OnReady initialization or the user's ready().
+    assert_eq!(*receiver.bind().auto, 42);
+    assert!(receiver.bind().ready_called);
+
+    let mut node = receiver.clone().upcast::<Node>();
+    let err = node.rpc_id(LOCAL_PEER_ID, "ping".into(), &[]);
+    assert_eq!(err, Error::OK);
+
+    node.clone()
+        .get_parent()
+        .unwrap()
+        .remove_child(node.clone());
+    node.free();
+}
+
+#[itest]
+fn rpc_typed_helpers(ctx: &TestContext) {
+    let mut receiver = add_receiver(ctx);
+
+    let err = receiver.bind_mut().rpc_id_set_value(LOCAL_PEER_ID, 12);
+    assert_eq!(err, Error::OK);
+    assert_eq!(receiver.bind().value, 12);
+
+    let err = receiver.bind_mut().rpc_set_value(-3);
+    assert_eq!(err, Error::OK);
+    assert_eq!(receiver.bind().value, -3);
+
+    free_receiver(ctx, receiver);
+}
+
+#[itest]
+fn rpc_config_dictionary() {
+    let dict = RpcConfig::default().to_dictionary();
+    assert_eq!(
+        dict.get("rpc_mode"),
+        Some(RPCMode::RPC_MODE_AUTHORITY.to_variant())
+    );
+    assert_eq!(
+        dict.get("transfer_mode"),
+        Some(TransferMode::TRANSFER_MODE_UNRELIABLE.to_variant())
+    );
+    assert_eq!(dict.get("call_local"), Some(false.to_variant()));
+    assert_eq!(dict.get("channel"), Some(0.to_variant()));
+
+    let config = RpcConfig {
+        rpc_mode: RPCMode::RPC_MODE_ANY_PEER,
+        channel: 2,
+        ..RpcConfig::default()
+    };
+    let dict = config.to_dictionary();
+    assert_eq!(
+        dict.get("rpc_mode"),
+        Some(RPCMode::RPC_MODE_ANY_PEER.to_variant())
+    );
+    assert_eq!(dict.get("channel"), Some(2.to_variant()));
+}